@@ -287,8 +287,10 @@ impl Iroha<ToriiNotStarted> {
                 Arc::clone(&kura),
                 live_query_store_handle.clone(),
             )
-        });
+        })
+        .with_history_depth(config.torii.query_history_depth);
         let state = Arc::new(state);
+        task::spawn(Arc::clone(&state).keep_history());
 
         let queue = Arc::new(Queue::from_config(config.queue, events_sender.clone()));
 
@@ -333,8 +335,9 @@ impl Iroha<ToriiNotStarted> {
             config.common.peer_id(),
             network.clone(),
             Arc::clone(&state),
-        )
-        .start();
+        );
+        let block_sync_status = block_sync.status();
+        let block_sync = block_sync.start();
 
         let gossiper = TransactionGossiper::from_config(
             config.common.chain_id.clone(),
@@ -376,8 +379,12 @@ impl Iroha<ToriiNotStarted> {
             live_query_store_handle,
             Arc::clone(&kura),
             Arc::clone(&state),
+            config.common.peer_id(),
+            block_sync_status,
             #[cfg(feature = "telemetry")]
             metrics_reporter,
+            #[cfg(feature = "telemetry")]
+            sumeragi.clone(),
         );
 
         Self::spawn_config_updates_broadcasting(kiso.clone(), logger.clone());
@@ -0,0 +1,549 @@
+//! Asynchronous counterpart of the [`Client`], which doesn't block the thread while waiting
+//! for the peer. Requests are built the same way as for the [`Client`].
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    num::NonZeroU64,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use eyre::{Result, WrapErr};
+use futures_util::{future, stream, Stream, StreamExt, TryStreamExt};
+use iroha_data_model::query::QueryOutputBox;
+use iroha_telemetry::metrics::{PeersStatus, Status};
+use url::Url;
+
+use crate::{
+    client::{
+        blocks_api::AsyncBlockStream, events_api::AsyncEventStream, Client, ConfigDTO,
+        ConfigResponseHandler, QueryOutput, QueryResponseHandler, QueryResult,
+        StatusResponseHandler, TransactionResponseHandler,
+    },
+    config::Config,
+    crypto::HashOf,
+    data_model::{
+        isi::Instruction,
+        prelude::*,
+        query::{
+            aggregation::{AggregateGroup, Aggregation},
+            http::ClientQueryBuilder,
+            IterableQuery, Query,
+        },
+        IdBox,
+    },
+    endpoints::{Health, HEALTH_CHECK_TIMEOUT},
+    http::{RequestBuilder, Response},
+    http_default::{async_http_client, AsyncRequestBuilder},
+    query_builder::QueryRequestBuilder,
+};
+
+/// Asynchronous Iroha client
+#[derive(Clone, Debug)]
+pub struct AsyncClient {
+    client: Client,
+    http: reqwest::Client,
+}
+
+impl AsyncClient {
+    /// Constructor for client from configuration
+    ///
+    /// # Errors
+    /// Fails if the TLS settings of the configuration can't be applied
+    pub fn new(configuration: Config) -> Result<Self> {
+        Self::from_client(Client::new(configuration))
+    }
+
+    /// Constructor for client from configuration and headers
+    ///
+    /// *Authorization* header will be added if `basic_auth` is presented
+    ///
+    /// # Errors
+    /// Fails if the TLS settings of the configuration can't be applied
+    pub fn with_headers(configuration: Config, headers: HashMap<String, String>) -> Result<Self> {
+        Self::from_client(Client::with_headers(configuration, headers))
+    }
+
+    /// Constructor for client which sends the same requests as the blocking `client`
+    ///
+    /// # Errors
+    /// Fails if the TLS settings of the `client` can't be applied
+    pub fn from_client(client: Client) -> Result<Self> {
+        let http = async_http_client(client.tls.as_ref())?;
+        Ok(Self { client, http })
+    }
+
+    /// Blocking client with the same settings.
+    /// Use it to build and sign transactions and queries.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Same as [`Client::endpoints`], but the health of the peers is checked concurrently
+    async fn endpoints(&self) -> Vec<Url> {
+        self.check_health().await;
+        self.client.endpoints.candidates(&self.client.torii_url)
+    }
+
+    /// Same as [`Client::check_health`]
+    async fn check_health(&self) {
+        let endpoints = &self.client.endpoints;
+        if endpoints.needs_health_check() {
+            let checks = endpoints
+                .all(&self.client.torii_url)
+                .into_iter()
+                .map(|torii_url| self.check_peer_health(torii_url));
+            endpoints.record_health_check(future::join_all(checks).await);
+        }
+    }
+
+    async fn check_peer_health(&self, torii_url: Url) -> (Url, Health) {
+        let request = self
+            .client
+            .at(&torii_url)
+            .prepare_ready_request::<AsyncRequestBuilder>()
+            .with_timeout(HEALTH_CHECK_TIMEOUT);
+        let response = request.send(&self.http).await;
+        (torii_url, Health::of_ready_response(response))
+    }
+
+    /// Same as [`Client::send_with_failover`]
+    async fn send_with_failover<T: Send>(
+        &self,
+        prepare: impl Fn(&Client) -> (AsyncRequestBuilder, T) + Send + Sync,
+    ) -> Result<(Response<Vec<u8>>, T)> {
+        let mut last_error = None;
+        for torii_url in self.endpoints().await {
+            let (request, output) = prepare(&self.client.at(&torii_url));
+            match request.send(&self.http).await {
+                Ok(response) => return Ok((response, output)),
+                Err(error) => {
+                    self.client.endpoints.mark_unreachable(&torii_url);
+                    last_error = Some(error);
+                }
+            }
+        }
+        Err(last_error.expect("There is at least one endpoint"))
+    }
+
+    /// Instructions API entry point. Submits one Iroha Special Instruction to `Iroha` peers.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit(
+        &self,
+        instruction: impl Instruction + Send,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_with_metadata([instruction], UnlimitedMetadata::new())
+            .await
+    }
+
+    /// Instructions API entry point. Submits several Iroha Special Instructions to `Iroha` peers.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all(
+        &self,
+        instructions: impl IntoIterator<Item = impl Instruction> + Send,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_with_metadata(instructions, UnlimitedMetadata::new())
+            .await
+    }
+
+    /// Instructions API entry point. Submits several Iroha Special Instructions to `Iroha` peers.
+    /// Allows to specify [`Metadata`] of [`TransactionBuilder`].
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all_with_metadata(
+        &self,
+        instructions: impl IntoIterator<Item = impl Instruction> + Send,
+        metadata: UnlimitedMetadata,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = self.client.build_transaction(instructions, metadata);
+        self.submit_transaction(&transaction).await
+    }
+
+    /// Submit a prebuilt transaction.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        iroha_logger::trace!(tx=?transaction, "Submitting");
+        let hash = transaction.hash();
+        // Peers deduplicate transactions by their hash, so resending one to another peer is safe
+        let (response, _) = self
+            .send_with_failover(|client| client.prepare_transaction_request(transaction))
+            .await
+            .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
+        TransactionResponseHandler::handle(&response)?;
+        Ok(hash)
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_blocking(
+        &self,
+        instruction: impl Instruction + Send,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_blocking_with_metadata([instruction], UnlimitedMetadata::new())
+            .await
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all_blocking(
+        &self,
+        instructions: impl IntoIterator<Item = impl Instruction> + Send,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_blocking_with_metadata(instructions, UnlimitedMetadata::new())
+            .await
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Allows to specify [`Metadata`] of [`TransactionBuilder`].
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all_blocking_with_metadata(
+        &self,
+        instructions: impl IntoIterator<Item = impl Instruction> + Send,
+        metadata: UnlimitedMetadata,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = self.client.build_transaction(instructions, metadata);
+        self.submit_transaction_blocking(&transaction).await
+    }
+
+    /// Submit the prebuilt transaction and wait until it is either rejected or committed.
+    /// If rejected, return the rejection reason.
+    ///
+    /// # Errors
+    /// Fails if sending a transaction to a peer fails or there is an error in the response
+    pub async fn submit_transaction_blocking(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let hash = transaction.hash();
+        let deadline = tokio::time::Instant::now() + self.client.transaction_status_timeout;
+        self.check_health().await;
+
+        // Subscribe before submitting so that the outcome of the transaction isn't missed
+        let mut events = tokio::time::timeout_at(
+            deadline,
+            self.listen_for_events(Client::tx_confirmation_filters(hash)),
+        )
+        .await
+        .map_err(Into::into)
+        .and_then(std::convert::identity)
+        .wrap_err("Failed to establish event listener connection")?;
+
+        let result = match self.submit_transaction(transaction).await {
+            Ok(_) => tokio::time::timeout_at(
+                deadline,
+                Client::listen_for_tx_confirmation_loop(&mut events, hash),
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(std::convert::identity),
+            Err(error) => Err(error),
+        };
+        events.close().await;
+        result
+    }
+
+    /// Get the status of the transaction with the given `hash`.
+    /// Returns `None` if the transaction isn't known to the peer.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub async fn get_transaction_status(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Result<Option<TransactionEvent>> {
+        let (resp, ()) = self
+            .send_with_failover(|client| (client.prepare_transaction_status_request(hash), ()))
+            .await?;
+        TransactionResponseHandler::handle_status(&resp)
+    }
+
+    /// Query API entry point. Shorthand for `self.build_query(r).execute()`.
+    ///
+    /// # Errors
+    /// Fails if sending request fails
+    pub async fn request<R>(
+        &self,
+        request: R,
+    ) -> QueryResult<<R::Output as AsyncQueryOutput>::Target>
+    where
+        R: Query + Debug + Send,
+        R::Output: AsyncQueryOutput,
+        <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+    {
+        self.build_query(request).execute().await
+    }
+
+    /// Query API entry point.
+    /// Creates a [`QueryRequestBuilder`] which can be used to configure requests queries from `Iroha` peers.
+    pub fn build_query<R>(&self, request: R) -> QueryRequestBuilder<'_, R, Self>
+    where
+        R: Query + Debug,
+        R::Output: QueryOutput,
+        <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+    {
+        QueryRequestBuilder::new(self, self.client.account_id.clone(), request)
+    }
+
+    async fn request_with_query_builder<O>(
+        &self,
+        query_builder: ClientQueryBuilder,
+    ) -> QueryResult<<O as AsyncQueryOutput>::Target>
+    where
+        O: AsyncQueryOutput,
+        <O as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+    {
+        iroha_logger::trace!(?query_builder);
+        // Same as with the blocking client, the cursor stays with the peer which responded
+        let (response, query_request) = self
+            .send_with_failover(|client| {
+                let query_request = client.query_request(query_builder.clone());
+                (query_request.clone().prepare(), query_request)
+            })
+            .await?;
+
+        let mut resp_handler = QueryResponseHandler::<O>::new(query_request);
+        let output = resp_handler.handle(&response)?;
+        Ok(<O as AsyncQueryOutput>::new(output, resp_handler, self))
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
+    ///
+    /// # Errors
+    /// Forwards from [`Client::listen_for_events_async`]
+    pub async fn listen_for_events(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
+    ) -> Result<AsyncEventStream> {
+        self.check_health().await;
+        self.client.listen_for_events_async(event_filters).await
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` blocks
+    ///
+    /// # Errors
+    /// Forwards from [`Client::listen_for_blocks_async`]
+    pub async fn listen_for_blocks(&self, height: NonZeroU64) -> Result<AsyncBlockStream> {
+        self.check_health().await;
+        self.client.listen_for_blocks_async(height).await
+    }
+
+    /// Get value of config on peer
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub async fn get_config(&self) -> Result<ConfigDTO> {
+        let resp = self
+            .client
+            .prepare_get_config_request::<AsyncRequestBuilder>()
+            .send(&self.http)
+            .await?;
+        ConfigResponseHandler::handle_get(&resp)
+    }
+
+    /// Send a request to change the configuration of a specified field.
+    ///
+    /// # Errors
+    /// If sending request or decoding fails
+    pub async fn set_config(&self, dto: ConfigDTO) -> Result<()> {
+        let resp = self
+            .client
+            .prepare_set_config_request::<AsyncRequestBuilder>(dto)?
+            .send(&self.http)
+            .await?;
+        ConfigResponseHandler::handle_set(&resp)
+    }
+
+    /// Gets network status seen from the peer
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub async fn get_status(&self) -> Result<Status> {
+        let resp = self
+            .client
+            .prepare_status_request::<AsyncRequestBuilder>()
+            .header(http::header::ACCEPT, "application/x-parity-scale")
+            .send(&self.http)
+            .await?;
+        StatusResponseHandler::handle_scale(&resp)
+    }
+
+    /// Gets the peers connected to the peer, with their connection diagnostics
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub async fn get_peers(&self) -> Result<PeersStatus> {
+        let resp = self
+            .client
+            .prepare_peers_request::<AsyncRequestBuilder>()
+            .send(&self.http)
+            .await?;
+        StatusResponseHandler::handle_scale(&resp)
+    }
+}
+
+impl<R> QueryRequestBuilder<'_, R, AsyncClient>
+where
+    R: Query + Debug + Send,
+    R::Output: AsyncQueryOutput,
+    <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    /// Send the query to the peer
+    ///
+    /// # Errors
+    /// Fails if sending request fails
+    pub async fn execute(self) -> QueryResult<<R::Output as AsyncQueryOutput>::Target> {
+        let (client, query_builder) = self.into_query_builder();
+        client
+            .request_with_query_builder::<R::Output>(query_builder)
+            .await
+    }
+}
+
+impl<R> QueryRequestBuilder<'_, R, AsyncClient>
+where
+    R: IterableQuery + Debug + Send,
+    R::Output: QueryOutput,
+    <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    /// Compute `aggregation` over the filtered results instead of returning them.
+    ///
+    /// Sorting, pagination and fetch size are applied to the resulting groups.
+    ///
+    /// # Errors
+    /// Fails if sending request fails
+    pub async fn aggregate(
+        self,
+        aggregation: Aggregation,
+    ) -> QueryResult<QueryStream<AggregateGroup>> {
+        let (client, query_builder) = self.into_query_builder();
+        client
+            .request_with_query_builder::<Vec<AggregateGroup>>(
+                query_builder.with_aggregation(aggregation),
+            )
+            .await
+    }
+
+    /// Return the ids of the results instead of the results themselves.
+    ///
+    /// # Errors
+    /// Fails if sending request fails
+    pub async fn ids(self) -> QueryResult<QueryStream<IdBox>> {
+        let (client, query_builder) = self.with_id_projection().into_query_builder();
+        client
+            .request_with_query_builder::<Vec<IdBox>>(query_builder)
+            .await
+    }
+}
+
+/// Output of a query executed by the [`AsyncClient`]
+pub trait AsyncQueryOutput: QueryOutput + Send {
+    /// Type of the query output
+    type Target;
+
+    /// Construct query output from query response
+    fn new(
+        output: Self,
+        query_handler: QueryResponseHandler<Self>,
+        client: &AsyncClient,
+    ) -> <Self as AsyncQueryOutput>::Target;
+}
+
+impl<T> AsyncQueryOutput for Vec<T>
+where
+    T: Debug + Clone + Send + 'static,
+    Self: QueryOutput,
+    <Self as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    type Target = QueryStream<T>;
+
+    fn new(
+        output: Self,
+        query_handler: QueryResponseHandler<Self>,
+        client: &AsyncClient,
+    ) -> QueryStream<T> {
+        QueryStream::new(output, query_handler, client.http.clone())
+    }
+}
+
+/// Results of an iterable query. The next batch of results
+/// is requested from the peer once the current one is exhausted.
+pub struct QueryStream<T> {
+    inner: stream::BoxStream<'static, QueryResult<T>>,
+}
+
+impl<T> QueryStream<T>
+where
+    T: Debug + Clone + Send + 'static,
+    Vec<T>: QueryOutput,
+    <Vec<T> as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    fn new(
+        first_batch: Vec<T>,
+        query_handler: QueryResponseHandler<Vec<T>>,
+        http: reqwest::Client,
+    ) -> Self {
+        let next_batches = stream::try_unfold(query_handler, move |query_handler| {
+            Self::next_batch(query_handler, http.clone())
+        });
+
+        Self {
+            inner: stream::iter([Ok(first_batch)])
+                .chain(next_batches)
+                .map_ok(|batch| stream::iter(batch.into_iter().map(Ok)))
+                .try_flatten()
+                .boxed(),
+        }
+    }
+
+    /// Fetch the next batch of results, if there are results left
+    async fn next_batch(
+        mut query_handler: QueryResponseHandler<Vec<T>>,
+        http: reqwest::Client,
+    ) -> QueryResult<Option<(Vec<T>, QueryResponseHandler<Vec<T>>)>> {
+        let Some(request) = query_handler.next_request() else {
+            return Ok(None);
+        };
+        let response = request.prepare::<AsyncRequestBuilder>().send(&http).await?;
+        let batch = query_handler.handle(&response)?;
+
+        Ok(Some((batch, query_handler)))
+    }
+}
+
+impl<T> Stream for QueryStream<T> {
+    type Item = QueryResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+impl<T> Debug for QueryStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryStream").finish_non_exhaustive()
+    }
+}
@@ -2,10 +2,12 @@
 //! add any custom end-point related logic.
 
 use std::{
+    borrow::Cow,
     collections::HashMap,
     fmt::Debug,
     marker::PhantomData,
     num::{NonZeroU32, NonZeroU64},
+    sync::Arc,
     thread,
     time::Duration,
 };
@@ -23,8 +25,8 @@ use iroha_data_model::{
     query::QueryOutputBox,
 };
 use iroha_logger::prelude::*;
-use iroha_telemetry::metrics::Status;
-use iroha_torii_const::uri as torii_uri;
+use iroha_telemetry::metrics::{PeersStatus, Status};
+use iroha_torii_const::{admin as torii_admin, uri as torii_uri};
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
 use rand::Rng;
@@ -32,15 +34,13 @@ use url::Url;
 
 use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
 use crate::{
-    config::Config,
-    crypto::{HashOf, KeyPair},
+    config::{Config, ToriiTls},
+    crypto::{HashOf, KeyPair, Signature},
     data_model::{
-        block::SignedBlock,
-        isi::Instruction,
-        prelude::*,
-        query::{predicate::PredicateBox, Pagination, Query, Sorting},
-        BatchedResponse, ChainId, ValidationFail,
+        block::SignedBlock, isi::Instruction, prelude::*, query::Query, BatchedResponse, ChainId,
+        ValidationFail,
     },
+    endpoints::{Endpoints, Health, HEALTH_CHECK_TIMEOUT},
     http::{Method as HttpMethod, RequestBuilder, Response, StatusCode},
     http_default::{self, DefaultRequestBuilder, WebSocketError, WebSocketMessage},
     query_builder::QueryRequestBuilder,
@@ -57,7 +57,7 @@ pub struct QueryResponseHandler<R> {
 }
 
 impl<R> QueryResponseHandler<R> {
-    fn new(query_request: QueryRequest) -> Self {
+    pub(crate) fn new(query_request: QueryRequest) -> Self {
         Self {
             query_request,
             _output_type: PhantomData,
@@ -90,7 +90,7 @@ impl<R: QueryOutput> QueryResponseHandler<R>
 where
     <R as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
 {
-    fn handle(&mut self, resp: &Response<Vec<u8>>) -> QueryResult<R> {
+    pub(crate) fn handle(&mut self, resp: &Response<Vec<u8>>) -> QueryResult<R> {
         // Separate-compilation friendly response handling
         fn _handle_query_response_base(
             resp: &Response<Vec<u8>>,
@@ -103,32 +103,9 @@ where
                          You are likely using a version of the client library \
                          that is incompatible with the version of the peer software",
                     )
-                        .map_err(Into::into)
+                    .map_err(Into::into)
                 }
-                StatusCode::BAD_REQUEST
-                | StatusCode::UNAUTHORIZED
-                | StatusCode::FORBIDDEN
-                | StatusCode::NOT_FOUND
-                | StatusCode::UNPROCESSABLE_ENTITY => Err(ValidationFail::decode_all(
-                    &mut resp.body().as_ref(),
-                )
-                    .map_or_else(
-                        |_| {
-                            ClientQueryError::Other(
-                                ResponseReport::with_msg("Query failed", resp)
-                                    .map_or_else(
-                                        |_| eyre!(
-                                        "Failed to decode response from Iroha. \
-                                        Response is neither a `ValidationFail` encoded value nor a valid utf-8 string error response. \
-                                        You are likely using a version of the client library that is incompatible with the version of the peer software",
-                                    ),
-                                        Into::into
-                                    ),
-                            )
-                        },
-                        ClientQueryError::Validation,
-                    )),
-                _ => Err(ResponseReport::with_msg("Unexpected query response", resp).unwrap_or_else(core::convert::identity).into()),
+                _ => Err(query_failure(resp)),
             }
         }
 
@@ -143,6 +120,50 @@ where
     }
 }
 
+impl<R> QueryResponseHandler<R> {
+    /// Request for the next batch of the query results, if there are results left
+    pub(crate) fn next_request(&self) -> Option<QueryRequest> {
+        let crate::data_model::query::QueryRequest::Cursor(cursor) = &self.query_request.request
+        else {
+            return None;
+        };
+        cursor.cursor().as_ref()?;
+
+        Some(self.query_request.clone())
+    }
+}
+
+/// Convert the response to a failed query into [`ClientQueryError`]
+fn query_failure(resp: &Response<Vec<u8>>) -> ClientQueryError {
+    match resp.status() {
+        StatusCode::BAD_REQUEST
+        | StatusCode::UNAUTHORIZED
+        | StatusCode::FORBIDDEN
+        | StatusCode::NOT_FOUND
+        | StatusCode::UNPROCESSABLE_ENTITY => ValidationFail::decode_all(&mut resp.body().as_ref())
+            .map_or_else(
+                |_| {
+                    ClientQueryError::Other(
+                        ResponseReport::with_msg("Query failed", resp).map_or_else(
+                            |_| {
+                                eyre!(
+                                    "Failed to decode response from Iroha. \
+                                    Response is neither a `ValidationFail` encoded value nor a valid utf-8 string error response. \
+                                    You are likely using a version of the client library that is incompatible with the version of the peer software",
+                                )
+                            },
+                            Into::into,
+                        ),
+                    )
+                },
+                ClientQueryError::Validation,
+            ),
+        _ => ResponseReport::with_msg("Unexpected query response", resp)
+            .unwrap_or_else(core::convert::identity)
+            .into(),
+    }
+}
+
 /// Different errors as a result of query response handling
 #[derive(Debug, thiserror::Error, displaydoc::Display)]
 pub enum ClientQueryError {
@@ -161,10 +182,10 @@ impl From<ResponseReport> for ClientQueryError {
 
 /// Phantom struct that handles Transaction API HTTP response
 #[derive(Clone, Copy)]
-struct TransactionResponseHandler;
+pub(crate) struct TransactionResponseHandler;
 
 impl TransactionResponseHandler {
-    fn handle(resp: &Response<Vec<u8>>) -> Result<()> {
+    pub(crate) fn handle(resp: &Response<Vec<u8>>) -> Result<()> {
         if resp.status() == StatusCode::OK {
             Ok(())
         } else {
@@ -175,6 +196,20 @@ impl TransactionResponseHandler {
             )
         }
     }
+
+    pub(crate) fn handle_status(resp: &Response<Vec<u8>>) -> Result<Option<TransactionEvent>> {
+        match resp.status() {
+            StatusCode::OK => TransactionEvent::decode_all(&mut resp.body().as_slice())
+                .map(Some)
+                .wrap_err("Failed to decode transaction status"),
+            StatusCode::NOT_FOUND => Ok(None),
+            status => Err(eyre!(
+                "Failed to get transaction status with HTTP status: {}. {}",
+                status,
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            )),
+        }
+    }
 }
 
 /// Phantom struct that handles status check HTTP response
@@ -187,7 +222,7 @@ impl StatusResponseHandler {
         serde_json::from_slice(slice).wrap_err("Failed to decode body")
     }
 
-    fn handle_raw(resp: &Response<Vec<u8>>) -> Result<&Vec<u8>> {
+    pub(crate) fn handle_raw(resp: &Response<Vec<u8>>) -> Result<&Vec<u8>> {
         if resp.status() != StatusCode::OK {
             return Err(ResponseReport::with_msg("Unexpected status response", resp)
                 .unwrap_or_else(core::convert::identity)
@@ -195,6 +230,40 @@ impl StatusResponseHandler {
         }
         Ok(resp.body())
     }
+
+    pub(crate) fn handle_scale<T: DecodeAll>(resp: &Response<Vec<u8>>) -> Result<T> {
+        let slice = Self::handle_raw(resp)?;
+        T::decode_all(&mut slice.as_slice()).map_err(|err| eyre!("{err}"))
+    }
+}
+
+/// Phantom struct that handles configuration HTTP responses
+#[derive(Clone, Copy)]
+pub(crate) struct ConfigResponseHandler;
+
+impl ConfigResponseHandler {
+    pub(crate) fn handle_get(resp: &Response<Vec<u8>>) -> Result<ConfigDTO> {
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get configuration with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        serde_json::from_slice(resp.body()).wrap_err("Failed to decode body")
+    }
+
+    pub(crate) fn handle_set(resp: &Response<Vec<u8>>) -> Result<()> {
+        if resp.status() != StatusCode::ACCEPTED {
+            return Err(eyre!(
+                "Failed to post configuration with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        };
+
+        Ok(())
+    }
 }
 
 /// Private structure to incapsulate error reporting for HTTP response.
@@ -262,16 +331,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.client_cursor >= self.iter.len() {
-            let crate::data_model::query::QueryRequest::Cursor(cursor) =
-                &self.query_handler.query_request.request
-            else {
-                return None;
-            };
-            if cursor.cursor().is_none() {
-                return None;
-            }
-
-            let request = match self.query_handler.query_request.clone().assemble().build() {
+            let request = match self.query_handler.next_request()?.assemble().build() {
                 Err(err) => return Some(Err(ClientQueryError::Other(err))),
                 Ok(ok) => ok,
             };
@@ -314,7 +374,19 @@ macro_rules! impl_query_output {
         impl QueryOutput for $ident {
             type Target = Self;
 
-            fn new(output: Self, _query_handler: QueryResponseHandler<Self>) -> Self::Target {
+            fn new(output: Self, _query_handler: QueryResponseHandler<Self>) -> Self {
+                output
+            }
+        }
+
+        impl crate::async_client::AsyncQueryOutput for $ident {
+            type Target = Self;
+
+            fn new(
+                output: Self,
+                _query_handler: QueryResponseHandler<Self>,
+                _client: &crate::async_client::AsyncClient,
+            ) -> Self {
                 output
             }
         } )+
@@ -329,6 +401,7 @@ impl_query_output! {
     crate::data_model::block::BlockHeader,
     crate::data_model::metadata::MetadataValueBox,
     crate::data_model::query::TransactionQueryOutput,
+    crate::data_model::query::TransactionProof,
     crate::data_model::executor::ExecutorDataModel,
     crate::data_model::trigger::Trigger,
     crate::data_model::prelude::Numeric,
@@ -359,6 +432,11 @@ pub struct Client {
     /// If `true` add nonce, which makes different hashes for
     /// transactions which occur repeatedly and/or simultaneously
     pub add_transaction_nonce: bool,
+    /// Custom TLS settings used to connect to the peer
+    pub tls: Option<ToriiTls>,
+    /// Endpoints of the other peers which the requests are routed to
+    /// when the peer at `torii_url` is down or lagging
+    pub(crate) endpoints: Arc<Endpoints>,
 }
 
 /// Query request
@@ -366,6 +444,7 @@ pub struct Client {
 pub struct QueryRequest {
     torii_url: Url,
     headers: HashMap<String, String>,
+    tls: Option<ToriiTls>,
     request: crate::data_model::query::QueryRequest<SignedQuery>,
 }
 
@@ -377,6 +456,7 @@ impl QueryRequest {
         Self {
             torii_url: format!("http://{torii_url}").parse().unwrap(),
             headers: HashMap::new(),
+            tls: None,
             request: crate::data_model::query::QueryRequest::Query(
                 ClientQueryBuilder::new(FindAllAccounts, test_samples::ALICE_ID.clone())
                     .sign(&test_samples::ALICE_KEYPAIR),
@@ -385,7 +465,14 @@ impl QueryRequest {
     }
 
     fn assemble(self) -> DefaultRequestBuilder {
-        let builder = DefaultRequestBuilder::new(
+        let tls = self.tls.clone();
+        self.prepare::<DefaultRequestBuilder>()
+            .with_tls(tls.as_ref())
+    }
+
+    /// Prepare the http-request with the request builder `B`
+    pub(crate) fn prepare<B: RequestBuilder>(self) -> B {
+        let builder = B::new(
             HttpMethod::POST,
             self.torii_url.join(torii_uri::QUERY).expect("Valid URI"),
         )
@@ -419,11 +506,13 @@ impl Client {
             chain_id,
             account_id,
             torii_api_url,
+            additional_torii_api_urls,
             key_pair,
             basic_auth,
             transaction_add_nonce,
             transaction_ttl,
             transaction_status_timeout,
+            torii_tls,
         }: Config,
         mut headers: HashMap<String, String>,
     ) -> Self {
@@ -443,7 +532,84 @@ impl Client {
             account_id,
             headers,
             add_transaction_nonce: transaction_add_nonce,
+            tls: torii_tls,
+            endpoints: Arc::new(Endpoints::new(additional_torii_api_urls)),
+        }
+    }
+
+    /// Client which sends the requests to the peer at `torii_url`
+    pub(crate) fn at(&self, torii_url: &Url) -> Cow<'_, Self> {
+        if *torii_url == self.torii_url {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(Self {
+                torii_url: torii_url.clone(),
+                ..self.clone()
+            })
+        }
+    }
+
+    /// Endpoints in the order the requests should be sent to them
+    fn endpoints(&self) -> Vec<Url> {
+        self.check_health();
+        self.endpoints.candidates(&self.torii_url)
+    }
+
+    /// Check the health of the peers concurrently if the last check is outdated
+    fn check_health(&self) {
+        if self.endpoints.needs_health_check() {
+            let health: Vec<_> = thread::scope(|spawner| {
+                // All the checks have to be spawned before any of them is joined
+                let mut checks = Vec::new();
+                for torii_url in self.endpoints.all(&self.torii_url) {
+                    checks.push(spawner.spawn(move || self.check_peer_health(torii_url)));
+                }
+                checks
+                    .into_iter()
+                    .filter_map(|check| check.join().ok())
+                    .collect()
+            });
+            self.endpoints.record_health_check(health);
+        }
+    }
+
+    fn check_peer_health(&self, torii_url: Url) -> (Url, Health) {
+        let response = self
+            .at(&torii_url)
+            .prepare_ready_request::<DefaultRequestBuilder>()
+            .with_tls(self.tls.as_ref())
+            .with_timeout(HEALTH_CHECK_TIMEOUT)
+            .build()
+            .and_then(http_default::DefaultRequest::send);
+        (torii_url, Health::of_ready_response(response))
+    }
+
+    /// Endpoint of the healthiest peer according to the last health check
+    pub(crate) fn preferred_endpoint(&self) -> Url {
+        self.endpoints.candidates(&self.torii_url).swap_remove(0)
+    }
+
+    /// Send the request made by `prepare` for the healthiest peer,
+    /// falling back to the next peer while the previous one can't be reached.
+    ///
+    /// Only idempotent requests may be sent this way, since a peer which failed to respond
+    /// could have processed the request anyway.
+    fn send_with_failover<T>(
+        &self,
+        prepare: impl Fn(&Self) -> (DefaultRequestBuilder, T),
+    ) -> Result<(Response<Vec<u8>>, T)> {
+        let mut last_error = None;
+        for torii_url in self.endpoints() {
+            let (request, output) = prepare(&self.at(&torii_url));
+            match request.with_tls(self.tls.as_ref()).build()?.send() {
+                Ok(response) => return Ok((response, output)),
+                Err(error) => {
+                    self.endpoints.mark_unreachable(&torii_url);
+                    last_error = Some(error);
+                }
+            }
         }
+        Err(last_error.expect("There is at least one endpoint"))
     }
 
     /// Builds transaction out of supplied instructions or wasm.
@@ -455,6 +621,17 @@ impl Client {
         instructions: impl Into<Executable>,
         metadata: UnlimitedMetadata,
     ) -> SignedTransaction {
+        self.build_unsigned_transaction(instructions, metadata)
+            .sign(&self.key_pair)
+    }
+
+    /// Builds transaction out of supplied instructions or wasm without signing it,
+    /// so that it can be signed with a key pair which is kept elsewhere.
+    pub fn build_unsigned_transaction(
+        &self,
+        instructions: impl Into<Executable>,
+        metadata: UnlimitedMetadata,
+    ) -> TransactionBuilder {
         let tx_builder = TransactionBuilder::new(self.chain_id.clone(), self.account_id.clone());
 
         let mut tx_builder = match instructions.into() {
@@ -470,7 +647,7 @@ impl Client {
             tx_builder.set_nonce(nonce);
         };
 
-        tx_builder.with_metadata(metadata).sign(&self.key_pair)
+        tx_builder.with_metadata(metadata)
     }
 
     /// Signs transaction
@@ -481,6 +658,82 @@ impl Client {
         transaction.sign(&self.key_pair)
     }
 
+    /// Co-sign multisig transaction and submit it so that the signature is added
+    /// to the signatures collected by the peer so far.
+    /// Returns hash of the submitted transaction or error string.
+    ///
+    /// Pending multisig transactions are kept by the peer which received them and aren't gossiped,
+    /// so all co-signers have to submit their signatures to the same peer.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub fn cosign_transaction(
+        &self,
+        transaction: SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_transaction(&self.sign_transaction(transaction))
+    }
+
+    /// Get multisig transactions of the given account which are waiting for co-signers.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_pending_transactions(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Vec<SignedTransaction>> {
+        let (resp, ()) = self.send_with_failover(|client| {
+            let request = DefaultRequestBuilder::new(
+                HttpMethod::GET,
+                client
+                    .torii_url
+                    .join(torii_uri::PENDING_TRANSACTIONS)
+                    .expect("Valid URI"),
+            )
+            .headers(&client.headers)
+            .param("account", account_id);
+            (request, ())
+        })?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get pending transactions with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Vec::<SignedTransaction>::decode_all(&mut resp.body().as_slice())
+            .wrap_err("Failed to decode pending transactions")
+    }
+
+    /// Get the status of the transaction with the given `hash`.
+    /// Returns `None` if the transaction isn't known to the peer.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_transaction_status(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Result<Option<TransactionEvent>> {
+        let (resp, ()) = self
+            .send_with_failover(|client| (client.prepare_transaction_status_request(hash), ()))?;
+        TransactionResponseHandler::handle_status(&resp)
+    }
+
+    /// Prepares http-request to implement [`Self::get_transaction_status`] on your own.
+    pub(crate) fn prepare_transaction_status_request<B: RequestBuilder>(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> B {
+        B::new(
+            HttpMethod::GET,
+            self.torii_url
+                .join(&format!("{}/{hash}", torii_uri::TRANSACTION))
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+    }
+
     /// Signs query
     ///
     /// # Errors
@@ -549,15 +802,71 @@ impl Client {
         transaction: &SignedTransaction,
     ) -> Result<HashOf<SignedTransaction>> {
         iroha_logger::trace!(tx=?transaction, "Submitting");
-        let (req, hash) = self.prepare_transaction_request::<DefaultRequestBuilder>(transaction);
-        let response = req
-            .build()?
-            .send()
+        let hash = transaction.hash();
+        // Peers deduplicate transactions by their hash, so resending one to another peer is safe
+        let (response, _) = self
+            .send_with_failover(|client| client.prepare_transaction_request(transaction))
             .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
         TransactionResponseHandler::handle(&response)?;
         Ok(hash)
     }
 
+    /// Run a prebuilt transaction against the current state of the peer without submitting it.
+    /// Returns either the data events the transaction would produce or the reason why it would be rejected.
+    ///
+    /// The transaction must be signed by the signatories of its authority as if it was submitted.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub fn simulate_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionSimulationResult> {
+        self.simulate(&TransactionToSimulate::Signed(transaction.clone()))
+    }
+
+    /// Run a transaction which isn't signed against the current state of the peer without submitting it,
+    /// e.g. to check a transaction before sending it to the signatories of its authority.
+    /// Returns the same as [`Self::simulate_transaction`], except that signatories aren't checked.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub fn simulate_unsigned_transaction(
+        &self,
+        transaction: &TransactionBuilder,
+    ) -> Result<TransactionSimulationResult> {
+        self.simulate(&TransactionToSimulate::Unsigned(transaction.clone()))
+    }
+
+    fn simulate(&self, transaction: &TransactionToSimulate) -> Result<TransactionSimulationResult> {
+        iroha_logger::trace!(tx=?transaction, "Simulating");
+        let (response, ()) = self
+            .send_with_failover(|client| {
+                let request = DefaultRequestBuilder::new(
+                    HttpMethod::POST,
+                    client
+                        .torii_url
+                        .join(torii_uri::TRANSACTION_SIMULATION)
+                        .expect("Valid URI"),
+                )
+                .headers(client.headers.clone())
+                .body(transaction.encode());
+                (request, ())
+            })
+            .wrap_err("Failed to send transaction for simulation")?;
+
+        if response.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to simulate transaction with HTTP status: {}. {}",
+                response.status(),
+                std::str::from_utf8(response.body()).unwrap_or(""),
+            ));
+        }
+
+        TransactionSimulationResult::decode_all(&mut response.body().as_slice())
+            .wrap_err("Failed to decode transaction simulation result")
+    }
+
     /// Submit the prebuilt transaction and wait until it is either rejected or committed.
     /// If rejected, return the rejection reason.
     ///
@@ -569,6 +878,7 @@ impl Client {
     ) -> Result<HashOf<SignedTransaction>> {
         let (init_sender, init_receiver) = tokio::sync::oneshot::channel();
         let hash = transaction.hash();
+        self.check_health();
 
         thread::scope(|spawner| {
             let submitter_handle = spawner.spawn(move || -> Result<()> {
@@ -604,19 +914,14 @@ impl Client {
 
         rt.block_on(async {
             let mut event_iterator = {
-                let filters = vec![
-                    TransactionEventFilter::default().for_hash(hash).into(),
-                    PipelineEventFilterBox::from(
-                        BlockEventFilter::default().for_status(BlockStatus::Applied),
-                    ),
-                ];
-
-                let event_iterator_result =
-                    tokio::time::timeout_at(deadline, self.listen_for_events_async(filters))
-                        .await
-                        .map_err(Into::into)
-                        .and_then(std::convert::identity)
-                        .wrap_err("Failed to establish event listener connection");
+                let event_iterator_result = tokio::time::timeout_at(
+                    deadline,
+                    self.listen_for_events_async(Self::tx_confirmation_filters(hash)),
+                )
+                .await
+                .map_err(Into::into)
+                .and_then(std::convert::identity)
+                .wrap_err("Failed to establish event listener connection");
                 let _send_result = init_sender.send(event_iterator_result.is_ok());
                 event_iterator_result?
             };
@@ -633,7 +938,19 @@ impl Client {
         })
     }
 
-    async fn listen_for_tx_confirmation_loop(
+    /// Filters of the events which report the outcome of the transaction with the given `hash`
+    pub(crate) fn tx_confirmation_filters(
+        hash: HashOf<SignedTransaction>,
+    ) -> Vec<PipelineEventFilterBox> {
+        vec![
+            TransactionEventFilter::default().for_hash(hash).into(),
+            PipelineEventFilterBox::from(
+                BlockEventFilter::default().for_status(BlockStatus::Applied),
+            ),
+        ]
+    }
+
+    pub(crate) async fn listen_for_tx_confirmation_loop(
         event_iterator: &mut AsyncEventStream,
         hash: HashOf<SignedTransaction>,
     ) -> Result<HashOf<SignedTransaction>> {
@@ -677,7 +994,7 @@ impl Client {
     /// it is better to use a response handler anyway. It allows to abstract from implementation details.
     ///
     /// For general usage example see [`Client::prepare_query_request`].
-    fn prepare_transaction_request<B: RequestBuilder>(
+    pub(crate) fn prepare_transaction_request<B: RequestBuilder>(
         &self,
         transaction: &SignedTransaction,
     ) -> (B, HashOf<SignedTransaction>) {
@@ -791,11 +1108,11 @@ impl Client {
     /// async fn fetch_accounts(client: &Client) -> Result<Vec<Account>> {
     ///     // Put `YourAsyncRequest` as a type here
     ///     // It returns the request and the handler (zero-cost abstraction) for the response
-    ///     let (req, resp_handler) = client.prepare_query_request::<_, YourAsyncRequest>(
-    ///         FindAllAccounts::new(),
-    ///         Pagination::default(),
-    ///         PredicateBox::default(),
-    ///     )?;
+    ///     let (req, resp_handler) = client.prepare_query_request::<Vec<Account>>(
+    ///         ClientQueryBuilder::new(FindAllAccounts::new(), client.account_id.clone())
+    ///             .with_pagination(Pagination::default())
+    ///             .with_filter(PredicateBox::default()),
+    ///     );
     ///
     ///     // Do what you need to send the request and to get the response
     ///     let resp = req.send().await;
@@ -806,29 +1123,11 @@ impl Client {
     ///     Ok(accounts.output())
     /// }
     /// ```
-    fn prepare_query_request<R: Query>(
+    fn prepare_query_request<O>(
         &self,
-        request: R,
-        filter: PredicateBox,
-        pagination: Pagination,
-        sorting: Sorting,
-        fetch_size: FetchSize,
-    ) -> (DefaultRequestBuilder, QueryResponseHandler<R::Output>)
-    where
-        <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
-    {
-        let query_builder = ClientQueryBuilder::new(request, self.account_id.clone())
-            .with_filter(filter)
-            .with_pagination(pagination)
-            .with_sorting(sorting)
-            .with_fetch_size(fetch_size);
-        let request = self.sign_query(query_builder);
-
-        let query_request = QueryRequest {
-            torii_url: self.torii_url.clone(),
-            headers: self.headers.clone(),
-            request: crate::data_model::query::QueryRequest::Query(request),
-        };
+        query_builder: ClientQueryBuilder,
+    ) -> (DefaultRequestBuilder, QueryResponseHandler<O>) {
+        let query_request = self.query_request(query_builder);
 
         (
             query_request.clone().assemble(),
@@ -836,29 +1135,36 @@ impl Client {
         )
     }
 
-    /// Create a request with pagination, sorting and add the filter.
+    /// Sign the query configured by `query_builder` and make a request out of it
+    pub(crate) fn query_request(&self, query_builder: ClientQueryBuilder) -> QueryRequest {
+        QueryRequest {
+            torii_url: self.torii_url.clone(),
+            headers: self.headers.clone(),
+            tls: self.tls.clone(),
+            request: crate::data_model::query::QueryRequest::Query(self.sign_query(query_builder)),
+        }
+    }
+
+    /// Create a request for the query configured by `query_builder`.
     ///
     /// # Errors
     /// Fails if sending request fails
-    pub(crate) fn request_with_filter_and_pagination_and_sorting<R: Query + Debug>(
+    pub(crate) fn request_with_query_builder<O>(
         &self,
-        request: R,
-        pagination: Pagination,
-        fetch_size: FetchSize,
-        sorting: Sorting,
-        filter: PredicateBox,
-    ) -> QueryResult<<R::Output as QueryOutput>::Target>
+        query_builder: &ClientQueryBuilder,
+    ) -> QueryResult<O::Target>
     where
-        R::Output: QueryOutput,
-        <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+        O: QueryOutput,
+        <O as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
     {
-        iroha_logger::trace!(?request, %pagination, ?sorting, ?filter);
-        let (req, mut resp_handler) =
-            self.prepare_query_request::<R>(request, filter, pagination, sorting, fetch_size);
-
-        let response = req.build()?.send()?;
+        iroha_logger::trace!(?query_builder);
+        // The handler keeps the endpoint of the peer which responded,
+        // so that the cursor of the query is used with the peer that holds it
+        let (response, mut resp_handler) = self.send_with_failover(|client| {
+            client.prepare_query_request::<O>(query_builder.clone())
+        })?;
         let output = resp_handler.handle(&response)?;
-        let output = QueryOutput::new(output, resp_handler);
+        let output = O::new(output, resp_handler);
 
         Ok(output)
     }
@@ -876,6 +1182,76 @@ impl Client {
         self.build_query(request).execute()
     }
 
+    /// Send `queries` to the peer in a single request.
+    ///
+    /// All the queries are evaluated against the same state, so their results are consistent
+    /// with each other. The result of each query is returned in the order of `queries`,
+    /// with all the results of iterable queries fetched.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or if the peer rejects the whole batch
+    pub fn request_batch(
+        &self,
+        queries: impl IntoIterator<Item = QueryBox>,
+    ) -> QueryResult<Vec<QueryResult<QueryOutputBox>>> {
+        let batch =
+            QueryBatch::new(queries.into_iter().map(|query| {
+                self.sign_query(ClientQueryBuilder::new(query, self.account_id.clone()))
+            }));
+        iroha_logger::trace!(?batch);
+        let (response, torii_url) = self.send_with_failover(|client| {
+            let request = DefaultRequestBuilder::new(
+                HttpMethod::POST,
+                client
+                    .torii_url
+                    .join(torii_uri::QUERY_BATCH)
+                    .expect("Valid URI"),
+            )
+            .headers(client.headers.clone())
+            .body(batch.encode());
+            (request, client.torii_url.clone())
+        })?;
+        if response.status() != StatusCode::OK {
+            return Err(query_failure(&response));
+        }
+
+        let responses = QueryBatchResponse::decode_all(&mut response.body().as_slice()).wrap_err(
+            "Failed to decode response from Iroha. \
+             You are likely using a version of the client library \
+             that is incompatible with the version of the peer software",
+        )?;
+
+        Ok(responses
+            .into_iter()
+            .map(|response| self.at(&torii_url).fetch_remaining(response?))
+            .collect())
+    }
+
+    /// Fetch all the results of a query following its first `response`.
+    fn fetch_remaining(
+        &self,
+        response: BatchedResponse<QueryOutputBox>,
+    ) -> QueryResult<QueryOutputBox> {
+        let (output, cursor) = response.into();
+        let QueryOutputBox::Vec(first_batch) = output else {
+            return Ok(output);
+        };
+
+        let results = ResultSet {
+            query_handler: QueryResponseHandler::new(QueryRequest {
+                torii_url: self.torii_url.clone(),
+                headers: self.headers.clone(),
+                tls: self.tls.clone(),
+                request: crate::data_model::query::QueryRequest::Cursor(cursor),
+            }),
+            iter: first_batch,
+            client_cursor: 0,
+        };
+        results
+            .collect::<QueryResult<Vec<_>>>()
+            .map(QueryOutputBox::Vec)
+    }
+
     /// Query API entry point using cursor.
     ///
     /// You should probably not use this function directly.
@@ -894,6 +1270,7 @@ impl Client {
         let request = QueryRequest {
             torii_url: self.torii_url.clone(),
             headers: self.headers.clone(),
+            tls: self.tls.clone(),
             request: crate::data_model::query::QueryRequest::Cursor(cursor),
         };
         let response = request.clone().assemble().build()?.send()?;
@@ -916,7 +1293,7 @@ impl Client {
         R::Output: QueryOutput,
         <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
     {
-        QueryRequestBuilder::new(self, request)
+        QueryRequestBuilder::new(self, self.account_id.clone(), request)
     }
 
     /// Connect (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
@@ -928,7 +1305,29 @@ impl Client {
         &self,
         event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
     ) -> Result<impl Iterator<Item = Result<EventBox>>> {
-        events_api::EventIterator::new(self.events_handler(event_filters)?)
+        self.check_health();
+        events_api::EventIterator::new(self.events_handler(event_filters)?, self.tls.as_ref())
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` events starting from `cursor`.
+    ///
+    /// Events of the committed blocks are replayed first and then the live events follow.
+    /// Events come with their cursors, so a subscription can be resumed
+    /// from [`EventCursor::next`] of the latest received event without losing or repeating events.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::events_handler`]
+    /// - Forwards from [`events_api::EventMessageIterator::new`]
+    pub fn replay_events(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+        cursor: EventCursor,
+    ) -> Result<impl Iterator<Item = Result<EventMessage>>> {
+        self.check_health();
+        events_api::EventMessageIterator::new(
+            self.events_handler(event_filters)?.starting_from(cursor),
+            self.tls.as_ref(),
+        )
     }
 
     /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
@@ -940,7 +1339,8 @@ impl Client {
         &self,
         event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
     ) -> Result<AsyncEventStream> {
-        events_api::AsyncEventStream::new(self.events_handler(event_filters)?).await
+        events_api::AsyncEventStream::new(self.events_handler(event_filters)?, self.tls.as_ref())
+            .await
     }
 
     /// Constructs an Events API handler. With it, you can use any WS client you want.
@@ -955,7 +1355,7 @@ impl Client {
         events_api::flow::Init::new(
             event_filters.into_iter().map(Into::into).collect(),
             self.headers.clone(),
-            self.torii_url
+            self.preferred_endpoint()
                 .join(torii_uri::SUBSCRIPTION)
                 .expect("Valid URI"),
         )
@@ -970,7 +1370,8 @@ impl Client {
         &self,
         height: NonZeroU64,
     ) -> Result<impl Iterator<Item = Result<SignedBlock>>> {
-        blocks_api::BlockIterator::new(self.blocks_handler(height)?)
+        self.check_health();
+        blocks_api::BlockIterator::new(self.blocks_handler(height)?, self.tls.as_ref())
     }
 
     /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` blocks
@@ -979,7 +1380,7 @@ impl Client {
     /// - Forwards from [`Self::events_handler`]
     /// - Forwards from [`blocks_api::BlockIterator::new`]
     pub async fn listen_for_blocks_async(&self, height: NonZeroU64) -> Result<AsyncBlockStream> {
-        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?).await
+        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?, self.tls.as_ref()).await
     }
 
     /// Construct a handler for Blocks API. With this handler you can use any WS client you want.
@@ -991,7 +1392,7 @@ impl Client {
         blocks_api::flow::Init::new(
             height,
             self.headers.clone(),
-            self.torii_url
+            self.preferred_endpoint()
                 .join(torii_uri::BLOCKS_STREAM)
                 .expect("Valid URI"),
         )
@@ -1002,25 +1403,25 @@ impl Client {
     /// # Errors
     /// Fails if sending request or decoding fails
     pub fn get_config(&self) -> Result<ConfigDTO> {
-        let resp = DefaultRequestBuilder::new(
+        let resp = self
+            .prepare_get_config_request::<DefaultRequestBuilder>()
+            .with_tls(self.tls.as_ref())
+            .build()?
+            .send()?;
+        ConfigResponseHandler::handle_get(&resp)
+    }
+
+    /// Prepares http-request to implement [`Self::get_config`] on your own.
+    pub(crate) fn prepare_get_config_request<B: RequestBuilder>(&self) -> B {
+        B::new(
             HttpMethod::GET,
             self.torii_url
                 .join(torii_uri::CONFIGURATION)
                 .expect("Valid URI"),
         )
         .headers(&self.headers)
+        .headers(self.admin_headers(&HttpMethod::GET, torii_uri::CONFIGURATION, &[]))
         .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
-        .build()?
-        .send()?;
-
-        if resp.status() != StatusCode::OK {
-            return Err(eyre!(
-                "Failed to get configuration with HTTP status: {}. {}",
-                resp.status(),
-                std::str::from_utf8(resp.body()).unwrap_or(""),
-            ));
-        }
-        serde_json::from_slice(resp.body()).wrap_err("Failed to decode body")
     }
 
     /// Send a request to change the configuration of a specified field.
@@ -1028,27 +1429,29 @@ impl Client {
     /// # Errors
     /// If sending request or decoding fails
     pub fn set_config(&self, dto: ConfigDTO) -> Result<()> {
+        let resp = self
+            .prepare_set_config_request::<DefaultRequestBuilder>(dto)?
+            .with_tls(self.tls.as_ref())
+            .build()?
+            .send()?;
+        ConfigResponseHandler::handle_set(&resp)
+    }
+
+    /// Prepares http-request to implement [`Self::set_config`] on your own.
+    pub(crate) fn prepare_set_config_request<B: RequestBuilder>(
+        &self,
+        dto: ConfigDTO,
+    ) -> Result<B> {
         let body = serde_json::to_vec(&dto).wrap_err(format!("Failed to serialize {dto:?}"))?;
         let url = self
             .torii_url
             .join(torii_uri::CONFIGURATION)
             .expect("Valid URI");
-        let resp = DefaultRequestBuilder::new(HttpMethod::POST, url)
+        Ok(B::new(HttpMethod::POST, url)
             .headers(&self.headers)
+            .headers(self.admin_headers(&HttpMethod::POST, torii_uri::CONFIGURATION, &body))
             .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
-            .body(body)
-            .build()?
-            .send()?;
-
-        if resp.status() != StatusCode::ACCEPTED {
-            return Err(eyre!(
-                "Failed to post configuration with HTTP status: {}. {}",
-                resp.status(),
-                std::str::from_utf8(resp.body()).unwrap_or(""),
-            ));
-        };
-
-        Ok(())
+            .body(body))
     }
 
     /// Gets network status seen from the peer
@@ -1058,10 +1461,10 @@ impl Client {
     pub fn get_status(&self) -> Result<Status> {
         let req = self
             .prepare_status_request::<DefaultRequestBuilder>()
-            .header(http::header::ACCEPT, "application/x-parity-scale");
+            .header(http::header::ACCEPT, "application/x-parity-scale")
+            .with_tls(self.tls.as_ref());
         let resp = req.build()?.send()?;
-        let scaled_resp = StatusResponseHandler::handle_raw(&resp).cloned()?;
-        DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
+        StatusResponseHandler::handle_scale(&resp)
     }
 
     /// Prepares http-request to implement [`Self::get_status`] on your own.
@@ -1076,22 +1479,97 @@ impl Client {
             self.torii_url.join(torii_uri::STATUS).expect("Valid URI"),
         )
         .headers(self.headers.clone())
+        .headers(self.admin_headers(&HttpMethod::GET, torii_uri::STATUS, &[]))
+    }
+
+    /// Prepares http-request to get the readiness report of the peer,
+    /// which doesn't require the admin authentication unlike [`Self::prepare_status_request`].
+    pub(crate) fn prepare_ready_request<B: RequestBuilder>(&self) -> B {
+        B::new(
+            HttpMethod::GET,
+            self.torii_url.join(torii_uri::READY).expect("Valid URI"),
+        )
+        .headers(self.headers.clone())
+    }
+
+    /// Gets the peers connected to the peer, with their connection diagnostics
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_peers(&self) -> Result<PeersStatus> {
+        let resp = self
+            .prepare_peers_request::<DefaultRequestBuilder>()
+            .with_tls(self.tls.as_ref())
+            .build()?
+            .send()?;
+        StatusResponseHandler::handle_scale(&resp)
+    }
+
+    /// Prepares http-request to implement [`Self::get_peers`] on your own.
+    pub(crate) fn prepare_peers_request<B: RequestBuilder>(&self) -> B {
+        B::new(
+            HttpMethod::GET,
+            self.torii_url.join(torii_uri::PEERS).expect("Valid URI"),
+        )
+        .headers(self.headers.clone())
+        .headers(self.admin_headers(&HttpMethod::GET, torii_uri::PEERS, &[]))
+        .header(http::header::ACCEPT, "application/x-parity-scale")
+    }
+
+    /// Headers which authenticate the request to an administrative endpoint
+    /// with the key pair of the client, see [`torii_admin`].
+    fn admin_headers(
+        &self,
+        method: &HttpMethod,
+        path: &str,
+        body: &[u8],
+    ) -> [(&'static str, String); 4] {
+        let timestamp_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |since_epoch| {
+                u64::try_from(since_epoch.as_millis()).unwrap_or(u64::MAX)
+            });
+        let nonce = hex::encode(rand::thread_rng().gen::<[u8; 16]>());
+        // Same as the `Host` header sent by the HTTP client
+        let host = self.torii_url.host_str().unwrap_or_default();
+        let host = self
+            .torii_url
+            .port()
+            .map_or_else(|| host.to_owned(), |port| format!("{host}:{port}"));
+        let payload =
+            torii_admin::payload(method.as_str(), &host, path, timestamp_ms, &nonce, body);
+        let signature = Signature::new(&self.key_pair, &payload);
+
+        [
+            (
+                torii_admin::PUBLIC_KEY_HEADER,
+                self.key_pair.public_key().to_string(),
+            ),
+            (torii_admin::TIMESTAMP_HEADER, timestamp_ms.to_string()),
+            (torii_admin::NONCE_HEADER, nonce),
+            (
+                torii_admin::SIGNATURE_HEADER,
+                hex::encode(signature.payload()),
+            ),
+        ]
     }
 }
 
 /// Logic for `sync` and `async` Iroha websocket streams
 pub mod stream_api {
     use futures_util::{SinkExt, Stream, StreamExt};
+    use tungstenite::protocol::frame::coding::CloseCode;
 
     use super::*;
     use crate::{
         http::ws::conn_flow::{Events, Init, InitData},
-        http_default::DefaultWebSocketRequestBuilder,
+        http_default::{DefaultWebSocketRequestBuilder, WebSocketDecoder},
     };
 
     /// Iterator for getting messages from the `WebSocket` stream.
     pub(super) struct SyncIterator<E> {
         stream: WebSocketStream,
+        decoder: WebSocketDecoder,
         handler: E,
     }
 
@@ -1106,6 +1584,7 @@ pub mod stream_api {
         /// - Message is an error
         pub fn new<I: Init<DefaultWebSocketRequestBuilder>>(
             handler: I,
+            tls: Option<&ToriiTls>,
         ) -> Result<SyncIterator<I::Next>> {
             trace!("Creating `SyncIterator`");
             let InitData {
@@ -1114,12 +1593,13 @@ pub mod stream_api {
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect()?;
+            let (mut stream, decoder) = req.build()?.with_tls(tls).connect()?;
             stream.send(WebSocketMessage::Binary(first_message))?;
 
             trace!("`SyncIterator` created successfully");
             Ok(SyncIterator {
                 stream,
+                decoder,
                 handler: next_handler,
             })
         }
@@ -1132,7 +1612,14 @@ pub mod stream_api {
             loop {
                 match self.stream.read() {
                     Ok(WebSocketMessage::Binary(message)) => {
-                        return Some(self.handler.message(message))
+                        return Some(
+                            self.decoder
+                                .decode(message)
+                                .and_then(|message| self.handler.message(message)),
+                        )
+                    }
+                    Ok(WebSocketMessage::Close(Some(frame))) if frame.code == CloseCode::Policy => {
+                        return Some(Err(eyre!("Subscription rejected: {}", frame.reason)))
                     }
                     Ok(_) => continue,
                     Err(WebSocketError::ConnectionClosed | WebSocketError::AlreadyClosed) => {
@@ -1166,6 +1653,7 @@ pub mod stream_api {
     /// Async stream for getting messages from the `WebSocket` stream.
     pub struct AsyncStream<E> {
         stream: AsyncWebSocketStream,
+        decoder: WebSocketDecoder,
         handler: E,
     }
 
@@ -1181,6 +1669,7 @@ pub mod stream_api {
         #[allow(clippy::future_not_send)]
         pub async fn new<I: Init<DefaultWebSocketRequestBuilder>>(
             handler: I,
+            tls: Option<&ToriiTls>,
         ) -> Result<AsyncStream<I::Next>> {
             trace!("Creating `AsyncStream`");
             let InitData {
@@ -1189,12 +1678,13 @@ pub mod stream_api {
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect_async().await?;
+            let (mut stream, decoder) = req.build()?.with_tls(tls).connect_async().await?;
             stream.send(WebSocketMessage::Binary(first_message)).await?;
 
             trace!("`AsyncStream` created successfully");
             Ok(AsyncStream {
                 stream,
+                decoder,
                 handler: next_handler,
             })
         }
@@ -1231,7 +1721,18 @@ pub mod stream_api {
         ) -> std::task::Poll<Option<Self::Item>> {
             match futures_util::ready!(self.stream.poll_next_unpin(cx)) {
                 Some(Ok(WebSocketMessage::Binary(message))) => {
-                    std::task::Poll::Ready(Some(self.handler.message(message)))
+                    let message = self.decoder.decode(message);
+                    std::task::Poll::Ready(Some(
+                        message.and_then(|message| self.handler.message(message)),
+                    ))
+                }
+                Some(Ok(WebSocketMessage::Close(Some(frame))))
+                    if frame.code == CloseCode::Policy =>
+                {
+                    std::task::Poll::Ready(Some(Err(eyre!(
+                        "Subscription rejected: {}",
+                        frame.reason
+                    ))))
                 }
                 Some(Ok(_)) => std::task::Poll::Pending,
                 Some(Err(err)) => std::task::Poll::Ready(Some(Err(err.into()))),
@@ -1281,6 +1782,11 @@ pub mod events_api {
                     filters,
                 })
             }
+
+            /// Replay the events of the committed blocks starting from `cursor` before the live ones.
+            pub fn starting_from(self, cursor: EventCursor) -> ReplayInit {
+                ReplayInit { init: self, cursor }
+            }
         }
 
         impl<R: RequestBuilder> FlowInit<R> for Init {
@@ -1298,6 +1804,38 @@ pub mod events_api {
             }
         }
 
+        /// Initialization struct for Events API flow which starts with the replay of committed blocks.
+        pub struct ReplayInit {
+            init: Init,
+            /// Cursor of the first event to receive
+            cursor: EventCursor,
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for ReplayInit {
+            type Next = EventMessages;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let Self {
+                    init:
+                        Init {
+                            url,
+                            headers,
+                            filters,
+                        },
+                    cursor,
+                } = self;
+
+                let msg = EventSubscriptionRequest::new(filters)
+                    .starting_from(cursor)
+                    .encode();
+                InitData::new(
+                    R::new(HttpMethod::GET, url).headers(headers),
+                    msg,
+                    EventMessages,
+                )
+            }
+        }
+
         /// Events handler for Events API flow
         #[derive(Debug, Copy, Clone)]
         pub struct Events;
@@ -1310,16 +1848,31 @@ pub mod events_api {
                 Ok(event_socket_message.into())
             }
         }
+
+        /// Events handler for Events API flow which keeps the cursors of the events
+        #[derive(Debug, Copy, Clone)]
+        pub struct EventMessages;
+
+        impl FlowEvents for EventMessages {
+            type Event = EventMessage;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                EventMessage::decode_all(&mut message.as_slice()).map_err(Into::into)
+            }
+        }
     }
 
     /// Iterator for getting events from the `WebSocket` stream.
     pub(super) type EventIterator = stream_api::SyncIterator<flow::Events>;
 
+    /// Iterator for getting events together with their cursors from the `WebSocket` stream.
+    pub(super) type EventMessageIterator = stream_api::SyncIterator<flow::EventMessages>;
+
     /// Async stream for getting events from the `WebSocket` stream.
     pub type AsyncEventStream = stream_api::AsyncStream<flow::Events>;
 }
 
-mod blocks_api {
+pub(crate) mod blocks_api {
     use super::*;
     use crate::http::ws::{
         conn_flow::{Events as FlowEvents, Init as FlowInit, InitData},
@@ -1502,12 +2055,27 @@ pub mod transaction {
     pub fn by_hash(hash: HashOf<SignedTransaction>) -> FindTransactionByHash {
         FindTransactionByHash::new(hash)
     }
+
+    /// Construct a query to retrieve transaction by hash with the proof of its inclusion into the block
+    pub fn proof_by_hash(hash: HashOf<SignedTransaction>) -> FindTransactionProofByHash {
+        FindTransactionProofByHash::new(hash)
+    }
 }
 
 pub mod trigger {
     //! Module with queries for triggers
     use super::*;
 
+    /// Construct a query to get ids of all active triggers
+    pub const fn all_ids() -> FindAllActiveTriggerIds {
+        FindAllActiveTriggerIds
+    }
+
+    /// Construct a query to get trigger by its id
+    pub fn by_id(trigger_id: TriggerId) -> FindTriggerById {
+        FindTriggerById::new(trigger_id)
+    }
+
     /// Construct a query to get triggers by domain id
     pub fn by_domain_id(domain_id: DomainId) -> FindTriggersByDomainId {
         FindTriggersByDomainId::new(domain_id)
@@ -1592,10 +2160,12 @@ mod tests {
             key_pair,
             account_id,
             torii_api_url: "http://127.0.0.1:8080".parse().unwrap(),
+            additional_torii_api_urls: Vec::new(),
             basic_auth: None,
             transaction_add_nonce: false,
             transaction_ttl: Duration::from_secs(5),
             transaction_status_timeout: Duration::from_secs(10),
+            torii_tls: None,
         }
     }
 
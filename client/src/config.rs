@@ -5,7 +5,7 @@ use std::{path::Path, time::Duration};
 
 use derive_more::Display;
 use error_stack::ResultExt;
-use eyre::Result;
+use eyre::{eyre, Result};
 use iroha_config_base::{read::ConfigReader, toml::TomlSource};
 use iroha_crypto::KeyPair;
 use iroha_data_model::{prelude::*, ChainId};
@@ -61,9 +61,95 @@ pub struct Config {
     pub key_pair: KeyPair,
     pub basic_auth: Option<BasicAuth>,
     pub torii_api_url: Url,
+    pub additional_torii_api_urls: Vec<Url>,
     pub transaction_ttl: Duration,
     pub transaction_status_timeout: Duration,
     pub transaction_add_nonce: bool,
+    #[serde(skip)]
+    pub torii_tls: Option<ToriiTls>,
+}
+
+/// Custom TLS settings used to connect to Torii over `https` and `wss`
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "__tls-rustls"), allow(missing_copy_implementations))]
+pub struct ToriiTls {
+    #[cfg(feature = "__tls-rustls")]
+    pub(crate) client_config: std::sync::Arc<rustls::ClientConfig>,
+    /// Trusted CA certificates, for the HTTP clients which can't take a prepared `client_config`
+    #[cfg(feature = "__tls-rustls")]
+    pub(crate) ca_certificates: Vec<rustls::pki_types::CertificateDer<'static>>,
+    /// PEM of the client certificate chain followed by its private key, if clients authenticate
+    #[cfg(feature = "__tls-rustls")]
+    pub(crate) identity_pem: Option<Vec<u8>>,
+}
+
+impl ToriiTls {
+    /// Trusts the CA certificates from `ca_file` and, if `identity` is provided,
+    /// authenticates with the certificate chain and private key from the given files.
+    ///
+    /// The blocking HTTP requests can't present a client certificate, so with `identity`
+    /// they fail with an error and only websockets and [`AsyncClient`](crate::async_client::AsyncClient)
+    /// are able to connect.
+    ///
+    /// # Errors
+    /// - unable to read or parse the PEM files
+    /// - the client is built without `rustls` support
+    #[cfg(feature = "__tls-rustls")]
+    pub fn load(ca_file: &Path, identity: Option<(&Path, &Path)>) -> Result<Self> {
+        use std::sync::Arc;
+
+        use eyre::WrapErr as _;
+
+        fn read(path: &Path) -> Result<Vec<u8>> {
+            std::fs::read(path).wrap_err_with(|| format!("Failed to read `{}`", path.display()))
+        }
+
+        let ca_certificates = rustls_pemfile::certs(&mut read(ca_file)?.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .wrap_err_with(|| format!("Invalid certificate in `{}`", ca_file.display()))?;
+        let mut roots = rustls::RootCertStore::empty();
+        for cert in &ca_certificates {
+            roots.add(cert.clone())?;
+        }
+        let builder = rustls::ClientConfig::builder().with_root_certificates(roots);
+
+        let (client_config, identity_pem) = match identity {
+            None => (builder.with_no_client_auth(), None),
+            Some((cert_file, key_file)) => {
+                let cert_pem = read(cert_file)?;
+                let key_pem = read(key_file)?;
+                let certs = rustls_pemfile::certs(&mut cert_pem.as_slice())
+                    .collect::<Result<Vec<_>, _>>()
+                    .wrap_err_with(|| {
+                        format!("Invalid certificate in `{}`", cert_file.display())
+                    })?;
+                let key = rustls_pemfile::private_key(&mut key_pem.as_slice())?
+                    .ok_or_else(|| eyre!("No private key found in `{}`", key_file.display()))?;
+                (
+                    builder.with_client_auth_cert(certs, key)?,
+                    Some([cert_pem, key_pem].join(&b'\n')),
+                )
+            }
+        };
+
+        Ok(Self {
+            client_config: Arc::new(client_config),
+            ca_certificates,
+            identity_pem,
+        })
+    }
+
+    /// Trusts the CA certificates from `ca_file` and, if `identity` is provided,
+    /// authenticates with the certificate chain and private key from the given files.
+    ///
+    /// # Errors
+    /// Always, since custom TLS settings are only supported with `rustls`
+    #[cfg(not(feature = "__tls-rustls"))]
+    pub fn load(_ca_file: &Path, _identity: Option<(&Path, &Path)>) -> Result<Self> {
+        Err(eyre!(
+            "Custom TLS settings require one of the `tls-rustls-*` features"
+        ))
+    }
 }
 
 /// An error type for [`Config::load`]
@@ -151,4 +237,43 @@ mod tests {
         let _ = with_scheme("https").expect("should be fine");
         let _ = with_scheme("ws").expect_err("not supported");
     }
+
+    #[test]
+    fn additional_torii_urls() {
+        fn with_urls(urls: &[&str]) -> error_stack::Result<Config, user::ParseError> {
+            let mut config = config_sample();
+            config.insert("additional_torii_urls".to_string(), urls.to_vec().into());
+            ConfigReader::new()
+                .with_toml_source(TomlSource::inline(config))
+                .read_and_complete::<user::Root>()
+                .unwrap()
+                .parse()
+        }
+
+        let config = with_urls(&["http://127.0.0.1:8081", "https://127.0.0.1:8082"])
+            .expect("should be fine");
+        assert_eq!(
+            config.additional_torii_api_urls,
+            vec![
+                "http://127.0.0.1:8081".parse::<Url>().unwrap(),
+                "https://127.0.0.1:8082".parse().unwrap()
+            ]
+        );
+        let _ = with_urls(&["http://127.0.0.1:8081", "ws://127.0.0.1:8082"])
+            .expect_err("not supported");
+    }
+
+    #[test]
+    fn torii_tls_cert_without_key() {
+        let _err = ConfigReader::new()
+            .with_toml_source(TomlSource::inline(config_sample()))
+            .with_env(MockEnv::from([
+                ("TORII_TLS_CA_FILE", "ca.pem"),
+                ("TORII_TLS_CERT_FILE", "client.pem"),
+            ]))
+            .read_and_complete::<user::Root>()
+            .unwrap()
+            .parse()
+            .expect_err("key file is missing");
+    }
 }
@@ -1,5 +1,7 @@
 //! User configuration view.
 
+use std::path::PathBuf;
+
 use error_stack::{Report, ResultExt};
 use iroha_config_base::{
     attach::ConfigValueAndOrigin,
@@ -19,11 +21,15 @@ pub struct Root {
     pub chain_id: ChainId,
     #[config(env = "TORII_URL")]
     pub torii_url: WithOrigin<Url>,
+    #[config(default)]
+    pub additional_torii_urls: WithOrigin<Vec<Url>>,
     pub basic_auth: Option<BasicAuth>,
     #[config(nested)]
     pub account: Account,
     #[config(nested)]
     pub transaction: Transaction,
+    #[config(nested)]
+    pub torii_tls: ToriiTls,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -34,6 +40,8 @@ pub enum ParseError {
     KeyPair,
     #[error("Unsupported URL scheme: `{scheme}`")]
     UnsupportedUrlScheme { scheme: String },
+    #[error("Invalid Torii TLS configuration")]
+    ToriiTls,
 }
 
 impl Root {
@@ -46,6 +54,7 @@ impl Root {
         let Self {
             chain_id,
             torii_url,
+            additional_torii_urls,
             basic_auth,
             account:
                 Account {
@@ -59,6 +68,7 @@ impl Root {
                     status_timeout: tx_timeout,
                     nonce: tx_add_nonce,
                 },
+            torii_tls,
         } = self;
 
         let mut emitter = Emitter::new();
@@ -85,6 +95,21 @@ impl Root {
                 .attach_printable("Note: only `http` and `https` protocols are supported"),
             ),
         }
+        for url in additional_torii_urls.value() {
+            match url.scheme() {
+                "http" | "https" => {}
+                scheme => emitter.emit(
+                    Report::new(ParseError::UnsupportedUrlScheme {
+                        scheme: scheme.to_string(),
+                    })
+                    .attach_printable(ConfigValueAndOrigin::new(
+                        url.clone(),
+                        additional_torii_urls.origin().clone(),
+                    ))
+                    .attach_printable("Note: only `http` and `https` protocols are supported"),
+                ),
+            }
+        }
 
         let (public_key, public_key_origin) = public_key.into_tuple();
         let (private_key, private_key_origin) = private_key.into_tuple();
@@ -95,6 +120,11 @@ impl Root {
             .change_context(ParseError::KeyPair)
             .ok_or_emit(&mut emitter);
 
+        let torii_tls = torii_tls
+            .load()
+            .change_context(ParseError::ToriiTls)
+            .ok_or_emit(&mut emitter);
+
         emitter.into_result()?;
 
         Ok(super::Config {
@@ -102,10 +132,12 @@ impl Root {
             account_id,
             key_pair: key_pair.unwrap(),
             torii_api_url: torii_url.into_value(),
+            additional_torii_api_urls: additional_torii_urls.into_value(),
             basic_auth,
             transaction_ttl: tx_ttl.into_value().get(),
             transaction_status_timeout: tx_timeout.into_value().get(),
             transaction_add_nonce: tx_add_nonce,
+            torii_tls: torii_tls.unwrap(),
         })
     }
 }
@@ -129,6 +161,64 @@ pub struct Transaction {
     pub nonce: bool,
 }
 
+#[derive(Debug, Clone, ReadConfig)]
+#[allow(missing_docs, clippy::struct_field_names)]
+pub struct ToriiTls {
+    #[config(env = "TORII_TLS_CA_FILE")]
+    pub ca_file: Option<WithOrigin<PathBuf>>,
+    #[config(env = "TORII_TLS_CERT_FILE")]
+    pub cert_file: Option<WithOrigin<PathBuf>>,
+    #[config(env = "TORII_TLS_KEY_FILE")]
+    pub key_file: Option<WithOrigin<PathBuf>>,
+}
+
+#[derive(thiserror::Error, Debug, Copy, Clone)]
+pub enum ToriiTlsError {
+    #[error("Invalid combination of provided parameters")]
+    Inconsistent,
+    #[error("Failed to load TLS files")]
+    Load,
+}
+
+impl ToriiTls {
+    fn load(self) -> error_stack::Result<Option<super::ToriiTls>, ToriiTlsError> {
+        let identity = match (self.cert_file, self.key_file) {
+            (None, None) => None,
+            (Some(cert_file), Some(key_file)) => Some((cert_file, key_file)),
+            (Some(_), None) => Err(ToriiTlsError::Inconsistent).attach_printable(
+                "`torii_tls.cert_file` is set, but `torii_tls.key_file` is not",
+            )?,
+            (None, Some(_)) => Err(ToriiTlsError::Inconsistent).attach_printable(
+                "`torii_tls.key_file` is set, but `torii_tls.cert_file` is not",
+            )?,
+        };
+        let Some(ca_file) = self.ca_file else {
+            if identity.is_some() {
+                return Err(Report::new(ToriiTlsError::Inconsistent).attach_printable(
+                    "`torii_tls.cert_file` and `torii_tls.key_file` are set, but `torii_tls.ca_file` is not",
+                ));
+            }
+            return Ok(None);
+        };
+
+        let ca_file = ca_file.resolve_relative_path();
+        let identity = identity.map(|(cert_file, key_file)| {
+            (
+                cert_file.resolve_relative_path(),
+                key_file.resolve_relative_path(),
+            )
+        });
+        super::ToriiTls::load(
+            &ca_file,
+            identity
+                .as_ref()
+                .map(|(cert_file, key_file)| (cert_file.as_path(), key_file.as_path())),
+        )
+        .map_err(|report| Report::new(ToriiTlsError::Load).attach_printable(format!("{report:#}")))
+        .map(Some)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -139,7 +229,12 @@ mod tests {
 
     #[test]
     fn parses_all_envs() {
-        let env = MockEnv::from([("TORII_URL", "http://localhost:8080")]);
+        let env = MockEnv::from([
+            ("TORII_URL", "http://localhost:8080"),
+            ("TORII_TLS_CA_FILE", "ca.pem"),
+            ("TORII_TLS_CERT_FILE", "client.pem"),
+            ("TORII_TLS_KEY_FILE", "client.key"),
+        ]);
 
         let _ = ConfigReader::new()
             .with_env(env.clone())
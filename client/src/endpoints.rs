@@ -0,0 +1,240 @@
+//! Peer endpoints of the client and their health, which decides the peer a request is sent to.
+//!
+//! The health of the peers is checked with the unauthenticated `/ready` endpoint: peers which
+//! don't respond, aren't ready or lag behind the highest block known to the others
+//! are only used as the last resort.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use eyre::Result;
+use serde::Deserialize;
+use url::Url;
+
+use crate::http::Response;
+
+/// How long the result of a health check is trusted
+pub const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// Timeout of the `/ready` request of a single health check
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+/// Peers which are this many blocks behind the highest known one are considered lagging
+const MAX_BLOCKS_BEHIND: u64 = 1;
+
+/// Health of a peer as seen by its last health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Health {
+    /// The peer is ready and reported its block height
+    Height(u64),
+    /// The peer responded without reporting its readiness, e.g. because it doesn't support it
+    Reachable,
+    /// The peer reported that it isn't ready to serve requests
+    NotReady,
+    /// The peer didn't respond
+    Unreachable,
+}
+
+/// Part of the report sent by the `/ready` endpoint which is used by the health check
+#[derive(Debug, Deserialize)]
+struct Readiness {
+    height: u64,
+}
+
+impl Health {
+    /// Health of the peer which sent the `response` to the readiness request
+    pub fn of_ready_response(response: Result<Response<Vec<u8>>>) -> Self {
+        let Ok(response) = response else {
+            return Self::Unreachable;
+        };
+        match serde_json::from_slice::<Readiness>(response.body()) {
+            Ok(readiness) if response.status().is_success() => Self::Height(readiness.height),
+            // Readiness is reported with `503 Service Unavailable`
+            Ok(_) => Self::NotReady,
+            Err(_) => Self::Reachable,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    health: HashMap<Url, Health>,
+    checked_at: Option<Instant>,
+}
+
+/// Endpoints of the peers other than the primary one and the health of all of them.
+/// Shared between the clones of the client.
+#[derive(Debug, Default)]
+pub struct Endpoints {
+    additional: Vec<Url>,
+    state: Mutex<State>,
+}
+
+impl Endpoints {
+    pub fn new(additional: Vec<Url>) -> Self {
+        Self {
+            additional,
+            state: Mutex::default(),
+        }
+    }
+
+    /// All the endpoints starting with the `primary` one
+    pub fn all(&self, primary: &Url) -> Vec<Url> {
+        let mut endpoints = vec![primary.clone()];
+        for url in &self.additional {
+            if !endpoints.contains(url) {
+                endpoints.push(url.clone());
+            }
+        }
+        endpoints
+    }
+
+    /// Whether the health of the endpoints has to be checked before picking one of them.
+    /// A single endpoint is never checked as there is no other peer to pick.
+    pub fn needs_health_check(&self) -> bool {
+        !self.additional.is_empty()
+            && self.state().checked_at.map_or(true, |checked_at| {
+                checked_at.elapsed() >= HEALTH_CHECK_INTERVAL
+            })
+    }
+
+    /// Replace the health of the endpoints with the results of a fresh check
+    pub fn record_health_check(&self, health: impl IntoIterator<Item = (Url, Health)>) {
+        let mut state = self.state();
+        state.health = health.into_iter().collect();
+        state.checked_at = Some(Instant::now());
+    }
+
+    /// Deprioritize the endpoint until the next health check after a request to it failed
+    pub fn mark_unreachable(&self, url: &Url) {
+        if self.additional.is_empty() {
+            return;
+        }
+        iroha_logger::warn!(%url, "Peer is unreachable");
+        self.state().health.insert(url.clone(), Health::Unreachable);
+    }
+
+    /// Endpoints in the order the requests should be sent to them: healthy ones in the order
+    /// they are configured, then the lagging or not ready ones and finally the unreachable ones.
+    pub fn candidates(&self, primary: &Url) -> Vec<Url> {
+        let mut endpoints = self.all(primary);
+        let state = self.state();
+        let max_height = state
+            .health
+            .values()
+            .filter_map(|health| match health {
+                Health::Height(height) => Some(*height),
+                Health::Reachable | Health::NotReady | Health::Unreachable => None,
+            })
+            .max();
+        endpoints.sort_by_key(|url| match state.health.get(url) {
+            None | Some(Health::Reachable) => 0,
+            Some(Health::Height(height)) => {
+                u8::from(max_height.is_some_and(|max| height + MAX_BLOCKS_BEHIND < max))
+            }
+            Some(Health::NotReady) => 1,
+            Some(Health::Unreachable) => 2,
+        });
+        endpoints
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(port: u16) -> Url {
+        format!("http://127.0.0.1:{port}").parse().unwrap()
+    }
+
+    #[test]
+    fn single_endpoint_is_never_checked() {
+        let endpoints = Endpoints::default();
+        assert!(!endpoints.needs_health_check());
+
+        endpoints.mark_unreachable(&url(8080));
+        assert_eq!(endpoints.candidates(&url(8080)), vec![url(8080)]);
+    }
+
+    #[test]
+    fn endpoints_are_ordered_by_health() {
+        let endpoints = Endpoints::new(vec![url(8081), url(8080), url(8082), url(8083)]);
+        assert!(endpoints.needs_health_check());
+        assert_eq!(
+            endpoints.candidates(&url(8080)),
+            vec![url(8080), url(8081), url(8082), url(8083)]
+        );
+
+        endpoints.record_health_check([
+            (url(8080), Health::Height(5)),
+            (url(8081), Health::Unreachable),
+            (url(8082), Health::Height(7)),
+            (url(8083), Health::Reachable),
+        ]);
+        assert!(!endpoints.needs_health_check());
+        assert_eq!(
+            endpoints.candidates(&url(8080)),
+            vec![url(8082), url(8083), url(8080), url(8081)]
+        );
+
+        endpoints.record_health_check([
+            (url(8080), Health::NotReady),
+            (url(8081), Health::Unreachable),
+            (url(8082), Health::Height(7)),
+            (url(8083), Health::Reachable),
+        ]);
+        assert_eq!(
+            endpoints.candidates(&url(8080)),
+            vec![url(8082), url(8083), url(8080), url(8081)]
+        );
+
+        endpoints.mark_unreachable(&url(8082));
+        assert_eq!(
+            endpoints.candidates(&url(8080)),
+            vec![url(8083), url(8080), url(8081), url(8082)]
+        );
+    }
+
+    #[test]
+    fn health_is_read_from_readiness_report() {
+        let response = |status: u16, body: &str| {
+            Ok(Response::builder()
+                .status(status)
+                .body(body.as_bytes().to_vec())
+                .unwrap())
+        };
+        let report = r#"{"genesis_committed":true,"height":3,"highest_known_height":3,"blocks_behind":0,"max_blocks_behind":1,"in_topology":true,"kura_writable":true}"#;
+
+        assert_eq!(
+            Health::of_ready_response(response(200, report)),
+            Health::Height(3)
+        );
+        assert_eq!(
+            Health::of_ready_response(response(503, report)),
+            Health::NotReady
+        );
+        assert_eq!(
+            Health::of_ready_response(response(404, "Not found")),
+            Health::Reachable
+        );
+        assert_eq!(
+            Health::of_ready_response(Err(eyre::eyre!("Connection refused"))),
+            Health::Unreachable
+        );
+    }
+
+    #[test]
+    fn peer_one_block_behind_is_not_lagging() {
+        let endpoints = Endpoints::new(vec![url(8081)]);
+        endpoints.record_health_check([
+            (url(8080), Health::Height(6)),
+            (url(8081), Health::Height(7)),
+        ]);
+        assert_eq!(endpoints.candidates(&url(8080)), vec![url(8080), url(8081)]);
+    }
+}
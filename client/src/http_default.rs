@@ -1,20 +1,41 @@
 //! Defaults for various items used in communication over http(s).
-use std::{net::TcpStream, str::FromStr};
+use std::{
+    io::{self, Read},
+    net::TcpStream,
+    str::FromStr,
+    time::Duration,
+};
 
 use attohttpc::{
     body as atto_body, RequestBuilder as AttoHttpRequestBuilder, Response as AttoHttpResponse,
 };
 use eyre::{eyre, Error, Result, WrapErr};
-use http::header::HeaderName;
-use tungstenite::{client::IntoClientRequest, stream::MaybeTlsStream, WebSocket};
+use flate2::{Decompress, FlushDecompress};
+use http::header::{HeaderName, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH};
+use iroha_torii_const::compression::{DEFLATE, SYNC_FLUSH_TAIL, WS_COMPRESSION_HEADER};
+use tungstenite::{
+    client::IntoClientRequest, handshake::client::Response as HandshakeResponse,
+    stream::MaybeTlsStream, WebSocket,
+};
 pub use tungstenite::{Error as WebSocketError, Message as WebSocketMessage};
 use url::Url;
 
-use crate::http::{Method, RequestBuilder, Response};
+use crate::{
+    config::ToriiTls,
+    http::{Method, RequestBuilder, Response},
+};
 
 type Bytes = Vec<u8>;
 type AttoHttpRequestBuilderWithBytes = AttoHttpRequestBuilder<atto_body::Bytes<Bytes>>;
 
+/// Content codings of the responses which the client is able to decode, in the order of preference
+const ACCEPTED_ENCODINGS: &str = "zstd, gzip";
+/// Size above which decoded response bodies and websocket messages are rejected,
+/// so that a small compressed payload can't exhaust the memory of the client
+const MAX_DECODED_SIZE: usize = 256 * 1024 * 1024;
+/// Number of bytes by which the buffer of an inflated websocket message grows
+const INFLATE_CHUNK_SIZE: usize = 64 * 1024;
+
 fn header_name_from_str(str: &str) -> Result<HeaderName> {
     HeaderName::from_str(str).wrap_err_with(|| format!("Failed to parse header name {str}"))
 }
@@ -38,6 +59,45 @@ impl DefaultRequestBuilder {
         }
     }
 
+    /// Use custom TLS settings for the connection, if any.
+    ///
+    /// The request fails to build if the settings authenticate with a client certificate,
+    /// which `attohttpc` is unable to present.
+    #[cfg(feature = "__tls-rustls")]
+    #[must_use]
+    pub fn with_tls(self, tls: Option<&ToriiTls>) -> Self {
+        match tls {
+            Some(tls) if tls.identity_pem.is_some() => self.and_then(|_| {
+                Err(eyre!(
+                    "Blocking HTTP requests can't present the client certificate \
+                     from `torii_tls.cert_file`, use `AsyncClient` or remove it from the configuration"
+                ))
+            }),
+            Some(tls) => {
+                let ca_certificates = tls.ca_certificates.clone();
+                self.and_then(|b| {
+                    Ok(ca_certificates
+                        .into_iter()
+                        .fold(b, AttoHttpRequestBuilder::add_root_certificate))
+                })
+            }
+            None => self,
+        }
+    }
+
+    /// Use custom TLS settings for the connection, if any.
+    #[cfg(not(feature = "__tls-rustls"))]
+    #[must_use]
+    pub fn with_tls(self, _tls: Option<&ToriiTls>) -> Self {
+        self
+    }
+
+    /// Give up on the request if the peer doesn't respond within `timeout`.
+    #[must_use]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.and_then(|b| Ok(b.timeout(timeout)))
+    }
+
     /// Build request by consuming self.
     pub fn build(self) -> Result<DefaultRequest> {
         self.inner
@@ -72,7 +132,10 @@ impl DefaultRequest {
 impl RequestBuilder for DefaultRequestBuilder {
     fn new(method: Method, url: Url) -> Self {
         Self {
-            inner: Ok(AttoHttpRequestBuilder::new(method, url)),
+            inner: Ok(AttoHttpRequestBuilder::new(method, url).header(
+                ACCEPT_ENCODING,
+                HeaderValue::from_static(ACCEPTED_ENCODINGS),
+            )),
             body: None,
         }
     }
@@ -95,6 +158,135 @@ impl RequestBuilder for DefaultRequestBuilder {
     }
 }
 
+/// Asynchronous request builder implemented on top of `reqwest` crate.
+#[derive(Debug)]
+pub struct AsyncRequestBuilder(Result<reqwest::Request>);
+
+impl AsyncRequestBuilder {
+    /// Same as [`DefaultRequestBuilder::and_then`].
+    fn and_then<F>(self, fun: F) -> Self
+    where
+        F: FnOnce(reqwest::Request) -> Result<reqwest::Request>,
+    {
+        Self(self.0.and_then(fun))
+    }
+
+    /// Same as [`DefaultRequestBuilder::with_timeout`].
+    #[must_use]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.and_then(|mut request| {
+            *request.timeout_mut() = Some(timeout);
+            Ok(request)
+        })
+    }
+
+    /// Sends the request with the `http` client and returns byte response
+    ///
+    /// # Errors
+    /// Fails if request building and sending fails or response transformation fails
+    pub async fn send(self, http: &reqwest::Client) -> Result<Response<Bytes>> {
+        let request = self.0?;
+        let (method, url) = (request.method().clone(), request.url().clone());
+
+        let response = http
+            .execute(request)
+            .await
+            .wrap_err_with(|| format!("Failed to send http {method} request to {url}"))?;
+
+        let mut builder = Response::builder().status(response.status());
+        let headers = builder
+            .headers_mut()
+            .ok_or_else(|| eyre!("Failed to get headers map reference."))?;
+        for (key, value) in response.headers() {
+            headers.insert(key, value.clone());
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .wrap_err("Failed to get response as bytes")?;
+        builder
+            .body(bytes.to_vec())
+            .wrap_err("Failed to construct response bytes body")
+    }
+}
+
+impl RequestBuilder for AsyncRequestBuilder {
+    fn new(method: Method, url: Url) -> Self {
+        Self(Ok(reqwest::Request::new(method, url)))
+    }
+
+    fn header<K: AsRef<str>, V: ToString + ?Sized>(self, key: K, value: &V) -> Self {
+        self.and_then(|mut request| {
+            let value = HeaderValue::from_str(&value.to_string())
+                .wrap_err_with(|| format!("Failed to parse value of header {}", key.as_ref()))?;
+            request
+                .headers_mut()
+                .append(header_name_from_str(key.as_ref())?, value);
+            Ok(request)
+        })
+    }
+
+    fn param<K: AsRef<str>, V: ToString + ?Sized>(self, key: K, value: &V) -> Self {
+        self.and_then(|mut request| {
+            request
+                .url_mut()
+                .query_pairs_mut()
+                .append_pair(key.as_ref(), &value.to_string());
+            Ok(request)
+        })
+    }
+
+    fn body(self, data: Vec<u8>) -> Self {
+        self.and_then(|mut request| {
+            *request.body_mut() = Some(data.into());
+            Ok(request)
+        })
+    }
+}
+
+/// Build the client which sends [`AsyncRequestBuilder`] requests with custom TLS settings, if any.
+/// Responses are decompressed by the client itself.
+///
+/// # Errors
+/// Fails if the TLS settings can't be applied
+#[cfg(feature = "__tls-rustls")]
+pub fn async_http_client(tls: Option<&ToriiTls>) -> Result<reqwest::Client> {
+    let Some(tls) = tls else {
+        return reqwest::Client::builder()
+            .build()
+            .wrap_err("Failed to build http client");
+    };
+
+    // The settings are passed as certificates rather than as the prepared `rustls` config,
+    // since `reqwest` may be built on another version of `rustls`
+    let mut builder = reqwest::Client::builder()
+        .use_rustls_tls()
+        .tls_built_in_root_certs(false);
+    for certificate in &tls.ca_certificates {
+        builder = builder.add_root_certificate(
+            reqwest::Certificate::from_der(certificate).wrap_err("Invalid CA certificate")?,
+        );
+    }
+    if let Some(identity_pem) = &tls.identity_pem {
+        builder = builder.identity(
+            reqwest::Identity::from_pem(identity_pem).wrap_err("Invalid client certificate")?,
+        );
+    }
+    builder.build().wrap_err("Failed to build http client")
+}
+
+/// Build the client which sends [`AsyncRequestBuilder`] requests with custom TLS settings, if any.
+/// Responses are decompressed by the client itself.
+///
+/// # Errors
+/// Fails if the TLS settings can't be applied
+#[cfg(not(feature = "__tls-rustls"))]
+pub fn async_http_client(_tls: Option<&ToriiTls>) -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .build()
+        .wrap_err("Failed to build http client")
+}
+
 /// Request builder built on top of [`http::request::Builder`]. Used for `WebSocket` connections.
 pub struct DefaultWebSocketRequestBuilder(Result<http::request::Builder>);
 
@@ -117,24 +309,144 @@ impl DefaultWebSocketRequestBuilder {
         for (header, value) in builder.headers_ref().ok_or(eyre!("No headers found"))? {
             request.headers_mut().entry(header).or_insert(value.clone());
         }
-        Ok(DefaultWebSocketStreamRequest(request))
+        request
+            .headers_mut()
+            .entry(WS_COMPRESSION_HEADER)
+            .or_insert(HeaderValue::from_static(DEFLATE));
+        Ok(DefaultWebSocketStreamRequest {
+            request,
+            #[cfg(feature = "__tls-rustls")]
+            tls: None,
+        })
     }
 }
 
 /// `WebSocket` request built by [`DefaultWebSocketRequestBuilder`]
-pub struct DefaultWebSocketStreamRequest(http::Request<()>);
+pub struct DefaultWebSocketStreamRequest {
+    request: http::Request<()>,
+    #[cfg(feature = "__tls-rustls")]
+    tls: Option<ToriiTls>,
+}
 
 impl DefaultWebSocketStreamRequest {
+    /// Use custom TLS settings for the connection, if any.
+    #[cfg(feature = "__tls-rustls")]
+    #[must_use]
+    pub fn with_tls(self, tls: Option<&ToriiTls>) -> Self {
+        Self {
+            tls: tls.cloned(),
+            ..self
+        }
+    }
+
+    /// Use custom TLS settings for the connection, if any.
+    #[cfg(not(feature = "__tls-rustls"))]
+    #[must_use]
+    pub fn with_tls(self, _tls: Option<&ToriiTls>) -> Self {
+        self
+    }
+
     /// Open [`WebSocketStream`] synchronously.
-    pub fn connect(self) -> Result<WebSocketStream> {
-        let (stream, _) = tungstenite::connect(self.0)?;
-        Ok(stream)
+    /// Returns the stream along with the decoder of the compression confirmed by the peer.
+    pub fn connect(self) -> Result<(WebSocketStream, WebSocketDecoder)> {
+        #[cfg(feature = "__tls-rustls")]
+        if let Some(tls) = self.tls {
+            let uri = self.request.uri();
+            let host = uri.host().ok_or_else(|| eyre!("Missing host in `{uri}`"))?;
+            let port = uri.port_u16().unwrap_or(match uri.scheme_str() {
+                Some("wss") => 443,
+                _ => 80,
+            });
+            let socket = TcpStream::connect((host, port))
+                .wrap_err_with(|| format!("Failed to connect to `{uri}`"))?;
+            let connector = tungstenite::Connector::Rustls(tls.client_config);
+            let (stream, response) =
+                tungstenite::client_tls_with_config(self.request, socket, None, Some(connector))
+                    .map_err(|error| eyre!("WebSocket handshake failed: {error}"))?;
+            return Ok((stream, WebSocketDecoder::negotiated(&response)));
+        }
+
+        let (stream, response) = tungstenite::connect(self.request)?;
+        Ok((stream, WebSocketDecoder::negotiated(&response)))
     }
 
     /// Open [`AsyncWebSocketStream`].
-    pub async fn connect_async(self) -> Result<AsyncWebSocketStream> {
-        let (stream, _) = tokio_tungstenite::connect_async(self.0).await?;
-        Ok(stream)
+    /// Returns the stream along with the decoder of the compression confirmed by the peer.
+    pub async fn connect_async(self) -> Result<(AsyncWebSocketStream, WebSocketDecoder)> {
+        #[cfg(feature = "__tls-rustls")]
+        if let Some(tls) = self.tls {
+            let connector = tokio_tungstenite::Connector::Rustls(tls.client_config);
+            let (stream, response) = tokio_tungstenite::connect_async_tls_with_config(
+                self.request,
+                None,
+                false,
+                Some(connector),
+            )
+            .await?;
+            return Ok((stream, WebSocketDecoder::negotiated(&response)));
+        }
+
+        let (stream, response) = tokio_tungstenite::connect_async(self.request).await?;
+        Ok((stream, WebSocketDecoder::negotiated(&response)))
+    }
+}
+
+/// Decoder of the binary messages received over a websocket.
+///
+/// Messages are inflated if the peer confirmed the compression requested
+/// in the [`WS_COMPRESSION_HEADER`] of the handshake.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebSocketDecoder {
+    deflate: bool,
+}
+
+impl WebSocketDecoder {
+    fn negotiated(response: &HandshakeResponse) -> Self {
+        Self {
+            deflate: response
+                .headers()
+                .get(WS_COMPRESSION_HEADER)
+                .is_some_and(|value| value == DEFLATE),
+        }
+    }
+
+    /// Decode the `message` received from the peer.
+    ///
+    /// # Errors
+    /// Fails if the message isn't a valid deflate stream or inflates beyond [`MAX_DECODED_SIZE`]
+    pub fn decode(self, message: Bytes) -> Result<Bytes> {
+        if !self.deflate {
+            return Ok(message);
+        }
+        inflate_message(message, MAX_DECODED_SIZE)
+    }
+}
+
+/// Inflate the `message` compressed with per-message deflate,
+/// see [`iroha_torii_const::compression`]
+fn inflate_message(mut message: Bytes, limit: usize) -> Result<Bytes> {
+    message.extend_from_slice(&SYNC_FLUSH_TAIL);
+    let mut decompress = Decompress::new(false);
+    let mut inflated = Vec::new();
+    loop {
+        if inflated.len() == inflated.capacity() {
+            inflated.reserve(INFLATE_CHUNK_SIZE.min(limit + 1 - inflated.len()));
+        }
+        let (read, written) = (decompress.total_in(), decompress.total_out());
+        let consumed = usize::try_from(read).expect("Consumed input fits into the message");
+        decompress
+            .decompress_vec(&message[consumed..], &mut inflated, FlushDecompress::Sync)
+            .wrap_err("Failed to inflate websocket message")?;
+        if inflated.len() > limit {
+            return Err(eyre!("Inflated websocket message exceeds {limit} bytes"));
+        }
+        let all_read = decompress.total_in() == message.len() as u64;
+        if all_read && inflated.len() < inflated.capacity() {
+            return Ok(inflated);
+        }
+        if decompress.total_in() == read && decompress.total_out() == written {
+            return Err(eyre!("Websocket message is a truncated deflate stream"));
+        }
     }
 }
 
@@ -180,11 +492,16 @@ impl TryFrom<ClientResponse> for Response<Bytes> {
             .headers_mut()
             .ok_or_else(|| eyre!("Failed to get headers map reference."))?;
         for (key, value) in response.headers() {
-            headers.insert(key, value.clone());
+            // The body is decoded below, so its coding and length no longer apply
+            if key != CONTENT_ENCODING && key != CONTENT_LENGTH {
+                headers.insert(key, value.clone());
+            }
         }
+        let encoding = response.headers().get(CONTENT_ENCODING).cloned();
         response
             .bytes()
             .wrap_err("Failed to get response as bytes")
+            .and_then(|bytes| decode_body(encoding.as_ref(), bytes))
             .and_then(|bytes| {
                 builder
                     .body(bytes)
@@ -192,3 +509,104 @@ impl TryFrom<ClientResponse> for Response<Bytes> {
             })
     }
 }
+
+/// Decode the response `body` compressed with the content `encoding`
+fn decode_body(encoding: Option<&HeaderValue>, body: Bytes) -> Result<Bytes> {
+    decode_body_limited(encoding, body, MAX_DECODED_SIZE)
+}
+
+/// Decode the response `body`, failing if it decodes beyond `limit` bytes
+fn decode_body_limited(encoding: Option<&HeaderValue>, body: Bytes, limit: usize) -> Result<Bytes> {
+    let Some(encoding) = encoding else {
+        return Ok(body);
+    };
+    match encoding.as_bytes() {
+        b"identity" => Ok(body),
+        b"zstd" => {
+            let decoder = zstd::stream::read::Decoder::new(body.as_slice())
+                .wrap_err("Failed to decode zstd body")?;
+            read_limited(decoder, limit).wrap_err("Failed to decode zstd body")
+        }
+        b"gzip" | b"x-gzip" => read_limited(flate2::read::GzDecoder::new(body.as_slice()), limit)
+            .wrap_err("Failed to decode gzip body"),
+        _ => Err(eyre!("Unsupported content encoding: {encoding:?}")),
+    }
+}
+
+/// Read the `decoder` to the end, failing if it yields more than `limit` bytes
+fn read_limited(decoder: impl Read, limit: usize) -> Result<Bytes> {
+    let mut body = Vec::new();
+    decoder.take(limit as u64 + 1).read_to_end(&mut body)?;
+    if body.len() > limit {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Decoded body exceeds {limit} bytes"),
+        )
+        .into());
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use super::*;
+
+    #[test]
+    fn decodes_compressed_bodies() {
+        let body = b"iroha".repeat(100);
+
+        let zstd_body = zstd::encode_all(body.as_slice(), 0).unwrap();
+        let decoded = decode_body(Some(&HeaderValue::from_static("zstd")), zstd_body).unwrap();
+        assert_eq!(decoded, body);
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&body).unwrap();
+        let gzip_body = encoder.finish().unwrap();
+        let decoded = decode_body(Some(&HeaderValue::from_static("gzip")), gzip_body).unwrap();
+        assert_eq!(decoded, body);
+
+        assert_eq!(decode_body(None, body.clone()).unwrap(), body);
+        assert!(decode_body(Some(&HeaderValue::from_static("br")), body).is_err());
+    }
+
+    #[test]
+    fn decoded_size_is_limited() {
+        let body = vec![0; 10_000];
+
+        let zstd_body = zstd::encode_all(body.as_slice(), 0).unwrap();
+        let zstd = HeaderValue::from_static("zstd");
+        assert!(decode_body_limited(Some(&zstd), zstd_body.clone(), body.len()).is_ok());
+        assert!(decode_body_limited(Some(&zstd), zstd_body, body.len() - 1).is_err());
+
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(&body).unwrap();
+        encoder.flush().unwrap();
+        let mut deflated = std::mem::take(encoder.get_mut());
+        deflated.truncate(deflated.len() - SYNC_FLUSH_TAIL.len());
+        assert_eq!(inflate_message(deflated.clone(), body.len()).unwrap(), body);
+        assert!(inflate_message(deflated, body.len() - 1).is_err());
+    }
+
+    #[test]
+    fn inflates_websocket_messages_only_when_negotiated() {
+        let message = b"iroha".repeat(100);
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&message).unwrap();
+        encoder.flush().unwrap();
+        let mut deflated = std::mem::take(encoder.get_mut());
+        deflated.truncate(deflated.len() - SYNC_FLUSH_TAIL.len());
+
+        let decoder = WebSocketDecoder { deflate: true };
+        assert_eq!(decoder.decode(deflated.clone()).unwrap(), message);
+        assert_eq!(
+            WebSocketDecoder::default()
+                .decode(deflated.clone())
+                .unwrap(),
+            deflated
+        );
+    }
+}
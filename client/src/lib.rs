@@ -1,7 +1,9 @@
 //! Crate contains client which talks to Iroha network via http
 
+pub mod async_client;
 pub mod client;
 pub mod config;
+mod endpoints;
 pub mod http;
 mod http_default;
 mod query_builder;
@@ -33,11 +35,13 @@ pub mod samples {
             chain_id,
             key_pair,
             torii_api_url,
+            additional_torii_api_urls: Vec::new(),
             account_id,
             basic_auth: None,
             transaction_ttl: DEFAULT_TRANSACTION_TIME_TO_LIVE,
             transaction_status_timeout: DEFAULT_TRANSACTION_STATUS_TIMEOUT,
             transaction_add_nonce: DEFAULT_TRANSACTION_NONCE,
+            torii_tls: None,
         }
     }
 
@@ -46,7 +50,9 @@ pub mod samples {
     /// # Errors
     /// Fails if sending request or decoding fails
     pub fn get_status_json(client: &Client) -> Result<Status> {
-        let req = client.prepare_status_request::<DefaultRequestBuilder>();
+        let req = client
+            .prepare_status_request::<DefaultRequestBuilder>()
+            .with_tls(client.tls.as_ref());
         let resp = req.build()?.send()?;
         StatusResponseHandler::handle(&resp)
     }
@@ -1,50 +1,95 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, num::NonZeroU64};
 
-use iroha_data_model::query::{IterableQuery, QueryOutputBox};
+use iroha_data_model::{
+    account::AccountId,
+    query::{IterableQuery, QueryOutputBox},
+    IdBox,
+};
 
 use crate::{
-    client::{Client, QueryOutput, QueryResult},
-    data_model::query::{predicate::PredicateBox, sorting::Sorting, FetchSize, Pagination, Query},
+    client::{Client, QueryOutput, QueryResult, ResultSet},
+    data_model::{
+        name::Name,
+        query::{
+            aggregation::{AggregateGroup, Aggregation},
+            http::ClientQueryBuilder,
+            predicate::PredicateBox,
+            sorting::Sorting,
+            FetchSize, Pagination, Projection, Query,
+        },
+    },
 };
 
-pub struct QueryRequestBuilder<'a, R> {
-    client: &'a Client,
+/// Builder of the query requests executed by the client `C`,
+/// which is either the blocking [`Client`] or the [`AsyncClient`](crate::async_client::AsyncClient)
+pub struct QueryRequestBuilder<'a, R, C = Client> {
+    client: &'a C,
+    account_id: AccountId,
     request: R,
     pagination: Pagination,
     filter: PredicateBox,
     sorting: Sorting,
     fetch_size: FetchSize,
+    projection: Projection,
+    at_height: Option<NonZeroU64>,
 }
 
-impl<'a, R> QueryRequestBuilder<'a, R>
+impl<'a, R, C> QueryRequestBuilder<'a, R, C>
 where
     R: Query + Debug,
     R::Output: QueryOutput,
     <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
 {
-    pub(crate) fn new(client: &'a Client, request: R) -> Self {
+    pub(crate) fn new(client: &'a C, account_id: AccountId, request: R) -> Self {
         Self {
             client,
+            account_id,
             request,
             pagination: Pagination::default(),
             sorting: Sorting::default(),
             filter: PredicateBox::default(),
             fetch_size: FetchSize::default(),
+            projection: Projection::default(),
+            at_height: None,
         }
     }
 
-    pub fn execute(self) -> QueryResult<<R::Output as QueryOutput>::Target> {
-        self.client.request_with_filter_and_pagination_and_sorting(
-            self.request,
-            self.pagination,
-            self.fetch_size,
-            self.sorting,
-            self.filter,
-        )
+    /// Query the state as it was right after the block at `height` was applied.
+    ///
+    /// Peers only serve a limited number of blocks behind the latest one.
+    pub fn with_at_height(mut self, height: NonZeroU64) -> Self {
+        self.at_height = Some(height);
+        self
+    }
+
+    pub(crate) fn into_query_builder(self) -> (&'a C, ClientQueryBuilder) {
+        let mut query_builder = ClientQueryBuilder::new(self.request, self.account_id)
+            .with_filter(self.filter)
+            .with_sorting(self.sorting)
+            .with_pagination(self.pagination)
+            .with_fetch_size(self.fetch_size)
+            .with_projection(self.projection);
+        if let Some(height) = self.at_height {
+            query_builder = query_builder.with_at_height(height);
+        }
+
+        (self.client, query_builder)
     }
 }
 
 impl<R> QueryRequestBuilder<'_, R>
+where
+    R: Query + Debug,
+    R::Output: QueryOutput,
+    <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    pub fn execute(self) -> QueryResult<<R::Output as QueryOutput>::Target> {
+        let (client, query_builder) = self.into_query_builder();
+        client.request_with_query_builder::<R::Output>(&query_builder)
+    }
+}
+
+impl<R, C> QueryRequestBuilder<'_, R, C>
 where
     R: IterableQuery + Debug,
     R::Output: QueryOutput,
@@ -69,4 +114,43 @@ where
         self.fetch_size = fetch_size;
         self
     }
+
+    /// Return the results without their nested collections and with only the given
+    /// `metadata_keys`, as described by [`Projection::Summary`].
+    ///
+    /// Use [`Self::ids`] to get only the ids of the results.
+    pub fn with_summary(mut self, metadata_keys: impl IntoIterator<Item = Name>) -> Self {
+        self.projection = Projection::summary(metadata_keys);
+        self
+    }
+
+    /// Return only the ids of the results, which changes the type of the output
+    /// so it's only used by the `ids` methods of the clients
+    pub(crate) fn with_id_projection(mut self) -> Self {
+        self.projection = Projection::Id;
+        self
+    }
+}
+
+impl<R> QueryRequestBuilder<'_, R>
+where
+    R: IterableQuery + Debug,
+    R::Output: QueryOutput,
+    <R::Output as TryFrom<QueryOutputBox>>::Error: Into<eyre::Error>,
+{
+    /// Compute `aggregation` over the filtered results instead of returning them.
+    ///
+    /// Sorting, pagination and fetch size are applied to the resulting groups.
+    pub fn aggregate(self, aggregation: Aggregation) -> QueryResult<ResultSet<AggregateGroup>> {
+        let (client, query_builder) = self.into_query_builder();
+        client.request_with_query_builder::<Vec<AggregateGroup>>(
+            &query_builder.with_aggregation(aggregation),
+        )
+    }
+
+    /// Return the ids of the results instead of the results themselves.
+    pub fn ids(self) -> QueryResult<ResultSet<IdBox>> {
+        let (client, query_builder) = self.with_id_projection().into_query_builder();
+        client.request_with_query_builder::<Vec<IdBox>>(&query_builder)
+    }
 }
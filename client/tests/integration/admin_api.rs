@@ -0,0 +1,34 @@
+use eyre::Result;
+use iroha::crypto::KeyPair;
+use iroha_config::parameters::actual::Root as Config;
+use test_network::*;
+use test_samples::ALICE_KEYPAIR;
+
+#[test]
+fn admin_endpoints_require_admin_signature() -> Result<()> {
+    let mut config = Config::test();
+    config.torii.admin_public_keys = vec![ALICE_KEYPAIR.public_key().clone()];
+    let (_rt, _peer, client) = PeerBuilder::new()
+        .with_config(config)
+        .with_port(11_340)
+        .start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let dto = client.get_config()?;
+    client.set_config(dto)?;
+    client.get_status()?;
+
+    let mut stranger = client.clone();
+    stranger.key_pair = KeyPair::random();
+    let errors = [
+        stranger.get_config().map(drop),
+        stranger.set_config(dto),
+        stranger.get_status().map(drop),
+    ];
+    for error in errors {
+        let error = error.expect_err("Stranger isn't an admin");
+        assert!(error.to_string().contains("401"), "{error}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use eyre::Result;
+use futures_util::TryStreamExt as _;
+use iroha::{
+    async_client::AsyncClient,
+    client::asset,
+    data_model::{asset::AssetDefinition, prelude::*},
+};
+use nonzero_ext::nonzero;
+use test_network::*;
+
+#[test]
+fn async_client_submits_and_streams_query_results() -> Result<()> {
+    let (rt, _peer, client) = <PeerBuilder>::new().with_port(11_370).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+    let client = AsyncClient::from_client(client)?;
+
+    rt.block_on(async {
+        let register: Vec<InstructionBox> = ('a'..='e')
+            .map(|c| format!("{c}#wonderland").parse().expect("Valid"))
+            .map(|asset_definition_id| {
+                Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into()
+            })
+            .collect();
+        let hash = client.submit_all_blocking(register).await?;
+        let status = client
+            .get_transaction_status(&hash)
+            .await?
+            .expect("Committed transaction should be known");
+        assert!(status.block_height().is_some());
+
+        let definitions = client
+            .build_query(asset::all_definitions())
+            .with_fetch_size(FetchSize::new(Some(nonzero!(2_u32))))
+            .execute()
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        for c in 'a'..='e' {
+            let asset_definition_id: AssetDefinitionId = format!("{c}#wonderland").parse()?;
+            assert!(definitions
+                .iter()
+                .any(|definition| definition.id() == &asset_definition_id));
+        }
+
+        let asset_definition_id: AssetDefinitionId = "a#wonderland".parse()?;
+        let definition = client
+            .request(asset::definition_by_id(asset_definition_id.clone()))
+            .await?;
+        assert_eq!(definition.id(), &asset_definition_id);
+        let missing = client
+            .request(asset::definition_by_id("missing#wonderland".parse()?))
+            .await;
+        assert!(missing.is_err());
+
+        let status = client.get_status().await?;
+        assert!(status.blocks >= 2);
+
+        Ok::<_, eyre::Report>(())
+    })?;
+
+    Ok(())
+}
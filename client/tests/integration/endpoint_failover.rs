@@ -0,0 +1,37 @@
+use eyre::Result;
+use iroha::{
+    client::{asset, Client, QueryResult},
+    config::Config as ClientConfig,
+    data_model::{asset::AssetDefinition, prelude::*},
+};
+use nonzero_ext::nonzero;
+use test_network::*;
+
+#[test]
+fn client_fails_over_to_reachable_peer() -> Result<()> {
+    let (_rt, peer, client) = <PeerBuilder>::new().with_port(11_380).start_with_runtime();
+    wait_for_genesis_committed(&[client], 0);
+
+    // Nothing listens on the primary endpoint, so every request has to go to the peer
+    let mut config = ClientConfig::test(&peer.api_address);
+    config.additional_torii_api_urls = vec![config.torii_api_url.clone()];
+    config.torii_api_url = "http://127.0.0.1:1".parse()?;
+    let client = Client::new(config);
+
+    let asset_definition_id: AssetDefinitionId = "failover#wonderland".parse()?;
+    client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        asset_definition_id.clone(),
+    )))?;
+
+    // Continuations of the query are sent to the peer which holds its cursor
+    let definitions = client
+        .build_query(asset::all_definitions())
+        .with_fetch_size(FetchSize::new(Some(nonzero!(1_u32))))
+        .execute()?
+        .collect::<QueryResult<Vec<_>>>()?;
+    assert!(definitions
+        .iter()
+        .any(|definition| *definition.id() == asset_definition_id));
+
+    Ok(())
+}
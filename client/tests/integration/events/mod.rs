@@ -1,3 +1,4 @@
 mod data;
 mod notification;
 mod pipeline;
+mod replay;
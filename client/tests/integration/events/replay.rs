@@ -0,0 +1,52 @@
+use std::num::NonZeroU64;
+
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use test_network::*;
+
+/// Collect cursors of the domain creation events until all of the `domain_ids` are created.
+fn domains_created_since(
+    client: &iroha::client::Client,
+    from: EventCursor,
+    domain_ids: &[DomainId],
+) -> Result<Vec<(DomainId, EventCursor)>> {
+    let mut created = Vec::new();
+
+    for message in client.replay_events([DataEventFilter::Any], from)? {
+        let message = message?;
+        let cursor = message.cursor().expect("Data events belong to blocks");
+        if let EventBox::Data(DataEvent::Domain(DomainEvent::Created(domain))) = message.event() {
+            if domain_ids.contains(domain.id()) {
+                created.push((domain.id().clone(), cursor));
+            }
+        }
+        if created.len() == domain_ids.len() {
+            break;
+        }
+    }
+
+    Ok(created)
+}
+
+#[test]
+fn replay_events_from_cursor() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_320).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let domain_ids: [DomainId; 2] = ["replay_a".parse()?, "replay_b".parse()?];
+    for domain_id in &domain_ids {
+        client.submit_blocking(Register::domain(Domain::new(domain_id.clone())))?;
+    }
+
+    let genesis = EventCursor::start_of(NonZeroU64::new(1).expect("Non-zero"));
+    let created = domains_created_since(&client, genesis, &domain_ids)?;
+    assert_eq!(created[0].0, domain_ids[0]);
+    assert_eq!(created[1].0, domain_ids[1]);
+    assert!(created[0].1 < created[1].1);
+
+    // Resuming right after the first event doesn't repeat it
+    let resumed = domains_created_since(&client, created[0].1.next(), &domain_ids[1..])?;
+    assert_eq!(resumed, created[1..]);
+
+    Ok(())
+}
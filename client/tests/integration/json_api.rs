@@ -0,0 +1,95 @@
+use eyre::Result;
+use iroha::data_model::{
+    prelude::*,
+    query::{
+        error::{FindError, QueryExecutionFail},
+        http::ClientQueryBuilder,
+        QueryOutputBox,
+    },
+    BatchedResponse,
+};
+use iroha_torii_const::uri;
+use test_network::*;
+use test_samples::ALICE_ID;
+
+const JSON_MIME_TYPE: &str = "application/json";
+
+#[test]
+fn query_with_json_encoding() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_305).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let post_query = |query: QueryBox| -> Result<attohttpc::Response> {
+        let query = client.sign_query(ClientQueryBuilder::new(query, client.account_id.clone()));
+        let response = attohttpc::post(client.torii_url.join(uri::QUERY)?)
+            .header(attohttpc::header::CONTENT_TYPE, JSON_MIME_TYPE)
+            .header(attohttpc::header::ACCEPT, JSON_MIME_TYPE)
+            .text(serde_json::to_string(&query)?)
+            .send()?;
+        Ok(response)
+    };
+
+    let response = post_query(FindAllDomains.into())?;
+    assert_eq!(response.status(), attohttpc::StatusCode::OK);
+    let response: BatchedResponse<QueryOutputBox> = serde_json::from_str(&response.text()?)?;
+    let (output, _cursor) = response.into();
+    let domains = Vec::<Domain>::try_from(output)?;
+    assert!(domains
+        .iter()
+        .any(|domain| domain.id() == ALICE_ID.domain_id()));
+
+    let missing_domain_id: DomainId = "missing".parse()?;
+    let response = post_query(FindDomainById::new(missing_domain_id.clone()).into())?;
+    assert_eq!(response.status(), attohttpc::StatusCode::NOT_FOUND);
+    let error: ValidationFail = serde_json::from_str(&response.text()?)?;
+    assert!(matches!(
+        error,
+        ValidationFail::QueryFailed(QueryExecutionFail::Find(FindError::Domain(domain_id)))
+            if domain_id == missing_domain_id
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn submit_transaction_with_json_encoding() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_310).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let domain_id: DomainId = "json".parse()?;
+    let transaction = client.build_transaction(
+        [Register::domain(Domain::new(domain_id.clone()))],
+        UnlimitedMetadata::new(),
+    );
+    let response = attohttpc::post(client.torii_url.join(uri::TRANSACTION)?)
+        .header(attohttpc::header::CONTENT_TYPE, JSON_MIME_TYPE)
+        .text(serde_json::to_string(&transaction)?)
+        .send()?;
+    assert_eq!(response.status(), attohttpc::StatusCode::OK);
+
+    client.poll_request(FindDomainById::new(domain_id), |_| true)?;
+
+    Ok(())
+}
+
+#[test]
+fn rejected_transaction_error_is_json() -> Result<()> {
+    let (_rt, _peer, mut client) = <PeerBuilder>::new().with_port(11_400).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    client.chain_id = ChainId::from("other");
+    let transaction = client.build_transaction(
+        [Register::domain(Domain::new("json".parse()?))],
+        UnlimitedMetadata::new(),
+    );
+    let response = attohttpc::post(client.torii_url.join(uri::TRANSACTION)?)
+        .header(attohttpc::header::CONTENT_TYPE, JSON_MIME_TYPE)
+        .header(attohttpc::header::ACCEPT, JSON_MIME_TYPE)
+        .text(serde_json::to_string(&transaction)?)
+        .send()?;
+    assert_eq!(response.status(), attohttpc::StatusCode::BAD_REQUEST);
+    let error: serde_json::Value = serde_json::from_str(&response.text()?)?;
+    assert!(error["error"].is_string());
+
+    Ok(())
+}
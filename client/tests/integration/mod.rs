@@ -1,17 +1,27 @@
 mod add_domain;
+mod admin_api;
 mod asset;
 mod asset_propagation;
+mod async_client;
 mod domain_owner_permissions;
+mod endpoint_failover;
 mod events;
 mod extra_functional;
+mod json_api;
+mod multisig;
 mod non_mintable;
 mod pagination;
+mod peers_status;
 mod permissions;
 mod queries;
+mod rate_limit;
+mod readiness;
 mod roles;
 mod set_parameter;
 mod sorting;
 mod status_response;
+mod transaction_simulation;
+mod transaction_status;
 mod transfer_asset;
 mod triggers;
 mod tx_chain_id;
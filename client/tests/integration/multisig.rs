@@ -0,0 +1,126 @@
+use eyre::Result;
+use iroha::{
+    client,
+    crypto::KeyPair,
+    data_model::{prelude::*, query::error::QueryExecutionFail},
+};
+use test_network::*;
+use test_samples::gen_account_in;
+
+#[test]
+fn multisig_transaction_is_committed_once_quorum_is_reached() -> Result<()> {
+    let chain_id = ChainId::from("0");
+
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_265).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let (multisig_id, signatory_1) = gen_account_in("wonderland");
+    let signatory_2 = KeyPair::random();
+    let signatory_policy = SignatoryPolicy::new(
+        [
+            (signatory_1.public_key().clone(), 1),
+            (signatory_2.public_key().clone(), 1),
+        ],
+        2,
+    );
+    test_client.submit_blocking(Register::account(
+        Account::new(multisig_id.clone()).with_signatory_policy(signatory_policy),
+    ))?;
+
+    let key: Name = "key".parse()?;
+    let value: Name = "value".parse()?;
+    let tx = TransactionBuilder::new(chain_id, multisig_id.clone())
+        .with_instructions([SetKeyValue::account(
+            multisig_id.clone(),
+            key.clone(),
+            value,
+        )])
+        .sign(&signatory_1);
+    test_client.submit_transaction(&tx)?;
+
+    // Transaction is kept aside until the second signatory signs it
+    let pending = test_client.get_pending_transactions(&multisig_id)?;
+    assert_eq!(pending.len(), 1);
+    let _err = test_client
+        .request(FindAccountKeyValueByIdAndKey::new(
+            multisig_id.clone(),
+            key.clone(),
+        ))
+        .expect_err("Transaction shouldn't be committed without the second signature");
+
+    let tx = pending
+        .into_iter()
+        .next()
+        .expect("Checked above")
+        .sign(&signatory_2);
+    test_client.submit_transaction_blocking(&tx)?;
+
+    assert!(test_client
+        .get_pending_transactions(&multisig_id)?
+        .is_empty());
+    test_client.request(FindAccountKeyValueByIdAndKey::new(multisig_id, key))?;
+
+    Ok(())
+}
+
+#[test]
+fn account_with_unreachable_quorum_is_not_registered() {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_270).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let (multisig_id, signatory) = gen_account_in("wonderland");
+    let signatory_policy = SignatoryPolicy::new([(signatory.public_key().clone(), 1)], 2);
+    let _err = test_client
+        .submit_blocking(Register::account(
+            Account::new(multisig_id.clone()).with_signatory_policy(signatory_policy),
+        ))
+        .expect_err("Quorum can't be reached by signatories");
+
+    let error = test_client
+        .request(client::account::by_id(multisig_id))
+        .expect_err("Account shouldn't be registered");
+    assert!(matches!(
+        error,
+        client::ClientQueryError::Validation(ValidationFail::QueryFailed(
+            QueryExecutionFail::Find(_)
+        ))
+    ));
+}
+
+#[test]
+fn rotated_signatory_controls_the_same_account() -> Result<()> {
+    let chain_id = ChainId::from("0");
+
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_275).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let (account_id, old_key_pair) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(account_id.clone())))?;
+
+    let new_key_pair = KeyPair::random();
+    let tx = TransactionBuilder::new(chain_id.clone(), account_id.clone())
+        .with_instructions([RotateSignatory::single(
+            account_id.clone(),
+            new_key_pair.public_key().clone(),
+        )])
+        .sign(&old_key_pair);
+    test_client.submit_transaction_blocking(&tx)?;
+
+    let key: Name = "key".parse()?;
+    let value: Name = "value".parse()?;
+    let set_key_value = SetKeyValue::account(account_id.clone(), key.clone(), value);
+    let tx = TransactionBuilder::new(chain_id.clone(), account_id.clone())
+        .with_instructions([set_key_value.clone()])
+        .sign(&old_key_pair);
+    let _err = test_client
+        .submit_transaction(&tx)
+        .expect_err("Old key shouldn't control the account anymore");
+
+    let tx = TransactionBuilder::new(chain_id, account_id.clone())
+        .with_instructions([set_key_value])
+        .sign(&new_key_pair);
+    test_client.submit_transaction_blocking(&tx)?;
+    test_client.request(FindAccountKeyValueByIdAndKey::new(account_id, key))?;
+
+    Ok(())
+}
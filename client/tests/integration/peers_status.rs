@@ -0,0 +1,14 @@
+use eyre::Result;
+use test_network::*;
+
+#[test]
+fn connected_peers_are_reported() -> Result<()> {
+    let (_rt, network, client) = Network::start_test_with_runtime(4, Some(11_350));
+    wait_for_genesis_committed(&network.clients(), 0);
+
+    let status = client.get_peers()?;
+    assert_eq!(status.peers.len(), 3, "{status:?}");
+    assert_ne!(status.role, "Undefined");
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use eyre::Result;
+use iroha::{
+    client::{self, QueryResult},
+    data_model::{
+        prelude::*,
+        query::{
+            aggregation::{Aggregation, GroupBy},
+            predicate::{string, value, PredicateBox},
+        },
+    },
+};
+use test_network::*;
+use test_samples::{gen_account_in, ALICE_ID};
+
+#[test]
+fn aggregate_filtered_assets() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_290).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let daisy_id: AssetDefinitionId = "daisy#wonderland".parse()?;
+    let (bob_id, _) = gen_account_in("wonderland");
+    let instructions: [InstructionBox; 4] = [
+        Register::account(Account::new(bob_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(daisy_id.clone())).into(),
+        Mint::asset_numeric(
+            numeric!(3),
+            AssetId::new(daisy_id.clone(), ALICE_ID.clone()),
+        )
+        .into(),
+        Mint::asset_numeric(numeric!(5), AssetId::new(daisy_id.clone(), bob_id.clone())).into(),
+    ];
+    test_client.submit_all_blocking(instructions)?;
+
+    let daisy_filter = PredicateBox::new(value::QueryOutputPredicate::Identifiable(
+        string::StringPredicate::starts_with("daisy"),
+    ));
+    let aggregate = |aggregation| {
+        test_client
+            .build_query(client::asset::all())
+            .with_filter(daisy_filter.clone())
+            .aggregate(aggregation)?
+            .map(|group| group.map(|group| (group.group().clone(), *group.value())))
+            .collect::<QueryResult<Vec<_>>>()
+    };
+
+    assert_eq!(
+        aggregate(Aggregation::count())?,
+        [(None, Some(numeric!(2)))]
+    );
+    assert_eq!(
+        aggregate(Aggregation::sum().group_by(GroupBy::AssetDefinition))?,
+        [(Some(daisy_id.into()), Some(numeric!(8)))]
+    );
+
+    let mut max_by_account = aggregate(Aggregation::max().group_by(GroupBy::Account))?;
+    max_by_account.sort_by_key(|(_, value)| *value);
+    assert_eq!(
+        max_by_account,
+        [
+            (Some(ALICE_ID.clone().into()), Some(numeric!(3))),
+            (Some(bob_id.into()), Some(numeric!(5))),
+        ]
+    );
+
+    Ok(())
+}
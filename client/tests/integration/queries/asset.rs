@@ -218,3 +218,44 @@ where
 
     Ok(())
 }
+
+#[test]
+fn find_asset_quantity_at_height() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_280).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let definition_id: AssetDefinitionId = "historical#wonderland".parse()?;
+    let asset_id = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        definition_id,
+    )))?;
+    let height_before_mint = test_client.get_status()?.blocks;
+
+    test_client.submit_blocking(Mint::asset_numeric(1_u32, asset_id.clone()))?;
+    let height_after_mint = test_client.get_status()?.blocks;
+    test_client.submit_blocking(Mint::asset_numeric(2_u32, asset_id.clone()))?;
+
+    let quantity_at = |height: u64| {
+        test_client
+            .build_query(FindAssetQuantityById::new(asset_id.clone()))
+            .with_at_height(height.try_into().expect("Height is non-zero"))
+            .execute()
+    };
+    assert_eq!(quantity_at(height_after_mint)?, numeric!(1));
+    assert_eq!(
+        test_client.request(FindAssetQuantityById::new(asset_id.clone()))?,
+        numeric!(3)
+    );
+    assert!(matches!(
+        quantity_at(height_before_mint).expect_err("Asset didn't exist at this height"),
+        ClientQueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::Find(_)))
+    ));
+    assert!(matches!(
+        quantity_at(height_after_mint + 100).expect_err("Height isn't reached yet"),
+        ClientQueryError::Validation(ValidationFail::QueryFailed(
+            QueryExecutionFail::HeightNotServed(_)
+        ))
+    ));
+
+    Ok(())
+}
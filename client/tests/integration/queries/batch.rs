@@ -0,0 +1,47 @@
+use eyre::Result;
+use iroha::{
+    client::ClientQueryError,
+    data_model::{
+        prelude::*,
+        query::error::{FindError, QueryExecutionFail},
+    },
+};
+use test_network::*;
+use test_samples::ALICE_ID;
+
+#[test]
+fn request_batch_of_queries() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_300).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let missing_domain_id: DomainId = "missing".parse()?;
+    let mut results = test_client
+        .request_batch([
+            FindAccountById::new(ALICE_ID.clone()).into(),
+            FindAllDomains.into(),
+            FindDomainById::new(missing_domain_id.clone()).into(),
+        ])?
+        .into_iter();
+
+    let alice = Account::try_from(results.next().expect("Result for each query")?)?;
+    assert_eq!(alice.id(), &*ALICE_ID);
+
+    let domains = Vec::<Domain>::try_from(results.next().expect("Result for each query")?)?;
+    assert!(domains
+        .iter()
+        .any(|domain| domain.id() == ALICE_ID.domain_id()));
+
+    let error = results
+        .next()
+        .expect("Result for each query")
+        .expect_err("Domain shouldn't be found");
+    assert!(matches!(
+        error,
+        ClientQueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::Find(
+            FindError::Domain(domain_id)
+        ))) if domain_id == missing_domain_id
+    ));
+    assert!(results.next().is_none());
+
+    Ok(())
+}
@@ -8,10 +8,14 @@ use iroha::{
 use test_network::*;
 
 mod account;
+mod aggregation;
 mod asset;
+mod batch;
+mod projection;
 mod query_errors;
 mod role;
 mod smart_contract;
+mod transaction;
 
 #[test]
 fn too_big_fetch_size_is_not_allowed() {
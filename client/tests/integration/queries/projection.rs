@@ -0,0 +1,41 @@
+use eyre::Result;
+use iroha::{
+    client::{self, QueryResult},
+    data_model::prelude::*,
+};
+use test_network::*;
+
+#[test]
+fn project_domains() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_295).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let wonderland_id: DomainId = "wonderland".parse()?;
+    let (kept, dropped): (Name, Name) = ("kept".parse()?, "dropped".parse()?);
+    let instructions: [InstructionBox; 2] = [
+        SetKeyValue::domain(wonderland_id.clone(), kept.clone(), "value".to_owned()).into(),
+        SetKeyValue::domain(wonderland_id.clone(), dropped.clone(), "value".to_owned()).into(),
+    ];
+    test_client.submit_all_blocking(instructions)?;
+
+    let ids = test_client
+        .build_query(client::domain::all())
+        .ids()?
+        .collect::<QueryResult<Vec<_>>>()?;
+    assert!(ids.contains(&wonderland_id.clone().into()));
+
+    let wonderland = test_client
+        .build_query(client::domain::all())
+        .with_summary([kept.clone()])
+        .execute()?
+        .collect::<QueryResult<Vec<_>>>()?
+        .into_iter()
+        .find(|domain| domain.id() == &wonderland_id)
+        .expect("Wonderland should be returned");
+    assert_eq!(wonderland.accounts().len(), 0);
+    assert_eq!(wonderland.asset_definitions().len(), 0);
+    assert!(wonderland.metadata().contains(&kept));
+    assert!(!wonderland.metadata().contains(&dropped));
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use eyre::Result;
+use iroha::{
+    client::{self, ClientQueryError},
+    crypto::{Hash, HashOf},
+    data_model::{
+        prelude::*,
+        query::error::{FindError, QueryExecutionFail},
+    },
+};
+use test_network::*;
+
+#[test]
+fn transaction_proof_verifies_against_block_header() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_390).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let domain_id: DomainId = "proven".parse()?;
+    let hash = client.submit_blocking(Register::domain(Domain::new(domain_id)))?;
+
+    let proof = client.request(client::transaction::proof_by_hash(hash))?;
+    assert_eq!(proof.transaction().as_ref().hash(), hash);
+    let transactions_hash = proof
+        .block_header()
+        .transactions_hash
+        .expect("Block with a transaction has transactions hash");
+    assert!(proof.audit_path().verify(&hash, &transactions_hash));
+
+    Ok(())
+}
+
+#[test]
+fn transaction_proof_of_missing_transaction_is_not_found() {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_395).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let hash = HashOf::<SignedTransaction>::from_untyped_unchecked(Hash::new("missing"));
+    let error = client
+        .request(client::transaction::proof_by_hash(hash))
+        .expect_err("Transaction shouldn't be found");
+    assert!(matches!(
+        error,
+        ClientQueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::Find(
+            FindError::Transaction(missing_hash)
+        ))) if missing_hash == hash
+    ));
+}
@@ -0,0 +1,26 @@
+use iroha::client;
+use iroha_config::parameters::actual::{Root as Config, TokenBucket, ToriiRateLimit};
+use nonzero_ext::nonzero;
+use test_network::*;
+
+#[test]
+fn queries_over_account_rate_limit_are_rejected() {
+    let mut config = Config::test();
+    config.torii.rate_limit = ToriiRateLimit {
+        per_ip: None,
+        per_account: Some(TokenBucket {
+            rate: nonzero!(1_u32),
+            burst: nonzero!(2_u32),
+        }),
+    };
+    let (_rt, _peer, client) = PeerBuilder::new()
+        .with_config(config)
+        .with_port(11_335)
+        .start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let error = (0..3)
+        .find_map(|_| client.request(client::domain::all()).err())
+        .expect("Burst of 2 queries should be exceeded");
+    assert!(format!("{error:?}").contains("429"), "{error:?}");
+}
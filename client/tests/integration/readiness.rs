@@ -0,0 +1,20 @@
+use eyre::Result;
+use iroha_torii_const::uri;
+use test_network::*;
+
+#[test]
+fn peer_is_ready_after_genesis() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_345).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let response = attohttpc::get(client.torii_url.join(uri::READY)?).send()?;
+    assert_eq!(response.status(), attohttpc::StatusCode::OK);
+
+    let readiness: serde_json::Value = serde_json::from_slice(&response.bytes()?)?;
+    assert_eq!(readiness["genesis_committed"], true);
+    assert_eq!(readiness["blocks_behind"], 0);
+    assert_eq!(readiness["in_topology"], true);
+    assert_eq!(readiness["kura_writable"], true);
+
+    Ok(())
+}
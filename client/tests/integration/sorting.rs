@@ -9,7 +9,8 @@ use iroha::{
         prelude::*,
         query::{
             predicate::{string, value, PredicateBox},
-            Pagination, Sorting,
+            sorting::{SortField, SortKey},
+            FetchSize, Pagination, Sorting,
         },
     },
 };
@@ -414,3 +415,58 @@ fn sort_only_elements_which_have_sorting_key() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn sort_assets_by_multiple_keys_across_cursor_pages() -> Result<()> {
+    const N_ASSETS: u32 = 9;
+
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_285).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let account_id = ALICE_ID.clone();
+    let mut asset_ids = vec![];
+    let mut register_asset_definitions = vec![];
+    let mut mint_assets = vec![];
+    for i in 0..N_ASSETS {
+        let asset_definition_id: AssetDefinitionId = format!("dahlia{i}#wonderland").parse()?;
+        let asset_id = AssetId::new(asset_definition_id.clone(), account_id.clone());
+        register_asset_definitions.push(Register::asset_definition(AssetDefinition::numeric(
+            asset_definition_id,
+        )));
+        mint_assets.push(Mint::asset_numeric(i % 3 + 1, asset_id.clone()));
+        asset_ids.push((i % 3 + 1, asset_id));
+    }
+    register_asset_definitions.shuffle(&mut thread_rng());
+    mint_assets.shuffle(&mut thread_rng());
+
+    test_client.submit_all_blocking(register_asset_definitions)?;
+    test_client.submit_all_blocking(mint_assets)?;
+
+    // Largest quantity first, assets with equal quantity ordered by id
+    asset_ids.sort_by(|(l_quantity, l_id), (r_quantity, r_id)| {
+        r_quantity.cmp(l_quantity).then_with(|| l_id.cmp(r_id))
+    });
+
+    let res = test_client
+        .build_query(client::asset::all())
+        .with_sorting(
+            Sorting::by(SortKey::descending(SortField::AssetQuantity))
+                .then_by(SortKey::ascending(SortField::Id)),
+        )
+        .with_filter(PredicateBox::new(
+            value::QueryOutputPredicate::Identifiable(string::StringPredicate::starts_with(
+                "dahlia",
+            )),
+        ))
+        .with_fetch_size(FetchSize::new(Some(nonzero!(2_u32))))
+        .execute()
+        .wrap_err("Failed to submit request")?
+        .collect::<QueryResult<Vec<_>>>()?;
+
+    assert!(res
+        .iter()
+        .map(Identifiable::id)
+        .eq(asset_ids.iter().map(|(_, id)| id)));
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use eyre::Result;
+use iroha::{client, crypto::KeyPair, data_model::prelude::*};
+use test_network::*;
+use test_samples::ALICE_ID;
+
+#[test]
+fn simulated_transaction_is_not_committed() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_325).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let domain_id: DomainId = "simulated".parse()?;
+    let transaction = client.build_transaction(
+        [Register::domain(Domain::new(domain_id.clone()))],
+        UnlimitedMetadata::new(),
+    );
+    let simulated = client
+        .simulate_transaction(&transaction)?
+        .expect("Registration of a new domain should be approved");
+    assert!(simulated.events().iter().any(|event| matches!(
+        event,
+        DataEvent::Domain(DomainEvent::Created(domain)) if *domain.id() == domain_id
+    )));
+    assert_eq!(simulated.fuel_consumed(), 0);
+    assert!(client.request(client::domain::by_id(domain_id)).is_err());
+
+    let rejected = client.build_transaction(
+        [Fail::new("simulated failure".to_owned())],
+        UnlimitedMetadata::new(),
+    );
+    assert!(matches!(
+        client.simulate_transaction(&rejected)?,
+        Err(TransactionRejectionReason::Validation(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn simulated_transaction_must_be_signed_by_authority() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_330).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let transaction = TransactionBuilder::new(client.chain_id.clone(), ALICE_ID.clone())
+        .with_instructions([Register::domain(Domain::new("unsigned".parse()?))])
+        .sign(&KeyPair::random());
+
+    assert!(matches!(
+        client.simulate_transaction(&transaction)?,
+        Err(TransactionRejectionReason::SignatoryCheck(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn unsigned_transaction_is_simulated_on_behalf_of_authority() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_405).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let domain_id: DomainId = "unsigned".parse()?;
+    let transaction = client.build_unsigned_transaction(
+        [Register::domain(Domain::new(domain_id.clone()))],
+        UnlimitedMetadata::new(),
+    );
+    let simulated = client
+        .simulate_unsigned_transaction(&transaction)?
+        .expect("Registration of a new domain should be approved");
+    assert!(simulated.events().iter().any(|event| matches!(
+        event,
+        DataEvent::Domain(DomainEvent::Created(domain)) if *domain.id() == domain_id
+    )));
+    assert!(client.request(client::domain::by_id(domain_id)).is_err());
+
+    Ok(())
+}
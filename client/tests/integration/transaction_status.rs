@@ -0,0 +1,46 @@
+use std::thread;
+
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_config::parameters::actual::Root as Config;
+use test_network::*;
+
+#[test]
+fn transaction_status_is_reported() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_315).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+    let pipeline_time = Config::pipeline_time();
+
+    let domain_id: DomainId = "status".parse()?;
+    let approved_hash = client.submit_blocking(Register::domain(Domain::new(domain_id)))?;
+    let status = client
+        .get_transaction_status(&approved_hash)?
+        .expect("Committed transaction should be known");
+    assert_eq!(status.status(), &TransactionStatus::Approved);
+    assert!(status.block_height().is_some());
+
+    let rejected_hash = client.submit(Fail::new("rejected".to_owned()))?;
+    let mut status = None;
+    for _ in 0..10 {
+        thread::sleep(pipeline_time);
+        status = client.get_transaction_status(&rejected_hash)?;
+        if status
+            .as_ref()
+            .is_some_and(|status| status.block_height().is_some())
+        {
+            break;
+        }
+    }
+    let status = status.expect("Submitted transaction should be known");
+    assert!(matches!(status.status(), TransactionStatus::Rejected(_)));
+
+    let unknown_transaction = client.build_transaction(
+        [Fail::new("never submitted".to_owned())],
+        UnlimitedMetadata::new(),
+    );
+    assert!(client
+        .get_transaction_status(&unknown_transaction.hash())?
+        .is_none());
+
+    Ok(())
+}
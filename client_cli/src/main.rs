@@ -82,9 +82,9 @@ impl FromStr for MetadataValueArg {
 #[derive(clap::Parser, Debug)]
 #[command(name = "iroha", version = concat!("version=", env!("CARGO_PKG_VERSION"), " git_commit_sha=", env!("VERGEN_GIT_SHA")), author)]
 struct Args {
-    /// Path to the configuration file
+    /// Path to the configuration file, required by all subcommands but `transaction build`
     #[arg(short, long, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
-    config: PathBuf,
+    config: Option<PathBuf>,
     /// More verbose output
     #[arg(short, long)]
     verbose: bool,
@@ -116,15 +116,30 @@ enum Subcommand {
     Blocks(blocks::Args),
     /// The subcommand related to multi-instructions as Json or Json5
     Json(json::Args),
+    /// The subcommand related to building, signing and submitting transactions in separate steps
+    #[clap(subcommand)]
+    Transaction(transaction::Args),
+    /// The subcommand related to triggers
+    #[clap(subcommand)]
+    Trigger(trigger::Args),
+    /// The subcommand related to roles
+    #[clap(subcommand)]
+    Role(role::Args),
+    /// The subcommand related to permissions of accounts
+    #[clap(subcommand)]
+    Permission(permission::Args),
 }
 
 /// Context inside which command is executed
 trait RunContext {
     /// Get access to configuration
-    fn configuration(&self) -> &Config;
+    ///
+    /// # Errors
+    /// Fails if the configuration isn't given or can't be loaded
+    fn configuration(&self) -> Result<&Config>;
 
-    fn client_from_config(&self) -> Client {
-        Client::new(self.configuration().clone())
+    fn client_from_config(&self) -> Result<Client> {
+        Ok(Client::new(self.configuration()?.clone()))
     }
 
     /// Serialize and print data
@@ -137,12 +152,14 @@ trait RunContext {
 
 struct PrintJsonContext<W> {
     write: W,
-    config: Config,
+    config: Option<Config>,
 }
 
 impl<W: std::io::Write> RunContext for PrintJsonContext<W> {
-    fn configuration(&self) -> &Config {
-        &self.config
+    fn configuration(&self) -> Result<&Config> {
+        self.config
+            .as_ref()
+            .ok_or_else(|| eyre!("The configuration file isn't set by `--config` argument"))
     }
 
     fn print_data(&mut self, data: &dyn Serialize) -> Result<()> {
@@ -171,7 +188,7 @@ macro_rules! match_all {
 impl RunArgs for Subcommand {
     fn run(self, context: &mut dyn RunContext) -> Result<()> {
         use Subcommand::*;
-        match_all!((self, context), { Domain, Account, Asset, Peer, Events, Wasm, Blocks, Json })
+        match_all!((self, context), { Domain, Account, Asset, Peer, Events, Wasm, Blocks, Json, Transaction, Trigger, Role, Permission })
     }
 }
 
@@ -194,14 +211,18 @@ fn main() -> error_stack::Result<(), MainError> {
 
     error_stack::Report::set_color_mode(color_mode());
 
-    let config = Config::load(config_path)
-        // FIXME: would be nice to NOT change the context, it's unnecessary
-        .change_context(MainError::Config)
-        .attach_printable("config path was set by `--config` argument")?;
-    if verbose {
+    let config = config_path
+        .map(|config_path| {
+            Config::load(config_path)
+                // FIXME: would be nice to NOT change the context, it's unnecessary
+                .change_context(MainError::Config)
+                .attach_printable("config path was set by `--config` argument")
+        })
+        .transpose()?;
+    if let (Some(config), true) = (&config, verbose) {
         eprintln!(
             "Configuration: {}",
-            &serde_json::to_string_pretty(&config)
+            &serde_json::to_string_pretty(config)
                 .change_context(MainError::SerializeConfig)
                 .attach_printable("caused by `--verbose` argument")?
         );
@@ -239,7 +260,7 @@ fn submit(
     metadata: UnlimitedMetadata,
     context: &mut dyn RunContext,
 ) -> Result<()> {
-    let iroha = context.client_from_config();
+    let iroha = context.client_from_config()?;
     let instructions = instructions.into();
     let tx = iroha.build_transaction(instructions, metadata);
 
@@ -306,7 +327,7 @@ mod events {
 
     fn listen(filter: impl Into<EventFilterBox>, context: &mut dyn RunContext) -> Result<()> {
         let filter = filter.into();
-        let iroha = context.client_from_config();
+        let iroha = context.client_from_config()?;
         eprintln!("Listening to events with filter: {filter:?}");
         iroha
             .listen_for_events([filter])
@@ -336,7 +357,7 @@ mod blocks {
     }
 
     fn listen(height: NonZeroU64, context: &mut dyn RunContext) -> Result<()> {
-        let iroha = context.client_from_config();
+        let iroha = context.client_from_config()?;
         eprintln!("Listening to blocks from height: {height}");
         iroha
             .listen_for_blocks(height)
@@ -401,7 +422,7 @@ mod domain {
 
     impl RunArgs for List {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
-            let client = context.client_from_config();
+            let client = context.client_from_config()?;
 
             let vec = match self {
                 Self::All => client
@@ -519,7 +540,10 @@ mod domain {
 mod account {
     use std::fmt::Debug;
 
-    use iroha::client::{self};
+    use iroha::{
+        client::{self},
+        crypto::Hash,
+    };
 
     use super::{Permission as DataModelPermission, *};
 
@@ -535,6 +559,12 @@ mod account {
         Grant(Grant),
         /// List all account permissions
         ListPermissions(ListPermissions),
+        /// List multisig transactions of the account which are waiting for co-signers
+        ListPending(ListPending),
+        /// Co-sign multisig transaction which is waiting for co-signers
+        Cosign(Cosign),
+        /// Replace keys controlling the account, keeping its id
+        RotateSignatory(RotateSignatory),
     }
 
     impl RunArgs for Args {
@@ -544,6 +574,9 @@ mod account {
                 Args::List,
                 Args::Grant,
                 Args::ListPermissions,
+                Args::ListPending,
+                Args::Cosign,
+                Args::RotateSignatory,
             })
         }
     }
@@ -554,19 +587,97 @@ mod account {
         /// Id of account in form `name@domain_name`
         #[arg(short, long)]
         pub id: AccountId,
+        /// Signatory of multisig account in form `public_key:weight`, weight defaults to 1
+        #[arg(short, long, requires("quorum"))]
+        pub signatory: Vec<Signatory>,
+        /// Minimal combined weight of signatories required to authorize a transaction
+        #[arg(short, long, requires("signatory"))]
+        pub quorum: Option<u16>,
         #[command(flatten)]
         pub metadata: MetadataArgs,
     }
 
+    /// Public key of the signatory with its weight
+    #[derive(Debug, Clone)]
+    pub struct Signatory(PublicKey, u8);
+
+    impl FromStr for Signatory {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self> {
+            let (public_key, weight) = match s.rsplit_once(':') {
+                Some((public_key, weight)) => (
+                    public_key,
+                    weight
+                        .parse()
+                        .wrap_err(format!("Failed to parse the signatory weight {weight}"))?,
+                ),
+                None => (s, 1),
+            };
+            let public_key = public_key
+                .parse()
+                .map_err(|err| eyre!("Failed to parse the signatory public key: {err}"))?;
+            Ok(Self(public_key, weight))
+        }
+    }
+
     impl RunArgs for Register {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
-            let Self { id, metadata } = self;
-            let create_account = iroha::data_model::isi::Register::account(Account::new(id));
+            let Self {
+                id,
+                signatory,
+                quorum,
+                metadata,
+            } = self;
+            let mut account = Account::new(id);
+            if let Some(quorum) = quorum {
+                let signatories = signatory
+                    .into_iter()
+                    .map(|Signatory(public_key, weight)| (public_key, weight));
+                account = account.with_signatory_policy(SignatoryPolicy::new(signatories, quorum));
+            }
+            let create_account = iroha::data_model::isi::Register::account(account);
             submit([create_account], metadata.load()?, context)
                 .wrap_err("Failed to register account")
         }
     }
 
+    /// Replace keys controlling the account, keeping its id
+    #[derive(clap::Args, Debug)]
+    pub struct RotateSignatory {
+        /// Id of account in form `name@domain_name`
+        #[arg(short, long)]
+        pub id: AccountId,
+        /// New signatory of the account in form `public_key:weight`, weight defaults to 1
+        #[arg(short, long, required = true)]
+        pub signatory: Vec<Signatory>,
+        /// Minimal combined weight of signatories required to authorize a transaction
+        #[arg(short, long, default_value_t = 1)]
+        pub quorum: u16,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for RotateSignatory {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                id,
+                signatory,
+                quorum,
+                metadata,
+            } = self;
+            let signatories = signatory
+                .into_iter()
+                .map(|Signatory(public_key, weight)| (public_key, weight));
+            let rotate_signatory = iroha::data_model::isi::RotateSignatory::new(
+                id,
+                SignatoryPolicy::new(signatories, quorum),
+            );
+            submit([rotate_signatory], metadata.load()?, context)
+                .wrap_err("Failed to rotate signatories of the account")
+        }
+    }
+
     /// List accounts with this command
     #[derive(clap::Subcommand, Debug, Clone)]
     pub enum List {
@@ -578,7 +689,7 @@ mod account {
 
     impl RunArgs for List {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
-            let client = context.client_from_config();
+            let client = context.client_from_config()?;
 
             let vec = match self {
                 Self::All => client
@@ -609,7 +720,7 @@ mod account {
 
     /// [`DataModelPermission`] wrapper implementing [`FromStr`]
     #[derive(Debug, Clone)]
-    pub struct Permission(DataModelPermission);
+    pub struct Permission(pub DataModelPermission);
 
     impl FromStr for Permission {
         type Err = Error;
@@ -648,7 +759,7 @@ mod account {
 
     impl RunArgs for ListPermissions {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
-            let client = context.client_from_config();
+            let client = context.client_from_config()?;
             let find_all_permissions = FindPermissionsByAccountId::new(self.id);
             let permissions = client
                 .request(find_all_permissions)
@@ -657,6 +768,57 @@ mod account {
             Ok(())
         }
     }
+
+    /// List multisig transactions of the account which are waiting for co-signers
+    #[derive(clap::Args, Debug)]
+    pub struct ListPending {
+        /// Account id
+        #[arg(short, long)]
+        id: AccountId,
+    }
+
+    impl RunArgs for ListPending {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let client = context.client_from_config()?;
+            let transactions = client
+                .get_pending_transactions(&self.id)
+                .wrap_err("Failed to get pending transactions")?
+                .into_iter()
+                .map(|tx| serde_json::json!({ "hash": tx.hash(), "transaction": tx }))
+                .collect::<Vec<_>>();
+            context.print_data(&transactions)?;
+            Ok(())
+        }
+    }
+
+    /// Co-sign multisig transaction which is waiting for co-signers
+    #[derive(clap::Args, Debug)]
+    pub struct Cosign {
+        /// Account id of the transaction authority
+        #[arg(short, long)]
+        id: AccountId,
+        /// Hash of the pending transaction as printed by `list-pending`
+        #[arg(long)]
+        hash: Hash,
+    }
+
+    impl RunArgs for Cosign {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self { id, hash } = self;
+            let client = context.client_from_config()?;
+            let transaction = client
+                .get_pending_transactions(&id)
+                .wrap_err("Failed to get pending transactions")?
+                .into_iter()
+                .find(|tx| Hash::from(tx.hash()) == hash)
+                .ok_or_else(|| eyre!("Pending transaction with hash {hash} is not found"))?;
+            let hash = client
+                .cosign_transaction(transaction)
+                .wrap_err("Failed to co-sign transaction")?;
+            context.print_data(&hash)?;
+            Ok(())
+        }
+    }
 }
 
 mod asset {
@@ -828,7 +990,7 @@ mod asset {
     impl RunArgs for Get {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
             let Self { asset_id } = self;
-            let iroha = context.client_from_config();
+            let iroha = context.client_from_config()?;
             let asset = iroha
                 .request(asset::by_id(asset_id))
                 .wrap_err("Failed to get asset.")?;
@@ -848,7 +1010,7 @@ mod asset {
 
     impl RunArgs for List {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
-            let client = context.client_from_config();
+            let client = context.client_from_config()?;
 
             let vec = match self {
                 Self::All => client
@@ -922,7 +1084,7 @@ mod asset {
     impl RunArgs for GetKeyValue {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
             let Self { asset_id, key } = self;
-            let client = context.client_from_config();
+            let client = context.client_from_config()?;
             let find_key_value = FindAssetKeyValueByIdAndKey::new(asset_id, key);
             let asset = client
                 .request(find_key_value)
@@ -1008,6 +1170,837 @@ mod peer {
     }
 }
 
+mod trigger {
+    use std::{
+        io::Read as _,
+        time::{Duration, SystemTime},
+    };
+
+    use iroha::client;
+
+    use super::*;
+
+    /// Subcommand for dealing with triggers
+    #[derive(Debug, clap::Subcommand)]
+    pub enum Args {
+        /// Register trigger
+        Register(Register),
+        /// Unregister trigger
+        Unregister(Unregister),
+        /// List triggers
+        #[clap(subcommand)]
+        List(List),
+        /// Get trigger by its id
+        Get(Get),
+        /// Execute trigger which is registered with the `execute` filter
+        Execute(Execute),
+        /// Add repetitions to the trigger
+        Mint(Mint),
+        /// Remove repetitions from the trigger
+        Burn(Burn),
+    }
+
+    impl RunArgs for Args {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            match_all!((self, context), {
+                Args::Register,
+                Args::Unregister,
+                Args::List,
+                Args::Get,
+                Args::Execute,
+                Args::Mint,
+                Args::Burn,
+            })
+        }
+    }
+
+    /// Register trigger
+    #[derive(Debug, clap::Args)]
+    pub struct Register {
+        /// Trigger id in form `name` or `name$domain_name`
+        #[arg(short, long)]
+        pub id: TriggerId,
+        /// Path to the Wasm file to execute, skip this flag to read instructions as Json or Json5 from stdin
+        #[arg(short, long)]
+        pub path: Option<PathBuf>,
+        /// How many times the trigger is executed: `indefinitely` or a number
+        #[arg(short, long, default_value = "indefinitely", value_parser = parse_repeats)]
+        pub repeats: Repeats,
+        /// Events which execute the trigger
+        #[command(subcommand)]
+        pub filter: Filter,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    /// Events which execute the trigger
+    #[derive(Debug, Clone, clap::Subcommand)]
+    pub enum Filter {
+        /// Execute the trigger by time
+        Time(TimeFilter),
+        /// Execute the trigger on data events
+        Data {
+            /// Data event filter given as JSON5 string
+            #[clap(value_parser = parse_data_filter)]
+            filter: DataEventFilter,
+        },
+        /// Execute the trigger with the `execute` subcommand or the `ExecuteTrigger` instruction
+        Execute,
+        /// Execute the trigger on pipeline events
+        Pipeline {
+            /// Pipeline event filter given as JSON5 string
+            #[clap(value_parser = parse_pipeline_filter)]
+            filter: PipelineEventFilterBox,
+        },
+    }
+
+    /// Execute the trigger by time
+    #[derive(Debug, Clone, Copy, clap::Args)]
+    pub struct TimeFilter {
+        /// Time of the first execution in milliseconds since the Unix epoch, defaults to now
+        #[arg(short, long)]
+        pub start: Option<u64>,
+        /// Period between executions in milliseconds, skip this flag to execute the trigger once
+        #[arg(short, long)]
+        pub period: Option<u64>,
+        /// Execute the trigger right before each block is committed
+        #[arg(long, conflicts_with_all(["start", "period"]))]
+        pub pre_commit: bool,
+    }
+
+    impl Filter {
+        fn into_triggering(self, trigger_id: &TriggerId) -> Result<TriggeringEventFilterBox> {
+            Ok(match self {
+                Self::Time(TimeFilter {
+                    pre_commit: true, ..
+                }) => {
+                    TriggeringEventFilterBox::Time(TimeEventFilter::new(ExecutionTime::PreCommit))
+                }
+                Self::Time(TimeFilter { start, period, .. }) => {
+                    let start = match start {
+                        Some(start) => Duration::from_millis(start),
+                        None => SystemTime::now()
+                            .duration_since(SystemTime::UNIX_EPOCH)
+                            .wrap_err("System time is before the Unix epoch")?,
+                    };
+                    let mut schedule = TimeSchedule::starting_at(start);
+                    if let Some(period) = period {
+                        schedule = schedule.with_period(Duration::from_millis(period));
+                    }
+                    TriggeringEventFilterBox::Time(TimeEventFilter::new(ExecutionTime::Schedule(
+                        schedule,
+                    )))
+                }
+                Self::Data { filter } => TriggeringEventFilterBox::Data(filter),
+                Self::Execute => TriggeringEventFilterBox::ExecuteTrigger(
+                    ExecuteTriggerEventFilter::new().for_trigger(trigger_id.clone()),
+                ),
+                Self::Pipeline { filter } => TriggeringEventFilterBox::Pipeline(filter),
+            })
+        }
+    }
+
+    fn parse_repeats(s: &str) -> Result<Repeats, String> {
+        if s == "indefinitely" {
+            return Ok(Repeats::Indefinitely);
+        }
+        s.parse::<u32>()
+            .map(Repeats::Exactly)
+            .map_err(|err| format!("Expected `indefinitely` or a number of repetitions: {err}"))
+    }
+
+    fn parse_data_filter(s: &str) -> Result<DataEventFilter, String> {
+        json5::from_str(s).map_err(|err| format!("Failed to deserialize filter from JSON5: {err}"))
+    }
+
+    fn parse_pipeline_filter(s: &str) -> Result<PipelineEventFilterBox, String> {
+        json5::from_str(s).map_err(|err| format!("Failed to deserialize filter from JSON5: {err}"))
+    }
+
+    impl RunArgs for Register {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                id,
+                path,
+                repeats,
+                filter,
+                metadata,
+            } = self;
+            let executable: Executable = if let Some(path) = path {
+                WasmSmartContract::from_compiled(
+                    read_file(path).wrap_err("Failed to read a Wasm from the file")?,
+                )
+                .into()
+            } else {
+                let mut raw_content = String::new();
+                stdin()
+                    .read_to_string(&mut raw_content)
+                    .wrap_err("Failed to read instructions from stdin")?;
+                let instructions: Vec<InstructionBox> = json5::from_str(&raw_content)
+                    .wrap_err("Failed to deserialize instructions from Json5")?;
+                instructions.into()
+            };
+            let filter = filter.into_triggering(&id)?;
+            let authority = context.configuration()?.account_id.clone();
+            let trigger = Trigger::new(id, Action::new(executable, repeats, authority, filter));
+            let register_trigger = iroha::data_model::isi::Register::trigger(trigger);
+            submit([register_trigger], metadata.load()?, context)
+                .wrap_err("Failed to register trigger")
+        }
+    }
+
+    /// Unregister trigger
+    #[derive(Debug, clap::Args)]
+    pub struct Unregister {
+        /// Trigger id in form `name` or `name$domain_name`
+        #[arg(short, long)]
+        pub id: TriggerId,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Unregister {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self { id, metadata } = self;
+            let unregister_trigger = iroha::data_model::isi::Unregister::trigger(id);
+            submit([unregister_trigger], metadata.load()?, context)
+                .wrap_err("Failed to unregister trigger")
+        }
+    }
+
+    /// List triggers with this command
+    #[derive(clap::Subcommand, Debug, Clone)]
+    pub enum List {
+        /// Ids of all active triggers
+        All,
+        /// Triggers registered in the domain
+        Domain {
+            /// Domain name as double-quoted string
+            #[arg(short, long)]
+            id: DomainId,
+        },
+    }
+
+    impl RunArgs for List {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let client = context.client_from_config()?;
+
+            match self {
+                Self::All => {
+                    let ids = client
+                        .request(client::trigger::all_ids())
+                        .wrap_err("Failed to get all trigger ids")?;
+                    context.print_data(&ids.collect::<QueryResult<Vec<_>>>()?)?;
+                }
+                Self::Domain { id } => {
+                    let triggers = client
+                        .request(client::trigger::by_domain_id(id))
+                        .wrap_err("Failed to get triggers of the domain")?;
+                    context.print_data(&triggers.collect::<QueryResult<Vec<_>>>()?)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Get trigger by its id
+    #[derive(Debug, clap::Args)]
+    pub struct Get {
+        /// Trigger id in form `name` or `name$domain_name`
+        #[arg(short, long)]
+        pub id: TriggerId,
+    }
+
+    impl RunArgs for Get {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let client = context.client_from_config()?;
+            let trigger = client
+                .request(client::trigger::by_id(self.id))
+                .wrap_err("Failed to get trigger")?;
+            context.print_data(&trigger)?;
+            Ok(())
+        }
+    }
+
+    /// Execute trigger
+    #[derive(Debug, clap::Args)]
+    pub struct Execute {
+        /// Trigger id in form `name` or `name$domain_name`
+        #[arg(short, long)]
+        pub id: TriggerId,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Execute {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self { id, metadata } = self;
+            submit([ExecuteTrigger::new(id)], metadata.load()?, context)
+                .wrap_err("Failed to execute trigger")
+        }
+    }
+
+    /// Add repetitions to the trigger
+    #[derive(Debug, clap::Args)]
+    pub struct Mint {
+        /// Trigger id in form `name` or `name$domain_name`
+        #[arg(short, long)]
+        pub id: TriggerId,
+        /// Number of repetitions to add
+        #[arg(short, long)]
+        pub repetitions: u32,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Mint {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                id,
+                repetitions,
+                metadata,
+            } = self;
+            let mint_repetitions =
+                iroha::data_model::isi::Mint::trigger_repetitions(repetitions, id);
+            submit([mint_repetitions], metadata.load()?, context)
+                .wrap_err("Failed to mint trigger repetitions")
+        }
+    }
+
+    /// Remove repetitions from the trigger
+    #[derive(Debug, clap::Args)]
+    pub struct Burn {
+        /// Trigger id in form `name` or `name$domain_name`
+        #[arg(short, long)]
+        pub id: TriggerId,
+        /// Number of repetitions to remove
+        #[arg(short, long)]
+        pub repetitions: u32,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Burn {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                id,
+                repetitions,
+                metadata,
+            } = self;
+            let burn_repetitions =
+                iroha::data_model::isi::Burn::trigger_repetitions(repetitions, id);
+            submit([burn_repetitions], metadata.load()?, context)
+                .wrap_err("Failed to burn trigger repetitions")
+        }
+    }
+}
+
+mod role {
+    use iroha::client;
+
+    use super::*;
+
+    /// Subcommand for dealing with roles
+    #[derive(Debug, clap::Subcommand)]
+    pub enum Args {
+        /// Register role
+        Register(Register),
+        /// Unregister role
+        Unregister(Unregister),
+        /// List roles
+        #[clap(subcommand)]
+        List(List),
+        /// Get role by its id
+        Get(Get),
+        /// Grant the role to the account
+        Grant(Grant),
+        /// Revoke the role from the account
+        Revoke(Revoke),
+    }
+
+    impl RunArgs for Args {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            match_all!((self, context), {
+                Args::Register,
+                Args::Unregister,
+                Args::List,
+                Args::Get,
+                Args::Grant,
+                Args::Revoke,
+            })
+        }
+    }
+
+    /// Register role
+    #[derive(Debug, clap::Args)]
+    pub struct Register {
+        /// Role name
+        #[arg(short, long)]
+        pub id: RoleId,
+        /// The JSON/JSON5 file with a permission token of the role, can be repeated
+        #[arg(short, long)]
+        pub permission: Vec<account::Permission>,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Register {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                id,
+                permission,
+                metadata,
+            } = self;
+            let permissions: Vec<_> = permission
+                .into_iter()
+                .map(|account::Permission(permission)| permission)
+                .collect();
+            permission::validate(&context.client_from_config()?, &permissions)?;
+            let role = permissions
+                .into_iter()
+                .fold(Role::new(id), NewRole::add_permission);
+            let register_role = iroha::data_model::isi::Register::role(role);
+            submit([register_role], metadata.load()?, context).wrap_err("Failed to register role")
+        }
+    }
+
+    /// Unregister role
+    #[derive(Debug, clap::Args)]
+    pub struct Unregister {
+        /// Role name
+        #[arg(short, long)]
+        pub id: RoleId,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Unregister {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self { id, metadata } = self;
+            let unregister_role = iroha::data_model::isi::Unregister::role(id);
+            submit([unregister_role], metadata.load()?, context)
+                .wrap_err("Failed to unregister role")
+        }
+    }
+
+    /// List roles with this command
+    #[derive(clap::Subcommand, Debug, Clone)]
+    pub enum List {
+        /// All roles
+        All,
+        /// Ids of the roles granted to the account
+        Account {
+            /// Account id
+            #[arg(short, long)]
+            id: AccountId,
+        },
+    }
+
+    impl RunArgs for List {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let client = context.client_from_config()?;
+
+            match self {
+                Self::All => {
+                    let roles = client
+                        .request(client::role::all())
+                        .wrap_err("Failed to get all roles")?;
+                    context.print_data(&roles.collect::<QueryResult<Vec<_>>>()?)?;
+                }
+                Self::Account { id } => {
+                    let role_ids = client
+                        .request(client::role::by_account_id(id))
+                        .wrap_err("Failed to get roles of the account")?;
+                    context.print_data(&role_ids.collect::<QueryResult<Vec<_>>>()?)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Get role by its id
+    #[derive(Debug, clap::Args)]
+    pub struct Get {
+        /// Role name
+        #[arg(short, long)]
+        pub id: RoleId,
+    }
+
+    impl RunArgs for Get {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let client = context.client_from_config()?;
+            let role = client
+                .request(client::role::by_id(self.id))
+                .wrap_err("Failed to get role")?;
+            context.print_data(&role)?;
+            Ok(())
+        }
+    }
+
+    /// Grant the role to the account
+    #[derive(Debug, clap::Args)]
+    pub struct Grant {
+        /// Role name
+        #[arg(short, long)]
+        pub id: RoleId,
+        /// Account id in form `name@domain_name`
+        #[arg(short, long)]
+        pub account: AccountId,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Grant {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                id,
+                account,
+                metadata,
+            } = self;
+            let grant_role = iroha::data_model::isi::Grant::role(id, account);
+            submit([grant_role], metadata.load()?, context)
+                .wrap_err("Failed to grant the role to the account")
+        }
+    }
+
+    /// Revoke the role from the account
+    #[derive(Debug, clap::Args)]
+    pub struct Revoke {
+        /// Role name
+        #[arg(short, long)]
+        pub id: RoleId,
+        /// Account id in form `name@domain_name`
+        #[arg(short, long)]
+        pub account: AccountId,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Revoke {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                id,
+                account,
+                metadata,
+            } = self;
+            let revoke_role = iroha::data_model::isi::Revoke::role(id, account);
+            submit([revoke_role], metadata.load()?, context)
+                .wrap_err("Failed to revoke the role from the account")
+        }
+    }
+}
+
+mod permission {
+    use std::collections::BTreeSet;
+
+    use iroha::client;
+    use serde_json::{Map, Value};
+
+    use super::*;
+
+    /// Subcommand for dealing with permissions of accounts
+    #[derive(Debug, clap::Subcommand)]
+    pub enum Args {
+        /// Grant the permission to the account
+        Grant(Grant),
+        /// Revoke the permission from the account
+        Revoke(Revoke),
+        /// List permissions of the account
+        List(List),
+    }
+
+    impl RunArgs for Args {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            match_all!((self, context), { Args::Grant, Args::Revoke, Args::List })
+        }
+    }
+
+    /// Grant the permission to the account
+    #[derive(Debug, clap::Args)]
+    pub struct Grant {
+        /// Account id in form `name@domain_name`
+        #[arg(short, long)]
+        pub account: AccountId,
+        /// The JSON/JSON5 file with a permission token
+        #[arg(short, long)]
+        pub permission: account::Permission,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Grant {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                account,
+                permission: account::Permission(permission),
+                metadata,
+            } = self;
+            validate(
+                &context.client_from_config()?,
+                std::slice::from_ref(&permission),
+            )?;
+            let grant = iroha::data_model::isi::Grant::permission(permission, account);
+            submit([grant], metadata.load()?, context)
+                .wrap_err("Failed to grant the permission to the account")
+        }
+    }
+
+    /// Revoke the permission from the account
+    #[derive(Debug, clap::Args)]
+    pub struct Revoke {
+        /// Account id in form `name@domain_name`
+        #[arg(short, long)]
+        pub account: AccountId,
+        /// The JSON/JSON5 file with a permission token
+        #[arg(short, long)]
+        pub permission: account::Permission,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Revoke {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                account,
+                permission: account::Permission(permission),
+                metadata,
+            } = self;
+            // Not validated, so that the permissions no longer known to the executor can be revoked
+            let revoke = iroha::data_model::isi::Revoke::permission(permission, account);
+            submit([revoke], metadata.load()?, context)
+                .wrap_err("Failed to revoke the permission from the account")
+        }
+    }
+
+    /// List permissions of the account
+    #[derive(Debug, clap::Args)]
+    pub struct List {
+        /// Account id in form `name@domain_name`
+        #[arg(short, long)]
+        pub account: AccountId,
+    }
+
+    impl RunArgs for List {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let client = context.client_from_config()?;
+            let permissions = client
+                .request(client::permission::by_account_id(self.account))
+                .wrap_err("Failed to get permissions of the account")?;
+            context.print_data(&permissions.collect::<QueryResult<Vec<_>>>()?)?;
+            Ok(())
+        }
+    }
+
+    /// Check the permissions against the data model of the executor,
+    /// so that typos in their ids and payloads are caught before submitting them
+    pub fn validate(client: &Client, permissions: &[Permission]) -> Result<()> {
+        if permissions.is_empty() {
+            return Ok(());
+        }
+        let data_model = client
+            .request(client::executor::data_model())
+            .wrap_err("Failed to get the executor data model")?;
+        let schema: Map<String, Value> = data_model
+            .schema()
+            .deserialize()
+            .wrap_err("Failed to deserialize the executor data model schema")?;
+        for permission in permissions {
+            validate_permission(data_model.permissions(), &schema, permission)
+                .map_err(|err| eyre!("Invalid permission `{}`: {err}", permission.id()))?;
+        }
+        Ok(())
+    }
+
+    fn validate_permission(
+        supported: &BTreeSet<PermissionId>,
+        schema: &Map<String, Value>,
+        permission: &Permission,
+    ) -> Result<(), String> {
+        if !supported.contains(permission.id()) {
+            let supported = supported
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(format!(
+                "it isn't supported by the executor, supported permissions are: {supported}"
+            ));
+        }
+        let payload: Value = permission
+            .payload()
+            .deserialize()
+            .map_err(|err| format!("payload isn't a valid JSON: {err}"))?;
+        validate_value(schema, permission.id().name().as_ref(), &payload, "payload")
+    }
+
+    /// Check the JSON `value` against the schema of the type `ty`.
+    /// Types missing from the schema and the ones without a well-known JSON form, e.g. enums, are accepted as is.
+    fn validate_value(
+        schema: &Map<String, Value>,
+        ty: &str,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), String> {
+        let mismatch = || Err(format!("`{path}` is expected to be `{ty}`, got `{value}`"));
+        let Some(type_schema) = schema.get(ty) else {
+            return Ok(());
+        };
+        match type_schema {
+            // Unit struct
+            Value::Null if !value.is_null() => mismatch(),
+            Value::String(inner) if inner == ty => match ty {
+                "String" if !value.is_string() => mismatch(),
+                "bool" if !value.is_boolean() => mismatch(),
+                _ => Ok(()),
+            },
+            Value::String(inner) => validate_value(schema, inner, value, path),
+            Value::Object(meta) => match meta.iter().next() {
+                Some((kind, Value::String(_))) if kind == "Int" && !value.is_number() => mismatch(),
+                Some((kind, Value::String(inner))) if kind == "Option" => {
+                    if value.is_null() {
+                        Ok(())
+                    } else {
+                        validate_value(schema, inner, value, path)
+                    }
+                }
+                Some((kind, Value::String(inner))) if kind == "Vec" => {
+                    let Value::Array(elements) = value else {
+                        return mismatch();
+                    };
+                    elements.iter().enumerate().try_for_each(|(i, element)| {
+                        validate_value(schema, inner, element, &format!("{path}[{i}]"))
+                    })
+                }
+                Some((kind, Value::Array(fields))) if kind == "Struct" => match value {
+                    Value::Object(object) => validate_fields(schema, fields, object, path),
+                    // Ids and other structs which are serialized as strings
+                    Value::String(_) => Ok(()),
+                    _ => mismatch(),
+                },
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_fields(
+        schema: &Map<String, Value>,
+        fields: &[Value],
+        object: &Map<String, Value>,
+        path: &str,
+    ) -> Result<(), String> {
+        let fields: Vec<(&str, &str)> = fields
+            .iter()
+            .filter_map(|field| Some((field["name"].as_str()?, field["type"].as_str()?)))
+            .collect();
+        if let Some(unknown) = object
+            .keys()
+            .find(|key| fields.iter().all(|(name, _)| name != key))
+        {
+            let expected = fields
+                .iter()
+                .map(|(name, _)| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(format!(
+                "unknown field `{path}.{unknown}`, expected one of: {expected}"
+            ));
+        }
+        for (name, ty) in fields {
+            let path = format!("{path}.{name}");
+            match object.get(name) {
+                Some(value) => validate_value(schema, ty, value, &path)?,
+                None if ty.starts_with("Option<") => {}
+                None => return Err(format!("missing field `{path}`")),
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_json::json;
+
+        use super::*;
+
+        fn schema() -> Map<String, Value> {
+            let Value::Object(schema) = json!({
+                "CanSetParameters": null,
+                "CanUnregisterDomain": { "Struct": [{ "name": "domain_id", "type": "DomainId" }] },
+                "CanMintAssetWithDefinition": {
+                    "Struct": [
+                        { "name": "asset_definition_id", "type": "AssetDefinitionId" },
+                        { "name": "limit", "type": "Option<u32>" }
+                    ]
+                },
+                "DomainId": { "Struct": [{ "name": "name", "type": "Name" }] },
+                "Name": "String",
+                "String": "String",
+                "Option<u32>": { "Option": "u32" },
+                "u32": { "Int": "FixedWidth" }
+            }) else {
+                unreachable!()
+            };
+            schema
+        }
+
+        fn validate(name: &str, payload: Value) -> Result<(), String> {
+            let supported = [
+                "CanSetParameters",
+                "CanUnregisterDomain",
+                "CanMintAssetWithDefinition",
+            ]
+            .into_iter()
+            .map(|name| PermissionId::new(name.parse().unwrap()))
+            .collect();
+            let permission = Permission::new(PermissionId::new(name.parse().unwrap()), payload);
+            validate_permission(&supported, &schema(), &permission)
+        }
+
+        #[test]
+        fn permissions_matching_schema_are_valid() {
+            validate("CanSetParameters", Value::Null).unwrap();
+            validate("CanUnregisterDomain", json!({ "domain_id": "wonderland" })).unwrap();
+            validate(
+                "CanUnregisterDomain",
+                json!({ "domain_id": { "name": "wonderland" } }),
+            )
+            .unwrap();
+            validate(
+                "CanMintAssetWithDefinition",
+                json!({ "asset_definition_id": "rose#wonderland" }),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn permissions_not_matching_schema_are_rejected() {
+            let err = validate("CanUnregisterDomian", Value::Null).unwrap_err();
+            assert!(err.contains("supported permissions are"), "{err}");
+
+            let err =
+                validate("CanUnregisterDomain", json!({ "domain": "wonderland" })).unwrap_err();
+            assert!(err.contains("unknown field `payload.domain`"), "{err}");
+
+            let err = validate("CanUnregisterDomain", json!({})).unwrap_err();
+            assert!(err.contains("missing field `payload.domain_id`"), "{err}");
+
+            let err =
+                validate("CanUnregisterDomain", json!({ "domain_id": { "name": 1 } })).unwrap_err();
+            assert!(err.contains("`payload.domain_id.name`"), "{err}");
+
+            let err = validate(
+                "CanMintAssetWithDefinition",
+                json!({ "asset_definition_id": "rose#wonderland", "limit": "ten" }),
+            )
+            .unwrap_err();
+            assert!(err.contains("`payload.limit`"), "{err}");
+
+            validate("CanSetParameters", json!({})).unwrap_err();
+        }
+    }
+}
+
 mod wasm {
     use std::{io::Read, path::PathBuf};
 
@@ -1065,6 +2058,257 @@ mod json {
         }
     }
 }
+
+mod transaction {
+    use std::{io::Read as _, path::Path, time::Duration};
+
+    use iroha::{config::DEFAULT_TRANSACTION_TIME_TO_LIVE, crypto::KeyPair};
+    use iroha_version::prelude::{DecodeVersioned as _, EncodeVersioned as _};
+    use parity_scale_codec::{DecodeAll as _, Encode as _};
+
+    use super::*;
+
+    /// Build, sign and submit transactions in separate steps,
+    /// so that they can be signed on a machine without network access
+    #[derive(Debug, clap::Subcommand)]
+    pub enum Args {
+        /// Build an unsigned transaction out of the instructions as Json or Json5 read from stdin
+        Build(Build),
+        /// Sign an unsigned transaction or add a signature to a signed one, without network access
+        Sign(Sign),
+        /// Submit a signed transaction
+        Submit(Submit),
+    }
+
+    impl RunArgs for Args {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            match_all!((self, context), { Args::Build, Args::Sign, Args::Submit })
+        }
+    }
+
+    /// Encoding of a transaction file
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+    pub enum Format {
+        /// SCALE, as sent to the peers
+        #[default]
+        Scale,
+        /// JSON, to review the transaction before signing it
+        Json,
+    }
+
+    impl Format {
+        fn encode_unsigned(self, transaction: &TransactionBuilder) -> Result<Vec<u8>> {
+            Ok(match self {
+                Self::Scale => transaction.encode(),
+                Self::Json => serde_json::to_vec_pretty(transaction)?,
+            })
+        }
+
+        fn encode_signed(self, transaction: &SignedTransaction) -> Result<Vec<u8>> {
+            Ok(match self {
+                Self::Scale => transaction.encode_versioned(),
+                Self::Json => serde_json::to_vec_pretty(transaction)?,
+            })
+        }
+    }
+
+    /// Build an unsigned transaction, which takes no private key.
+    ///
+    /// The configuration is only needed for the authority and the chain id which aren't given.
+    #[derive(Debug, clap::Args)]
+    pub struct Build {
+        /// Path to write the unsigned transaction to
+        #[arg(short, long, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
+        pub output: PathBuf,
+        /// Encoding of the unsigned transaction
+        #[arg(short, long, value_enum, default_value_t)]
+        pub format: Format,
+        /// Account on behalf of which the transaction is made, defaults to the one from the configuration
+        #[arg(short, long)]
+        pub authority: Option<AccountId>,
+        /// Id of the chain the transaction is made for, defaults to the one from the configuration
+        #[arg(long)]
+        pub chain_id: Option<ChainId>,
+        /// Time to live of the transaction in milliseconds, defaults to 100 seconds.
+        /// It should cover the time until the transaction is signed and submitted
+        #[arg(long)]
+        pub ttl: Option<u64>,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for Build {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                output,
+                format,
+                authority,
+                chain_id,
+                ttl,
+                metadata,
+            } = self;
+            let mut raw_content = String::new();
+            stdin().read_to_string(&mut raw_content)?;
+            let instructions: Vec<InstructionBox> = json5::from_str(&raw_content)?;
+
+            let (chain_id, authority) = match (chain_id, authority) {
+                (Some(chain_id), Some(authority)) => (chain_id, authority),
+                (chain_id, authority) => {
+                    let config = context.configuration()?;
+                    (
+                        chain_id.unwrap_or_else(|| config.chain_id.clone()),
+                        authority.unwrap_or_else(|| config.account_id.clone()),
+                    )
+                }
+            };
+            let mut transaction = TransactionBuilder::new(chain_id, authority)
+                .with_instructions(instructions)
+                .with_metadata(metadata.load()?);
+            transaction
+                .set_ttl(ttl.map_or(DEFAULT_TRANSACTION_TIME_TO_LIVE, Duration::from_millis));
+            write_transaction(&output, format.encode_unsigned(&transaction)?)
+        }
+    }
+
+    /// Sign a transaction file
+    #[derive(Debug, clap::Args)]
+    pub struct Sign {
+        /// Path to the unsigned or signed transaction, either in SCALE or JSON
+        #[arg(short, long, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
+        pub input: PathBuf,
+        /// Path to write the signed transaction to
+        #[arg(short, long, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
+        pub output: PathBuf,
+        /// JSON file with the `public_key` and `private_key` to sign with,
+        /// the key pair from the configuration is used if omitted
+        #[arg(short, long, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
+        pub key_pair: Option<PathBuf>,
+        /// Encoding of the signed transaction
+        #[arg(short, long, value_enum, default_value_t)]
+        pub format: Format,
+    }
+
+    impl RunArgs for Sign {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                input,
+                output,
+                key_pair,
+                format,
+            } = self;
+            let key_pair = match key_pair {
+                Some(path) => {
+                    let key_pair_json = fs::read_to_string(&path).wrap_err_with(|| {
+                        eyre!("Failed to read the key pair file `{}`", path.display())
+                    })?;
+                    serde_json::from_str::<KeyPair>(&key_pair_json).wrap_err_with(|| {
+                        eyre!("Failed to deserialize key pair from `{}`", path.display())
+                    })?
+                }
+                None => context.configuration()?.key_pair.clone(),
+            };
+
+            let raw_transaction = read_transaction(&input)?;
+            let transaction = match decode_signed(&raw_transaction) {
+                Ok(transaction) => transaction.sign(&key_pair),
+                Err(_) => decode_unsigned(&raw_transaction)
+                    .wrap_err("The file contains neither an unsigned nor a signed transaction")?
+                    .sign(&key_pair),
+            };
+            write_transaction(&output, format.encode_signed(&transaction)?)?;
+            context.print_data(&transaction.hash())
+        }
+    }
+
+    /// Submit a signed transaction file
+    #[derive(Debug, clap::Args)]
+    pub struct Submit {
+        /// Path to the signed transaction, either in SCALE or JSON
+        #[arg(short, long, value_name("PATH"), value_hint(clap::ValueHint::FilePath))]
+        pub input: PathBuf,
+        /// Wait until the transaction is committed or rejected
+        #[arg(short, long)]
+        pub wait: bool,
+    }
+
+    impl RunArgs for Submit {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self { input, wait } = self;
+            let transaction = decode_signed(&read_transaction(&input)?)?;
+
+            let iroha = context.client_from_config()?;
+            let hash = if wait {
+                iroha.submit_transaction_blocking(&transaction)
+            } else {
+                iroha.submit_transaction(&transaction)
+            }
+            .wrap_err("Failed to submit transaction")?;
+            context.print_data(&hash)
+        }
+    }
+
+    fn read_transaction(path: &Path) -> Result<Vec<u8>> {
+        read_file(path)
+            .wrap_err_with(|| eyre!("Failed to read the transaction file `{}`", path.display()))
+    }
+
+    fn write_transaction(path: &Path, content: Vec<u8>) -> Result<()> {
+        fs::write(path, content)
+            .wrap_err_with(|| eyre!("Failed to write the transaction file `{}`", path.display()))
+    }
+
+    /// JSON files start with an object, while SCALE encoding never starts with `{`
+    fn is_json(content: &[u8]) -> bool {
+        content.iter().find(|byte| !byte.is_ascii_whitespace()) == Some(&b'{')
+    }
+
+    fn decode_unsigned(content: &[u8]) -> Result<TransactionBuilder> {
+        if is_json(content) {
+            serde_json::from_slice(content).wrap_err("Failed to deserialize unsigned transaction")
+        } else {
+            TransactionBuilder::decode_all(&mut &*content)
+                .wrap_err("Failed to decode unsigned transaction")
+        }
+    }
+
+    fn decode_signed(content: &[u8]) -> Result<SignedTransaction> {
+        if is_json(content) {
+            serde_json::from_slice(content).wrap_err("Failed to deserialize signed transaction")
+        } else {
+            SignedTransaction::decode_all_versioned(content)
+                .wrap_err("Failed to decode signed transaction")
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use iroha::data_model::ChainId;
+
+        use super::*;
+
+        #[test]
+        fn transaction_files_roundtrip() -> Result<()> {
+            let key_pair = KeyPair::random();
+            let authority = AccountId::new("wonderland".parse()?, key_pair.public_key().clone());
+            let register_domain =
+                iroha::data_model::isi::Register::domain(Domain::new("offline".parse()?));
+            let unsigned = TransactionBuilder::new(ChainId::from("0"), authority)
+                .with_instructions([register_domain]);
+
+            for format in [Format::Scale, Format::Json] {
+                let content = format.encode_unsigned(&unsigned)?;
+                assert_eq!(decode_unsigned(&content)?, unsigned);
+                assert!(decode_signed(&content).is_err());
+
+                let signed = decode_unsigned(&content)?.sign(&key_pair);
+                let content = format.encode_signed(&signed)?;
+                assert_eq!(decode_signed(&content)?, signed);
+            }
+
+            Ok(())
+        }
+    }
+}
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
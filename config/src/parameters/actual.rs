@@ -274,6 +274,39 @@ impl Default for WasmRuntime {
 pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     pub max_content_len_bytes: u64,
+    pub query_history_depth: u64,
+    pub query_batch_max_size: NonZeroUsize,
+    /// Number of blocks the peer may lag behind the highest known one while reporting that it's ready
+    pub ready_max_blocks_behind: u64,
+    pub tls: Option<ToriiTls>,
+    pub rate_limit: ToriiRateLimit,
+    /// Keys which sign requests to the administrative endpoints. The endpoints are open if empty.
+    pub admin_public_keys: Vec<PublicKey>,
+}
+
+/// TLS termination settings of Torii. Clients are authenticated if `client_ca_file` is set.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::struct_field_names)]
+pub struct ToriiTls {
+    pub cert_file: WithOrigin<PathBuf>,
+    pub key_file: WithOrigin<PathBuf>,
+    pub client_ca_file: Option<WithOrigin<PathBuf>>,
+}
+
+/// Rate limits of Torii endpoints. Requests aren't limited if the respective limit isn't set.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(missing_docs)]
+pub struct ToriiRateLimit {
+    pub per_ip: Option<TokenBucket>,
+    pub per_account: Option<TokenBucket>,
+}
+
+/// Limit of `rate` requests per second on average with bursts of up to `burst` requests
+#[derive(Debug, Clone, Copy)]
+#[allow(missing_docs)]
+pub struct TokenBucket {
+    pub rate: NonZeroU32,
+    pub burst: NonZeroU32,
 }
 
 /// Complete configuration needed to start regular telemetry.
@@ -80,10 +80,16 @@ pub mod chain_wide {
 }
 
 pub mod torii {
-    use std::time::Duration;
+    use super::*;
 
     pub const MAX_CONTENT_LENGTH: u64 = 2_u64.pow(20) * 16;
     pub const QUERY_IDLE_TIME: Duration = Duration::from_secs(30);
+    /// Default number of blocks behind the latest one at which historical queries are served
+    pub const QUERY_HISTORY_DEPTH: u64 = 100;
+    /// Default maximum number of queries in a single batch
+    pub const QUERY_BATCH_MAX_SIZE: NonZeroUsize = nonzero!(32_usize);
+    /// Default number of blocks the peer may lag behind the highest known one while being ready
+    pub const READY_MAX_BLOCKS_BEHIND: u64 = 1;
 }
 
 pub mod telemetry {
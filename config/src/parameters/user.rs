@@ -86,11 +86,14 @@ pub struct Root {
 }
 
 #[derive(thiserror::Error, Debug, Copy, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum ParseError {
     #[error("Failed to construct the key pair")]
     BadKeyPair,
     #[error("Invalid genesis configuration")]
     BadGenesis,
+    #[error("Invalid Torii TLS configuration")]
+    BadToriiTls,
 }
 
 impl Root {
@@ -123,7 +126,11 @@ impl Root {
         let queue = self.queue;
         let snapshot = self.snapshot;
         let dev_telemetry = self.dev_telemetry;
-        let (torii, live_query_store) = self.torii.parse();
+        let torii = self
+            .torii
+            .parse()
+            .change_context(ParseError::BadToriiTls)
+            .ok_or_emit(&mut emitter);
         let telemetry = self.telemetry.map(actual::Telemetry::from);
         let chain_wide = self.chain_wide.parse();
 
@@ -147,6 +154,7 @@ impl Root {
             peer_id: peer_id.unwrap(),
         };
         let genesis = genesis.unwrap();
+        let (torii, live_query_store) = torii.unwrap();
 
         Ok(actual::Root {
             common: peer,
@@ -550,19 +558,104 @@ pub struct Torii {
     pub max_content_length: HumanBytes<u64>,
     #[config(default = "defaults::torii::QUERY_IDLE_TIME.into()")]
     pub query_idle_time: HumanDuration,
+    #[config(default = "defaults::torii::QUERY_HISTORY_DEPTH")]
+    pub query_history_depth: u64,
+    #[config(default = "defaults::torii::QUERY_BATCH_MAX_SIZE")]
+    pub query_batch_max_size: NonZeroUsize,
+    #[config(default = "defaults::torii::READY_MAX_BLOCKS_BEHIND")]
+    pub ready_max_blocks_behind: u64,
+    #[config(nested)]
+    pub tls: ToriiTls,
+    #[config(nested)]
+    pub rate_limit: ToriiRateLimit,
+    #[config(default)]
+    pub admin_public_keys: Vec<PublicKey>,
 }
 
 impl Torii {
-    fn parse(self) -> (actual::Torii, actual::LiveQueryStore) {
+    fn parse(self) -> Result<(actual::Torii, actual::LiveQueryStore), ToriiTlsConfigError> {
         let torii = actual::Torii {
             address: self.address,
             max_content_len_bytes: self.max_content_length.get(),
+            query_history_depth: self.query_history_depth,
+            query_batch_max_size: self.query_batch_max_size,
+            ready_max_blocks_behind: self.ready_max_blocks_behind,
+            tls: self.tls.parse()?,
+            rate_limit: self.rate_limit.parse(),
+            admin_public_keys: self.admin_public_keys,
         };
 
         let query = actual::LiveQueryStore {
             idle_time: self.query_idle_time.get(),
         };
 
-        (torii, query)
+        Ok((torii, query))
+    }
+}
+
+#[derive(Debug, ReadConfig)]
+#[allow(clippy::struct_field_names)]
+pub struct ToriiTls {
+    #[config(env = "API_TLS_CERT_FILE")]
+    pub cert_file: Option<WithOrigin<PathBuf>>,
+    #[config(env = "API_TLS_KEY_FILE")]
+    pub key_file: Option<WithOrigin<PathBuf>>,
+    #[config(env = "API_TLS_CLIENT_CA_FILE")]
+    pub client_ca_file: Option<WithOrigin<PathBuf>>,
+}
+
+impl ToriiTls {
+    fn parse(self) -> Result<Option<actual::ToriiTls>, ToriiTlsConfigError> {
+        match (self.cert_file, self.key_file, self.client_ca_file) {
+            (None, None, None) => Ok(None),
+            (Some(cert_file), Some(key_file), client_ca_file) => Ok(Some(actual::ToriiTls {
+                cert_file,
+                key_file,
+                client_ca_file,
+            })),
+            (Some(_), None, _) => Err(ToriiTlsConfigError::Inconsistent)
+                .attach_printable("`torii.tls.cert_file` is set, but `torii.tls.key_file` is not")?,
+            (None, Some(_), _) => Err(ToriiTlsConfigError::Inconsistent)
+                .attach_printable("`torii.tls.key_file` is set, but `torii.tls.cert_file` is not")?,
+            (None, None, Some(_)) => Err(ToriiTlsConfigError::Inconsistent).attach_printable(
+                "`torii.tls.client_ca_file` is set, but `torii.tls.cert_file` and `torii.tls.key_file` are not",
+            )?,
+        }
+    }
+}
+
+/// Limits are in requests per second, bursts default to the limit
+#[derive(Debug, Clone, Copy, ReadConfig)]
+#[allow(clippy::struct_field_names)]
+pub struct ToriiRateLimit {
+    #[config(env = "API_RATE_LIMIT_PER_IP")]
+    pub per_ip: Option<NonZeroU32>,
+    #[config(env = "API_RATE_LIMIT_PER_IP_BURST")]
+    pub per_ip_burst: Option<NonZeroU32>,
+    #[config(env = "API_RATE_LIMIT_PER_ACCOUNT")]
+    pub per_account: Option<NonZeroU32>,
+    #[config(env = "API_RATE_LIMIT_PER_ACCOUNT_BURST")]
+    pub per_account_burst: Option<NonZeroU32>,
+}
+
+impl ToriiRateLimit {
+    fn parse(self) -> actual::ToriiRateLimit {
+        let bucket = |rate: Option<NonZeroU32>, burst: Option<NonZeroU32>| {
+            rate.map(|rate| actual::TokenBucket {
+                rate,
+                burst: burst.unwrap_or(rate),
+            })
+        };
+
+        actual::ToriiRateLimit {
+            per_ip: bucket(self.per_ip, self.per_ip_burst),
+            per_account: bucket(self.per_account, self.per_account_burst),
+        }
     }
 }
+
+#[derive(Debug, displaydoc::Display, thiserror::Error, Copy, Clone)]
+pub enum ToriiTlsConfigError {
+    /// Invalid combination of provided parameters
+    Inconsistent,
+}
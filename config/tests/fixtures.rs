@@ -115,6 +115,15 @@ fn minimal_config_snapshot() {
                     },
                 },
                 max_content_len_bytes: 16777216,
+                query_history_depth: 100,
+                query_batch_max_size: 32,
+                ready_max_blocks_behind: 1,
+                tls: None,
+                rate_limit: ToriiRateLimit {
+                    per_ip: None,
+                    per_account: None,
+                },
+                admin_public_keys: [],
             },
             kura: Kura {
                 init_mode: Strict,
@@ -289,6 +298,17 @@ fn inconsistent_genesis_config() {
     );
 }
 
+#[test]
+fn inconsistent_torii_tls_config() {
+    let error = load_config_from_fixtures("inconsistent_torii_tls.toml")
+        .expect_err("should fail with bad torii tls config");
+
+    assert_contains!(
+        format!("{error:?}"),
+        "`torii.tls.cert_file` is set, but `torii.tls.key_file` is not"
+    );
+}
+
 /// Aims the purpose of checking that every single provided env variable is consumed and parsed
 /// into a valid config.
 #[test]
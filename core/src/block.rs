@@ -4,10 +4,10 @@
 //! 2. If a block is received, i.e. deserialized:
 //!     `SignedBlock` -> `ValidBlock` -> `CommittedBlock`
 //! [`Block`]s are organised into a linear sequence over time (also known as the block chain).
-use std::error::Error as _;
+use std::{collections::BTreeSet, error::Error as _};
 
 use iroha_config::parameters::defaults::chain_wide::CONSENSUS_ESTIMATION as DEFAULT_CONSENSUS_ESTIMATION;
-use iroha_crypto::{HashOf, KeyPair, MerkleTree, SignatureOf, SignaturesOf};
+use iroha_crypto::{HashOf, KeyPair, MerkleTree, SignatureOf, SignaturesOf, SparseMerkleTree};
 use iroha_data_model::{
     block::*,
     events::prelude::*,
@@ -38,6 +38,8 @@ pub enum TransactionValidationError {
 pub enum BlockValidationError {
     /// Block has committed transactions
     HasCommittedTransactions,
+    /// Block has several transactions with the same payload
+    DuplicateTransactions,
     /// Mismatch between the actual and expected hashes of the latest block. Expected: {expected:?}, actual: {actual:?}
     LatestBlockHashMismatch {
         /// Expected value
@@ -61,6 +63,13 @@ pub enum BlockValidationError {
     },
     /// The transaction hash stored in the block header does not match the actual transaction hash
     TransactionHashMismatch,
+    /// Mismatch between the actual and expected hashes of the world state. Expected: {expected:?}, actual: {actual:?}
+    StateHashMismatch {
+        /// Expected value
+        expected: Option<HashOf<SparseMerkleTree<StateEntry>>>,
+        /// Actual value
+        actual: Option<HashOf<SparseMerkleTree<StateEntry>>>,
+    },
     /// Error during transaction validation
     TransactionValidation(#[from] TransactionValidationError),
     /// Mismatch between the actual and expected topology. Expected: {expected:?}, actual: {actual:?}
@@ -149,6 +158,7 @@ mod pending {
             prev_block_hash: Option<HashOf<SignedBlock>>,
             view_change_index: u64,
             transactions: &[CommittedTransaction],
+            state_hash: Option<HashOf<SparseMerkleTree<StateEntry>>>,
         ) -> BlockHeader {
             BlockHeader {
                 height: previous_height + 1,
@@ -158,6 +168,7 @@ mod pending {
                     .map(|value| value.as_ref().hash())
                     .collect::<MerkleTree<_>>()
                     .hash(),
+                state_hash,
                 timestamp_ms: SystemTime::now()
                     .duration_since(SystemTime::UNIX_EPOCH)
                     .expect("Failed to get the current system time")
@@ -202,7 +213,8 @@ mod pending {
 
         /// Chain the block with existing blockchain.
         ///
-        /// Upon executing this method current timestamp is stored in the block header.
+        /// Upon executing this method current timestamp and the hash
+        /// of the world state after the previous block are stored in the block header.
         pub fn chain(
             self,
             view_change_index: u64,
@@ -216,6 +228,7 @@ mod pending {
                     state.latest_block_hash(),
                     view_change_index,
                     &transactions,
+                    state.world.state_hash(),
                 ),
                 transactions,
                 commit_topology: self.0.commit_topology.ordered_peers,
@@ -261,6 +274,8 @@ mod valid {
         /// - There is a mismatch between candidate block height and actual blockchain height
         /// - There is a mismatch between candidate block previous block hash and actual latest block hash
         /// - Block has committed transactions
+        /// - Block has several transactions with the same payload
+        /// - Block header state hash doesn't match with the state after the previous block
         /// - Block header transaction hashes don't match with computed transaction hashes
         /// - Error during validation of individual transactions
         /// - Topology field is incorrect
@@ -326,9 +341,11 @@ mod valid {
                 }
             }
 
+            // Transactions are compared by their payloads, so that a transaction
+            // can't be executed again with another set of signatures
             if block
                 .transactions()
-                .any(|tx| state_block.has_transaction(tx.as_ref().hash()))
+                .any(|tx| state_block.has_transaction_payload(tx.as_ref().hash_of_payload()))
             {
                 return WithEvents::new(Err((
                     block,
@@ -336,6 +353,28 @@ mod valid {
                 )));
             }
 
+            let mut payloads = BTreeSet::new();
+            if !block
+                .transactions()
+                .all(|tx| payloads.insert(tx.as_ref().hash_of_payload()))
+            {
+                return WithEvents::new(Err((block, BlockValidationError::DuplicateTransactions)));
+            }
+
+            // The header commits to the state the block is applied to, see `BlockHeader::state_hash`
+            let expected_state_hash = state_block.world.state_hash();
+            let actual_state_hash = block.header().state_hash;
+
+            if expected_state_hash != actual_state_hash {
+                return WithEvents::new(Err((
+                    block,
+                    BlockValidationError::StateHashMismatch {
+                        expected: expected_state_hash,
+                        actual: actual_state_hash,
+                    },
+                )));
+            }
+
             if let Err(error) = Self::validate_transactions(
                 &block,
                 expected_chain_id,
@@ -488,6 +527,7 @@ mod valid {
                     height: 2,
                     previous_block_hash: None,
                     transactions_hash: None,
+                    state_hash: None,
                     timestamp_ms: 0,
                     view_change_index: 0,
                     consensus_estimation_ms: DEFAULT_CONSENSUS_ESTIMATION
@@ -668,12 +708,41 @@ mod valid {
 
 mod commit {
     use super::*;
+    use crate::state::StateBlock;
 
     /// Represents a block accepted by consensus.
     /// Every [`Self`] will have a different height.
     #[derive(Debug, Clone)]
     pub struct CommittedBlock(pub(super) ValidBlock);
 
+    impl CommittedBlock {
+        /// Execute transactions of the `block` which is already committed to the blockchain.
+        ///
+        /// Signatures and consensus are not checked since the block is expected to come from [`Kura`](crate::kura::Kura).
+        ///
+        /// # Errors
+        ///
+        /// Fails with the hash of the first committed transaction which doesn't pass validation
+        pub(crate) fn replay(
+            block: SignedBlock,
+            state_block: &mut StateBlock<'_>,
+        ) -> Result<Self, (HashOf<SignedTransaction>, TransactionRejectionReason)> {
+            for tx in block.transactions().filter(|tx| tx.error.is_none()) {
+                let tx = AcceptedTransaction(tx.value.clone());
+                state_block
+                    .transaction_executor()
+                    .validate(tx, state_block)
+                    .map_err(|(tx, error)| (tx.hash(), error))?;
+            }
+
+            if block.header().is_genesis() {
+                *state_block.world.trusted_peers_ids = block.commit_topology().clone();
+            }
+
+            Ok(Self(ValidBlock(block)))
+        }
+    }
+
     impl From<CommittedBlock> for ValidBlock {
         fn from(source: CommittedBlock) -> Self {
             ValidBlock(source.0.into())
@@ -780,6 +849,13 @@ mod event {
         }
     }
 
+    impl CommittedBlock {
+        /// Pipeline events emitted while the block was validated and committed, in the order of emission.
+        pub(crate) fn pipeline_events(&self) -> impl Iterator<Item = PipelineEventBox> + '_ {
+            self.0.produce_events().chain(self.produce_events())
+        }
+    }
+
     impl EventProducer for BlockValidationError {
         fn produce_events(&self) -> impl Iterator<Item = PipelineEventBox> {
             core::iter::empty()
@@ -1,5 +1,10 @@
 //! This module contains structures and messages for synchronization of blocks between peers.
-use std::{fmt::Debug, num::NonZeroU32, sync::Arc, time::Duration};
+use std::{
+    fmt::Debug,
+    num::NonZeroU32,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use iroha_config::parameters::actual::BlockSync as Config;
 use iroha_crypto::HashOf;
@@ -8,6 +13,7 @@ use iroha_logger::prelude::*;
 use iroha_macro::*;
 use iroha_p2p::Post;
 use parity_scale_codec::{Decode, Encode};
+use parking_lot::Mutex;
 use tokio::sync::mpsc;
 
 use crate::{
@@ -35,6 +41,55 @@ impl BlockSynchronizerHandle {
     }
 }
 
+/// Time after which the block height reported by a peer is no longer trusted
+const HEIGHT_OBSERVATION_TTL: Duration = Duration::from_secs(60);
+
+/// Progress of the block synchronization, shared with the readiness reporting.
+#[derive(Debug, Clone, Default)]
+pub struct BlockSyncStatus {
+    highest_known_height: Arc<Mutex<Option<HeightObservation>>>,
+}
+
+/// Block height reported by a peer
+#[derive(Debug, Clone, Copy)]
+struct HeightObservation {
+    height: u64,
+    observed_at: Instant,
+}
+
+impl BlockSyncStatus {
+    /// Highest block height reported by other peers recently.
+    ///
+    /// Reported heights can't be verified until the blocks are received,
+    /// so they expire after [`HEIGHT_OBSERVATION_TTL`] not to keep the peer
+    /// lagging behind forever because of a single bogus report.
+    pub fn highest_known_height(&self) -> u64 {
+        let now = Instant::now();
+        self.highest_known_height
+            .lock()
+            .filter(|observation| !observation.is_expired(now))
+            .map_or(0, |observation| observation.height)
+    }
+
+    fn observe_height(&self, height: u64, now: Instant) {
+        let mut highest = self.highest_known_height.lock();
+        if highest.map_or(true, |observation| {
+            observation.is_expired(now) || observation.height <= height
+        }) {
+            *highest = Some(HeightObservation {
+                height,
+                observed_at: now,
+            });
+        }
+    }
+}
+
+impl HeightObservation {
+    fn is_expired(self, now: Instant) -> bool {
+        now.saturating_duration_since(self.observed_at) > HEIGHT_OBSERVATION_TTL
+    }
+}
+
 /// Structure responsible for block synchronization between peers.
 pub struct BlockSynchronizer {
     sumeragi: SumeragiHandle,
@@ -44,9 +99,15 @@ pub struct BlockSynchronizer {
     gossip_max_size: NonZeroU32,
     network: IrohaNetwork,
     state: Arc<State>,
+    status: BlockSyncStatus,
 }
 
 impl BlockSynchronizer {
+    /// Handle to the synchronization progress of [`Self`].
+    pub fn status(&self) -> BlockSyncStatus {
+        self.status.clone()
+    }
+
     /// Start [`Self`] actor.
     pub fn start(self) -> BlockSynchronizerHandle {
         let (message_sender, message_receiver) = mpsc::channel(1);
@@ -121,6 +182,7 @@ impl BlockSynchronizer {
             gossip_max_size: config.gossip_max_size,
             network,
             state,
+            status: BlockSyncStatus::default(),
         }
     }
 }
@@ -162,12 +224,18 @@ pub mod message {
         pub blocks: Vec<SignedBlock>,
         /// Peer id
         pub peer_id: PeerId,
+        /// Height of the latest block of the peer, which can be ahead of the shared `blocks`
+        pub latest_height: u64,
     }
 
     impl ShareBlocks {
         /// Construct [`ShareBlocks`].
-        pub const fn new(blocks: Vec<SignedBlock>, peer_id: PeerId) -> Self {
-            Self { blocks, peer_id }
+        pub const fn new(blocks: Vec<SignedBlock>, peer_id: PeerId, latest_height: u64) -> Self {
+            Self {
+                blocks,
+                peer_id,
+                latest_height,
+            }
         }
     }
 
@@ -190,7 +258,10 @@ pub mod message {
                     prev_hash,
                     peer_id,
                 }) => {
-                    let local_latest_block_hash = block_sync.state.view().latest_block_hash();
+                    let (local_latest_block_hash, local_height) = {
+                        let state_view = block_sync.state.view();
+                        (state_view.latest_block_hash(), state_view.height())
+                    };
 
                     if *latest_hash == local_latest_block_hash
                         || *prev_hash == local_latest_block_hash
@@ -223,13 +294,24 @@ pub mod message {
                         error!(hash=?prev_hash, "Blocks array is empty but shouldn't be.");
                     } else {
                         trace!(hash=?prev_hash, "Sharing blocks after hash");
-                        Message::ShareBlocks(ShareBlocks::new(blocks, block_sync.peer_id.clone()))
-                            .send_to(&block_sync.network, peer_id.clone())
-                            .await;
+                        Message::ShareBlocks(ShareBlocks::new(
+                            blocks,
+                            block_sync.peer_id.clone(),
+                            local_height,
+                        ))
+                        .send_to(&block_sync.network, peer_id.clone())
+                        .await;
                     }
                 }
-                Message::ShareBlocks(ShareBlocks { blocks, .. }) => {
+                Message::ShareBlocks(ShareBlocks {
+                    blocks,
+                    latest_height,
+                    ..
+                }) => {
                     use crate::sumeragi::message::BlockMessage;
+                    block_sync
+                        .status
+                        .observe_height(*latest_height, Instant::now());
                     for block in blocks.clone() {
                         block_sync
                             .sumeragi
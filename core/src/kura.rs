@@ -7,7 +7,11 @@ use std::{
     fs,
     io::{BufWriter, Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
 };
 
 use iroha_config::{kura::InitMode, parameters::actual::Kura as Config};
@@ -27,6 +31,9 @@ const LOCK_FILE_NAME: &str = "kura.lock";
 
 const SIZE_OF_BLOCK_HASH: u64 = Hash::LENGTH as u64;
 
+/// How often the Kura thread checks whether the block store is still writable
+const WRITABLE_CHECK_PERIOD: Duration = Duration::from_secs(1);
+
 /// The interface of Kura subsystem
 #[derive(Debug)]
 pub struct Kura {
@@ -39,6 +46,8 @@ pub struct Kura {
     block_data: Mutex<Vec<(HashOf<SignedBlock>, Option<Arc<SignedBlock>>)>>,
     /// Path to file for plain text blocks.
     block_plain_text_path: Option<PathBuf>,
+    /// Whether the block store was writable when last checked by the Kura thread.
+    writable: AtomicBool,
 }
 
 impl Kura {
@@ -60,6 +69,7 @@ impl Kura {
 
         let kura = Arc::new(Self {
             mode: config.init_mode,
+            writable: AtomicBool::new(block_store.is_writable()),
             block_store: Mutex::new(block_store),
             block_data: Mutex::new(Vec::new()),
             block_plain_text_path,
@@ -78,6 +88,7 @@ impl Kura {
             block_store: Mutex::new(BlockStore::new(PathBuf::new(), LockStatus::Locked)),
             block_data: Mutex::new(Vec::new()),
             block_plain_text_path: None,
+            writable: AtomicBool::new(false),
         })
     }
 
@@ -200,6 +211,7 @@ impl Kura {
             (block_data_guard.len(), block_data_guard.last().map(|d| d.0))
         };
         let mut should_exit = false;
+        let mut writable_checked_at = Instant::now();
         loop {
             // If kura receive shutdown then close block channel and write remaining blocks to the storage
             if shutdown_receiver.try_recv().is_ok() {
@@ -225,6 +237,11 @@ impl Kura {
 
                 written_block_count = block_data_guard.len();
                 drop(block_data_guard);
+                if writable_checked_at.elapsed() >= WRITABLE_CHECK_PERIOD {
+                    let writable = kura.block_store.lock().is_writable();
+                    kura.writable.store(writable, Ordering::Relaxed);
+                    writable_checked_at = Instant::now();
+                }
                 std::thread::sleep(std::time::Duration::from_millis(1));
                 continue;
             }
@@ -354,6 +371,13 @@ impl Kura {
         data.pop();
         data.push((block.hash(), Some(block)));
     }
+
+    /// Check whether new blocks can be written to the block store.
+    ///
+    /// Doesn't touch the disk, the check is done periodically by the Kura thread.
+    pub fn is_writable(&self) -> bool {
+        self.writable.load(Ordering::Relaxed)
+    }
 }
 
 /// Loaded block count
@@ -773,6 +797,18 @@ impl BlockStore {
         Ok(())
     }
 
+    /// Check whether the index, data and hashes files can be opened for appending.
+    pub fn is_writable(&self) -> bool {
+        [INDEX_FILE_NAME, DATA_FILE_NAME, HASHES_FILE_NAME]
+            .into_iter()
+            .all(|file_name| {
+                std::fs::OpenOptions::new()
+                    .append(true)
+                    .open(self.path_to_blockchain.join(file_name))
+                    .is_ok()
+            })
+    }
+
     /// Append `block_data` to this block store. First write
     /// the data to the data file and then create a new index
     /// for it in the index file.
@@ -1016,6 +1052,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn block_store_is_writable_once_files_are_created() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store = BlockStore::new(dir.path(), LockStatus::Unlocked);
+        assert!(!block_store.is_writable());
+
+        block_store.create_files_if_they_do_not_exist().unwrap();
+        assert!(block_store.is_writable());
+    }
+
     #[test]
     fn lock_and_unlock() {
         let dir = tempfile::tempdir().unwrap();
@@ -3,7 +3,8 @@
 use std::{sync::Arc, time::SystemTime};
 
 use eyre::{Result, WrapErr as _};
-use iroha_telemetry::metrics::Metrics;
+use iroha_p2p::{peer::ConnectionDirection, PeerInfo};
+use iroha_telemetry::metrics::{self, Metrics, PeerStatus, Uptime};
 use parking_lot::Mutex;
 use storage::storage::StorageReadOnly;
 
@@ -148,4 +149,35 @@ impl MetricsReporter {
     pub fn metrics(&self) -> &Metrics {
         &self.metrics
     }
+
+    /// Connection diagnostics of the currently connected peers.
+    pub async fn peers(&self) -> Vec<PeerStatus> {
+        self.network
+            .peers_info()
+            .await
+            .into_iter()
+            .map(
+                |PeerInfo {
+                     peer_id,
+                     direction,
+                     uptime,
+                     since_last_message,
+                     bytes_sent,
+                     bytes_received,
+                     dropped_messages,
+                 }| PeerStatus {
+                    id: peer_id,
+                    direction: match direction {
+                        ConnectionDirection::Incoming => metrics::ConnectionDirection::Incoming,
+                        ConnectionDirection::Outgoing => metrics::ConnectionDirection::Outgoing,
+                    },
+                    uptime: Uptime(uptime),
+                    since_last_message: since_last_message.map(Uptime),
+                    bytes_sent,
+                    bytes_received,
+                    dropped_messages,
+                },
+            )
+            .collect()
+    }
 }
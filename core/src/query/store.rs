@@ -248,7 +248,7 @@ impl LiveQueryStoreHandle {
 mod tests {
     use iroha_data_model::{
         metadata::MetadataValueBox,
-        query::{predicate::PredicateBox, FetchSize, Pagination, Sorting},
+        query::{predicate::PredicateBox, FetchSize, Pagination, Projection, Sorting},
     };
     use nonzero_ext::nonzero;
 
@@ -276,7 +276,14 @@ mod tests {
             let mut counter = 0;
 
             let query_output = query_output
-                .apply_postprocessing(&filter, &sorting, pagination, fetch_size)
+                .apply_postprocessing(
+                    &filter,
+                    None,
+                    &sorting,
+                    pagination,
+                    &Projection::default(),
+                    fetch_size,
+                )
                 .unwrap();
 
             let (batch, mut cursor) = query_store_handle
@@ -3,11 +3,11 @@ use core::time::Duration;
 use std::num::NonZeroUsize;
 
 use crossbeam_queue::ArrayQueue;
-use dashmap::{mapref::entry::Entry, DashMap};
+use dashmap::{mapref::entry::Entry, DashMap, DashSet};
 use eyre::Result;
 use indexmap::IndexSet;
 use iroha_config::parameters::actual::Queue as Config;
-use iroha_crypto::HashOf;
+use iroha_crypto::{Hash, HashOf};
 use iroha_data_model::{
     account::AccountId,
     events::pipeline::{TransactionEvent, TransactionStatus},
@@ -22,15 +22,40 @@ use crate::{prelude::*, EventsSender};
 
 impl AcceptedTransaction {
     // TODO: We should have another type of transaction like `CheckedTransaction` in the type system?
-    fn is_signatory_consistent(&self) -> bool {
+    fn is_signatory_consistent(&self, state_view: &StateView) -> bool {
         let authority = self.as_ref().authority();
-        let signatory = self.as_ref().signature().public_key();
-        authority.signatory_matches(signatory)
+        let mut signatories = self
+            .as_ref()
+            .signatures()
+            .iter()
+            .map(|signature| signature.public_key());
+
+        match state_view.world.account(authority) {
+            Ok(account) => {
+                signatories.all(|signatory| account.signatory_weight(signatory).is_some())
+            }
+            // Existence of the account is checked during validation
+            Err(_) => signatories.all(|signatory| authority.signatory_matches(signatory)),
+        }
+    }
+
+    /// Check if [`self`] has collected enough signatures to be put into a block.
+    fn is_quorum_reached(&self, state_view: &StateView) -> bool {
+        let signatories = self
+            .as_ref()
+            .signatures()
+            .iter()
+            .map(|signature| signature.public_key());
+
+        state_view
+            .world
+            .account(self.as_ref().authority())
+            .map_or(true, |account| account.is_quorum_reached(signatories))
     }
 
-    /// Check if [`self`] is committed or rejected.
+    /// Check if [`self`] is committed or rejected, possibly with other signatures.
     fn is_in_blockchain(&self, state_view: &StateView<'_>) -> bool {
-        state_view.has_transaction(self.as_ref().hash())
+        state_view.has_transaction_payload(self.as_ref().hash_of_payload())
     }
 }
 
@@ -44,6 +69,12 @@ pub struct Queue {
     tx_hashes: ArrayQueue<HashOf<SignedTransaction>>,
     /// [`AcceptedTransaction`]s addressed by `Hash`
     accepted_txs: DashMap<HashOf<SignedTransaction>, AcceptedTransaction>,
+    /// Hashes of payloads of [`AcceptedTransaction`]s, so that the same transaction
+    /// with another set of signatures isn't queued twice
+    accepted_payloads: DashSet<Hash>,
+    /// Multisig transactions which are waiting for co-signers, addressed by `Hash` of their payload.
+    /// They are kept by this peer only and aren't gossiped, so co-signers have to submit to the same peer.
+    multisig_txs: DashMap<Hash, AcceptedTransaction>,
     /// Amount of transactions per user in the queue
     txs_per_user: DashMap<AccountId, usize>,
     /// The maximum number of transactions in the queue
@@ -79,6 +110,8 @@ pub enum Error {
     IsInQueue,
     /// Signatories in signature and payload mismatch
     SignatoryInconsistent,
+    /// The multisig transaction waiting for co-signers has the same payload hash but a different payload
+    PayloadMismatch,
 }
 
 /// Failure that can pop up when pushing transaction into the queue
@@ -105,6 +138,8 @@ impl Queue {
             events_sender,
             tx_hashes: ArrayQueue::new(capacity.get()),
             accepted_txs: DashMap::new(),
+            accepted_payloads: DashSet::new(),
+            multisig_txs: DashMap::new(),
             txs_per_user: DashMap::new(),
             capacity,
             capacity_per_user,
@@ -175,15 +210,35 @@ impl Queue {
             Err(Error::Expired)
         } else if tx.is_in_blockchain(state_view) {
             Err(Error::InBlockchain)
-        } else if !tx.is_signatory_consistent() {
+        } else if !tx.is_signatory_consistent(state_view) {
             Err(Error::SignatoryInconsistent)
         } else {
             Ok(())
         }
     }
 
+    /// Returns multisig transactions of the given account which are waiting for co-signers.
+    pub fn multisig_transactions<'state>(
+        &'state self,
+        account_id: &'state AccountId,
+        state_view: &'state StateView,
+    ) -> impl Iterator<Item = AcceptedTransaction> + 'state {
+        self.multisig_txs.iter().filter_map(move |tx| {
+            if tx.value().as_ref().authority() == account_id
+                && self.is_pending(tx.value(), state_view)
+            {
+                return Some(tx.value().clone());
+            }
+
+            None
+        })
+    }
+
     /// Push transaction into queue.
     ///
+    /// Multisig transactions which haven't reached the quorum of their authority yet
+    /// are kept aside until co-signers push the same payload with their signatures.
+    ///
     /// # Errors
     /// See [`enum@Error`]
     pub fn push(&self, tx: AcceptedTransaction, state_view: &StateView) -> Result<(), Failure> {
@@ -192,6 +247,25 @@ impl Queue {
             return Err(Failure { tx, err });
         }
 
+        let Some(tx) = self.collect_signatures(tx, state_view)? else {
+            return Ok(());
+        };
+
+        let payload_hash = tx.as_ref().hash_of_payload();
+        if !self.accepted_payloads.insert(payload_hash) {
+            return Err(Failure {
+                tx,
+                err: Error::IsInQueue,
+            });
+        }
+        self.push_accepted(tx).map_err(|failure| {
+            self.accepted_payloads.remove(&payload_hash);
+            failure
+        })
+    }
+
+    /// Put the transaction which has collected enough signatures into the queue
+    fn push_accepted(&self, tx: AcceptedTransaction) -> Result<(), Failure> {
         // Get `txs_len` before entry to avoid deadlock
         let txs_len = self.accepted_txs.len();
         let hash = tx.as_ref().hash();
@@ -246,6 +320,87 @@ impl Queue {
         Ok(())
     }
 
+    /// Merge signatures of the transaction with the signatures collected for the same payload so far.
+    ///
+    /// Returns the transaction once the quorum of its authority is reached,
+    /// otherwise the transaction is kept in the multisig store and `None` is returned.
+    fn collect_signatures(
+        &self,
+        tx: AcceptedTransaction,
+        state_view: &StateView,
+    ) -> Result<Option<AcceptedTransaction>, Failure> {
+        // Get `txs_len` and `user_txs_len` before entry to avoid deadlock
+        let txs_len = self.multisig_txs.len();
+        let user_txs_len = self
+            .multisig_txs
+            .iter()
+            .filter(|pending| pending.value().as_ref().authority() == tx.as_ref().authority())
+            .count();
+        match self.multisig_txs.entry(tx.as_ref().hash_of_payload()) {
+            Entry::Occupied(mut entry) => {
+                if !entry.get_mut().0.merge_signatures(tx.as_ref()) {
+                    return Err(Failure {
+                        tx,
+                        err: Error::PayloadMismatch,
+                    });
+                }
+
+                if !entry.get().is_quorum_reached(state_view) {
+                    trace!("Multisig transaction is waiting for more signatures");
+                    return Ok(None);
+                }
+
+                Ok(Some(entry.remove()))
+            }
+            Entry::Vacant(entry) => {
+                if tx.is_quorum_reached(state_view) {
+                    return Ok(Some(tx));
+                }
+
+                if txs_len >= self.capacity.get() {
+                    warn!(
+                        max = self.capacity,
+                        "Achieved maximum amount of multisig transactions"
+                    );
+                    return Err(Failure {
+                        tx,
+                        err: Error::Full,
+                    });
+                }
+                if user_txs_len >= self.capacity_per_user.get() {
+                    warn!(
+                        max_txs_per_user = self.capacity_per_user,
+                        "Account reached maximum amount of multisig transactions"
+                    );
+                    return Err(Failure {
+                        tx,
+                        err: Error::MaximumTransactionsPerUser,
+                    });
+                }
+
+                trace!("Multisig transaction is waiting for more signatures");
+                entry.insert(tx);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Remove multisig transactions which expired before collecting enough signatures.
+    fn remove_expired_multisig_transactions(&self) -> Vec<AcceptedTransaction> {
+        let mut expired_transactions = Vec::new();
+
+        self.multisig_txs.retain(|_, tx| {
+            if self.is_expired(tx) {
+                expired_transactions.push(tx.clone());
+                return false;
+            }
+
+            true
+        });
+
+        expired_transactions
+    }
+
     /// Pop single transaction from the queue. Removes all transactions that fail the `tx_check`.
     fn pop_from_queue(
         &self,
@@ -270,6 +425,8 @@ impl Queue {
             let tx = entry.get();
             if let Err(e) = self.check_tx(tx, state_view) {
                 let (_, tx) = entry.remove_entry();
+                self.accepted_payloads
+                    .remove(&tx.as_ref().hash_of_payload());
                 self.decrease_per_user_tx_count(tx.as_ref().authority());
                 if let Error::Expired = e {
                     expired_transactions.push(tx);
@@ -282,6 +439,23 @@ impl Queue {
         }
     }
 
+    /// Returns the status of the transaction with the given `hash` if it's in the queue.
+    ///
+    /// Transactions which expired but haven't been removed from the queue yet
+    /// are reported as [`TransactionStatus::Expired`].
+    pub fn transaction_status(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Option<TransactionStatus> {
+        self.accepted_txs.get(hash).map(|tx| {
+            if self.is_expired(tx.value()) {
+                TransactionStatus::Expired
+            } else {
+                TransactionStatus::Queued
+            }
+        })
+    }
+
     /// Return the number of transactions in the queue.
     pub fn tx_len(&self) -> usize {
         self.accepted_txs.len()
@@ -315,16 +489,18 @@ impl Queue {
         }
 
         let mut seen_queue = Vec::new();
-        let mut expired_transactions = Vec::new();
+        let mut expired_transactions = self.remove_expired_multisig_transactions();
 
         let txs_from_queue = core::iter::from_fn(|| {
             self.pop_from_queue(&mut seen_queue, state_view, &mut expired_transactions)
         });
 
-        let transactions_hashes: IndexSet<HashOf<SignedTransaction>> =
-            transactions.iter().map(|tx| tx.as_ref().hash()).collect();
+        let payload_hashes: IndexSet<Hash> = transactions
+            .iter()
+            .map(|tx| tx.as_ref().hash_of_payload())
+            .collect();
         let txs = txs_from_queue
-            .filter(|tx| !transactions_hashes.contains(&tx.as_ref().hash()))
+            .filter(|tx| !payload_hashes.contains(&tx.as_ref().hash_of_payload()))
             .take(max_txs_in_block - transactions.len());
         transactions.extend(txs);
 
@@ -408,6 +584,8 @@ pub mod tests {
                 events_sender: tokio::sync::broadcast::Sender::new(1),
                 tx_hashes: ArrayQueue::new(cfg.capacity.get()),
                 accepted_txs: DashMap::new(),
+                accepted_payloads: DashSet::new(),
+                multisig_txs: DashMap::new(),
                 txs_per_user: DashMap::new(),
                 capacity: cfg.capacity,
                 capacity_per_user: cfg.capacity_per_user,
@@ -549,7 +727,9 @@ pub mod tests {
         let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
         let tx = accepted_tx_by_someone(&time_source);
         let mut state_block = state.block();
-        state_block.transactions.insert(tx.as_ref().hash(), 1);
+        state_block
+            .transaction_payloads
+            .insert(tx.as_ref().hash_of_payload(), 1);
         state_block.commit();
         let state_view = state.view();
         let queue = Queue::test(config_factory(), &time_source);
@@ -574,7 +754,9 @@ pub mod tests {
         let queue = Queue::test(config_factory(), &time_source);
         queue.push(tx.clone(), &state.view()).unwrap();
         let mut state_block = state.block();
-        state_block.transactions.insert(tx.as_ref().hash(), 1);
+        state_block
+            .transaction_payloads
+            .insert(tx.as_ref().hash_of_payload(), 1);
         state_block.commit();
         assert_eq!(
             queue
@@ -663,6 +845,41 @@ pub mod tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    async fn transaction_status() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+        let state_view = state.view();
+
+        let (time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(
+            Config {
+                transaction_time_to_live: Duration::from_millis(200),
+                ..config_factory()
+            },
+            &time_source,
+        );
+        let tx = accepted_tx_by_someone(&time_source);
+        let tx_hash = tx.as_ref().hash();
+        assert_eq!(queue.transaction_status(&tx_hash), None);
+
+        queue
+            .push(tx, &state_view)
+            .expect("Failed to push tx into queue");
+        assert_eq!(
+            queue.transaction_status(&tx_hash),
+            Some(TransactionStatus::Queued)
+        );
+
+        time_handle.advance(Duration::from_millis(201));
+        assert_eq!(
+            queue.transaction_status(&tx_hash),
+            Some(TransactionStatus::Expired)
+        );
+    }
+
     #[test]
     async fn custom_expired_transaction_is_rejected() {
         const TTL_MS: u64 = 200;
@@ -777,7 +994,9 @@ pub mod tests {
                     for tx in queue.collect_transactions_for_block(&state.view(), max_txs_in_block)
                     {
                         let mut state_block = state.block();
-                        state_block.transactions.insert(tx.as_ref().hash(), 1);
+                        state_block
+                            .transaction_payloads
+                            .insert(tx.as_ref().hash_of_payload(), 1);
                         state_block.commit();
                     }
                     // Simulate random small delays
@@ -800,6 +1019,197 @@ pub mod tests {
         }
     }
 
+    /// World with the account controlled by both key pairs with the quorum of 2
+    fn multisig_world(
+        account_id: &AccountId,
+        key_pair: &KeyPair,
+        co_signers: &[&KeyPair],
+    ) -> World {
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(account_id);
+        let signatory_policy = SignatoryPolicy::new(
+            core::iter::once(key_pair)
+                .chain(co_signers.iter().copied())
+                .map(|key_pair| (key_pair.public_key().clone(), 1)),
+            2,
+        );
+        let account = Account::new(account_id.clone())
+            .with_signatory_policy(signatory_policy)
+            .build(account_id);
+        assert!(domain.add_account(account).is_none());
+        World::with([domain], PeersIds::new())
+    }
+
+    #[test]
+    async fn multisig_tx_waits_for_quorum() {
+        let (account_id, key_pair) = gen_account_in("wonderland");
+        let co_signer = KeyPair::random();
+        let world = multisig_world(&account_id, &key_pair, &[&co_signer]);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world, kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let chain_id = ChainId::from("0");
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        let tx = TransactionBuilder::new_with_time_source(
+            chain_id.clone(),
+            account_id.clone(),
+            &time_source,
+        )
+        .with_instructions([Fail::new("multisig".to_owned())]);
+        let signed_by = |key_pair: &KeyPair| {
+            AcceptedTransaction::accept(tx.clone().sign(key_pair), &chain_id, &limits)
+                .expect("Failed to accept Transaction.")
+        };
+
+        queue
+            .push(signed_by(&key_pair), &state_view)
+            .expect("Failed to push tx into queue");
+        assert!(queue
+            .collect_transactions_for_block(&state_view, 10)
+            .is_empty());
+        assert_eq!(
+            queue
+                .multisig_transactions(&account_id, &state_view)
+                .count(),
+            1
+        );
+
+        queue
+            .push(signed_by(&co_signer), &state_view)
+            .expect("Failed to push tx into queue");
+        let available = queue.collect_transactions_for_block(&state_view, 10);
+        assert_eq!(available.len(), 1);
+        assert_eq!(available[0].as_ref().signatures().len(), 2);
+        assert_eq!(
+            queue
+                .multisig_transactions(&account_id, &state_view)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    async fn committed_multisig_tx_with_extra_signature_is_rejected() {
+        let (account_id, key_pair) = gen_account_in("wonderland");
+        let (co_signer, extra_co_signer) = (KeyPair::random(), KeyPair::random());
+        let world = multisig_world(&account_id, &key_pair, &[&co_signer, &extra_co_signer]);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let chain_id = ChainId::from("0");
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        let committed_tx = TransactionBuilder::new_with_time_source(
+            chain_id.clone(),
+            account_id.clone(),
+            &time_source,
+        )
+        .with_instructions([Fail::new("multisig".to_owned())])
+        .sign(&key_pair)
+        .sign(&co_signer);
+        let mut state_block = state.block();
+        state_block.transactions.insert(committed_tx.hash(), 1);
+        state_block
+            .transaction_payloads
+            .insert(committed_tx.hash_of_payload(), 1);
+        state_block.commit();
+
+        let resubmitted_tx = committed_tx.clone().sign(&extra_co_signer);
+        assert_ne!(resubmitted_tx.hash(), committed_tx.hash());
+        let resubmitted_tx = AcceptedTransaction::accept(resubmitted_tx, &chain_id, &limits)
+            .expect("Failed to accept Transaction.");
+        assert!(matches!(
+            queue.push(resubmitted_tx, &state.view()),
+            Err(Failure {
+                err: Error::InBlockchain,
+                ..
+            })
+        ));
+        assert_eq!(queue.accepted_txs.len(), 0);
+    }
+
+    #[test]
+    async fn multisig_txs_are_limited_per_user() {
+        let (account_id, key_pair) = gen_account_in("wonderland");
+        let world = multisig_world(&account_id, &key_pair, &[&KeyPair::random()]);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world, kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Queue::test(
+            Config {
+                capacity_per_user: 1.try_into().unwrap(),
+                ..config_factory()
+            },
+            &time_source,
+        );
+
+        let chain_id = ChainId::from("0");
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        let signed_tx = |message: &str| {
+            let tx = TransactionBuilder::new_with_time_source(
+                chain_id.clone(),
+                account_id.clone(),
+                &time_source,
+            )
+            .with_instructions([Fail::new(message.to_owned())])
+            .sign(&key_pair);
+            AcceptedTransaction::accept(tx, &chain_id, &limits)
+                .expect("Failed to accept Transaction.")
+        };
+
+        queue
+            .push(signed_tx("first"), &state_view)
+            .expect("Failed to push tx into queue");
+        assert!(matches!(
+            queue.push(signed_tx("second"), &state_view),
+            Err(Failure {
+                err: Error::MaximumTransactionsPerUser,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    async fn push_tx_signed_by_non_signatory() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let (account_id, _key_pair) = gen_account_in("wonderland");
+        let tx = accepted_tx_by(account_id, &KeyPair::random(), &time_source);
+        assert!(matches!(
+            queue.push(tx, &state_view),
+            Err(Failure {
+                err: Error::SignatoryInconsistent,
+                ..
+            })
+        ));
+    }
+
     #[test]
     async fn push_tx_in_future() {
         let future_threshold = Duration::from_secs(1);
@@ -903,8 +1313,8 @@ pub mod tests {
         for transaction in transactions {
             // Put transaction hashes into state as if they were in the blockchain
             state_block
-                .transactions
-                .insert(transaction.as_ref().hash(), 1);
+                .transaction_payloads
+                .insert(transaction.as_ref().hash_of_payload(), 1);
         }
         state_block.commit();
         // Cleanup transactions
@@ -18,8 +18,7 @@ impl Registrable for iroha_data_model::account::NewAccount {
 }
 
 /// All instructions related to accounts:
-/// - minting/burning public key into account signatories
-/// - minting/burning signature condition check
+/// - rotating account signatories
 /// - update metadata
 /// - grant permissions and roles
 /// - Revoke permissions or roles
@@ -236,6 +235,35 @@ pub mod isi {
         }
     }
 
+    impl Execute for RotateSignatory {
+        #[metrics(+"rotate_signatory")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            if !self.signatory_policy.is_reachable() {
+                return Err(Error::InvariantViolation(
+                    "Quorum of the account should be non-zero and reachable by its signatories"
+                        .to_owned(),
+                ));
+            }
+
+            state_transaction
+                .world
+                .account_mut(&account_id)?
+                .signatory_policy = Some(self.signatory_policy);
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SignatoryRotated(account_id)));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Grant<Permission, Account> {
         #[metrics(+"grant_account_permission")]
         fn execute(
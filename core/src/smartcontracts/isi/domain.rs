@@ -56,6 +56,15 @@ pub mod isi {
                 ));
             }
 
+            if let Some(signatory_policy) = account.signatory_policy() {
+                if !signatory_policy.is_reachable() {
+                    return Err(InstructionExecutionError::InvariantViolation(
+                        "Quorum of the account should be non-zero and reachable by its signatories"
+                            .to_owned(),
+                    ));
+                }
+            }
+
             let domain = state_transaction.world.domain_mut(&account_id.domain_id)?;
             if domain.accounts.contains_key(&account_id) {
                 return Err(RepetitionError {
@@ -58,6 +58,7 @@ impl Execute for InstructionBox {
             Self::NewParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
             Self::Log(isi) => isi.execute(authority, state_transaction),
+            Self::RotateSignatory(isi) => isi.execute(authority, state_transaction),
         }
     }
 }
@@ -240,6 +241,7 @@ mod tests {
     use core::str::FromStr as _;
     use std::sync::Arc;
 
+    use iroha_crypto::KeyPair;
     use iroha_data_model::metadata::MetadataValueBox;
     use test_samples::{
         gen_account_in, ALICE_ID, SAMPLE_GENESIS_ACCOUNT_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
@@ -398,6 +400,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn rotate_signatory_keeps_account_id() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let new_key = KeyPair::random().into_parts().0;
+
+        RotateSignatory::single(account_id.clone(), new_key.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        let account = state_transaction.world.account(&account_id)?;
+        assert_eq!(account.signatory_weight(&new_key), Some(1));
+        assert_eq!(account.signatory_weight(account_id.signatory()), None);
+
+        let unreachable = SignatoryPolicy::new([(new_key, 1)], 2);
+        assert!(matches!(
+            RotateSignatory::new(account_id.clone(), unreachable)
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        Ok(())
+    }
+
     #[test]
     async fn executing_unregistered_trigger_should_return_error() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
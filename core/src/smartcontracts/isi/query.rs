@@ -1,13 +1,17 @@
 //! Query functionality. The common error type is also defined here,
 //! alongside functions for converting them into HTTP responses.
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::BTreeMap};
 
 use eyre::Result;
 use iroha_data_model::{
+    metadata::MetadataValueBox,
     prelude::*,
     query::{
-        error::QueryExecutionFail as Error, predicate::PredicateBox, Pagination, QueryOutputBox,
-        Sorting,
+        aggregation::{AggregateFunction, AggregateGroup, Aggregation, GroupBy},
+        error::QueryExecutionFail as Error,
+        predicate::PredicateBox,
+        sorting::{SortField, SortKey, SortOrder},
+        Pagination, Projection, QueryOutputBox, Sorting,
     },
 };
 use parity_scale_codec::{Decode, Encode};
@@ -38,22 +42,28 @@ pub enum LazyQueryOutput<'a> {
 impl LazyQueryOutput<'_> {
     /// If the underlying output is an iterator, apply all the query postprocessing:
     /// - filtering
+    /// - aggregation
     /// - sorting
     /// - pagination
+    /// - projection
     /// - batching
     pub fn apply_postprocessing(
         self,
         filter: &PredicateBox,
+        aggregation: Option<&Aggregation>,
         sorting: &Sorting,
         pagination: Pagination,
+        projection: &Projection,
         fetch_size: FetchSize,
     ) -> Result<ProcessedQueryOutput, Error> {
         match self {
             // nothing applies to the singular results
             LazyQueryOutput::QueryOutput(output) => {
                 if filter != &PredicateBox::default()
+                    || aggregation.is_some()
                     || sorting != &Sorting::default()
                     || pagination != Pagination::default()
+                    || projection != &Projection::default()
                     || fetch_size != FetchSize::default()
                 {
                     return Err(Error::InvalidSingularParameters);
@@ -65,51 +75,51 @@ impl LazyQueryOutput<'_> {
                 // filter the results
                 let iter = iter.filter(move |v| filter.applies(v));
 
-                // sort & paginate
-                let output = match &sorting.sort_by_metadata_key {
-                    Some(key) => {
-                        // if sorting was requested, we need to retrieve all the results first
-                        let mut pairs: Vec<(Option<QueryOutputBox>, QueryOutputBox)> = iter
-                            .map(|value| {
-                                let key = match &value {
-                                    QueryOutputBox::Identifiable(IdentifiableBox::Asset(asset)) => {
-                                        match asset.value() {
-                                            AssetValue::Store(store) => {
-                                                store.get(key).cloned().map(Into::into)
-                                            }
-                                            _ => None,
-                                        }
-                                    }
-                                    QueryOutputBox::Identifiable(v) => {
-                                        TryInto::<&dyn HasMetadata>::try_into(v)
-                                            .ok()
-                                            .and_then(|has_metadata| {
-                                                has_metadata.metadata().get(key)
-                                            })
-                                            .cloned()
-                                            .map(Into::into)
-                                    }
-                                    _ => None,
-                                };
-                                (key, value)
-                            })
-                            .collect();
-                        pairs.sort_by(|(left_key, _), (right_key, _)| {
-                            match (left_key, right_key) {
-                                (Some(l), Some(r)) => l.cmp(r),
-                                (Some(_), None) => Ordering::Less,
-                                (None, Some(_)) => Ordering::Greater,
-                                (None, None) => Ordering::Equal,
-                            }
-                        });
-                        pairs
+                // replace the results with the aggregated values, if requested
+                let iter: Box<dyn Iterator<Item = QueryOutputBox> + '_> = match aggregation {
+                    Some(aggregation) => Box::new(
+                        aggregate(aggregation, iter)?
                             .into_iter()
-                            .map(|(_, val)| val)
-                            .paginate(pagination)
-                            .collect::<Vec<_>>()
-                    }
+                            .map(QueryOutputBox::from),
+                    ),
+                    None => Box::new(iter),
+                };
+
+                // sort & paginate
+                let output = if sorting.keys.is_empty() {
                     // no sorting, can just paginate the results without constructing the full output vec
-                    None => iter.paginate(pagination).collect::<Vec<_>>(),
+                    iter.paginate(pagination).collect::<Vec<_>>()
+                } else {
+                    // if sorting was requested, we need to retrieve all the results first
+                    let mut pairs: Vec<(Vec<Option<QueryOutputBox>>, QueryOutputBox)> = iter
+                        .map(|value| {
+                            let keys = sorting
+                                .keys
+                                .iter()
+                                .map(|key| sort_value(&key.field, &value))
+                                .collect();
+                            (keys, value)
+                        })
+                        .collect();
+                    // NOTE: sorting must be stable so that the order of the results
+                    // which are equal by all the keys is the same for every query
+                    pairs.sort_by(|(left_keys, _), (right_keys, _)| {
+                        compare_sort_values(&sorting.keys, left_keys, right_keys)
+                    });
+                    pairs
+                        .into_iter()
+                        .map(|(_, val)| val)
+                        .paginate(pagination)
+                        .collect::<Vec<_>>()
+                };
+
+                // leave only the requested fields of the results
+                let output = match projection {
+                    Projection::Full => output,
+                    projection => output
+                        .into_iter()
+                        .map(|value| project(projection, value))
+                        .collect(),
                 };
 
                 let fetch_size = fetch_size
@@ -126,6 +136,246 @@ impl LazyQueryOutput<'_> {
     }
 }
 
+/// Leave only the fields of `value` selected by `projection`
+fn project(projection: &Projection, value: QueryOutputBox) -> QueryOutputBox {
+    let QueryOutputBox::Identifiable(entity) = value else {
+        return value;
+    };
+
+    match projection {
+        Projection::Full => QueryOutputBox::Identifiable(entity),
+        Projection::Id => QueryOutputBox::Id(entity.id_box()),
+        Projection::Summary(metadata_keys) => {
+            QueryOutputBox::Identifiable(summarize(metadata_keys, entity))
+        }
+    }
+}
+
+/// Drop the nested collections of `entity` and the entries of its metadata not under `metadata_keys`
+fn summarize(metadata_keys: &[Name], mut entity: IdentifiableBox) -> IdentifiableBox {
+    match &mut entity {
+        IdentifiableBox::Domain(domain) => {
+            domain.accounts.clear();
+            domain.asset_definitions.clear();
+            domain.asset_total_quantities.clear();
+            domain.metadata.retain_keys(metadata_keys);
+        }
+        IdentifiableBox::Account(account) => {
+            account.assets.clear();
+            account.metadata.retain_keys(metadata_keys);
+        }
+        IdentifiableBox::AssetDefinition(definition) => {
+            definition.metadata.retain_keys(metadata_keys);
+        }
+        IdentifiableBox::Asset(asset) => {
+            if let AssetValue::Store(store) = &mut asset.value {
+                store.retain_keys(metadata_keys);
+            }
+        }
+        IdentifiableBox::Trigger(trigger) => trigger.action.metadata.retain_keys(metadata_keys),
+        _ => {}
+    }
+
+    entity
+}
+
+/// Compute `aggregation` over `values`, one [`AggregateGroup`] per group ordered by the group id
+fn aggregate(
+    aggregation: &Aggregation,
+    values: impl Iterator<Item = QueryOutputBox>,
+) -> Result<Vec<AggregateGroup>, Error> {
+    // aggregated value of each group along with the definition of the assets aggregated in it
+    let mut groups = BTreeMap::new();
+    if aggregation.group_by.is_none() {
+        // without grouping the result is returned even if there are no values
+        groups.insert(None, (initial_aggregate_value(aggregation.function), None));
+    }
+
+    for value in values {
+        let group = match aggregation.group_by {
+            Some(group_by) => match group_id(group_by, &value) {
+                Some(group) => Some(group),
+                None => continue,
+            },
+            None => None,
+        };
+        let (aggregated, asset_definition) = groups
+            .entry(group)
+            .or_insert_with(|| (initial_aggregate_value(aggregation.function), None));
+
+        let numeric = numeric_value(&value);
+        if aggregation.function != AggregateFunction::Count && numeric.is_some() {
+            if let QueryOutputBox::Identifiable(IdentifiableBox::Asset(asset)) = &value {
+                let definition_id = &asset.id().definition_id;
+                match asset_definition {
+                    Some(asset_definition) if asset_definition != definition_id => {
+                        return Err(Error::AggregationOfDifferentAssets)
+                    }
+                    Some(_) => {}
+                    None => *asset_definition = Some(definition_id.clone()),
+                }
+            }
+        }
+
+        *aggregated = match (aggregation.function, numeric) {
+            (AggregateFunction::Count, _) => aggregated
+                .unwrap_or(Numeric::ZERO)
+                .checked_add(Numeric::ONE)
+                .map(Some)
+                .ok_or(Error::AggregationOverflow)?,
+            (AggregateFunction::Sum, Some(value)) => aggregated
+                .unwrap_or(Numeric::ZERO)
+                .checked_add(value)
+                .map(Some)
+                .ok_or(Error::AggregationOverflow)?,
+            (AggregateFunction::Min, Some(value)) => {
+                Some(aggregated.map_or(value, |aggregated| aggregated.min(value)))
+            }
+            (AggregateFunction::Max, Some(value)) => {
+                Some(aggregated.map_or(value, |aggregated| aggregated.max(value)))
+            }
+            // non-numeric values don't contribute to sum, min or max
+            (_, None) => *aggregated,
+        };
+    }
+
+    Ok(groups
+        .into_iter()
+        .map(|(group, (value, _))| AggregateGroup { group, value })
+        .collect())
+}
+
+/// Value of an empty group of `function`
+fn initial_aggregate_value(function: AggregateFunction) -> Option<Numeric> {
+    match function {
+        AggregateFunction::Count | AggregateFunction::Sum => Some(Numeric::ZERO),
+        AggregateFunction::Min | AggregateFunction::Max => None,
+    }
+}
+
+/// Extract the id of the group `value` belongs to when grouping by `group_by`
+fn group_id(group_by: GroupBy, value: &QueryOutputBox) -> Option<IdBox> {
+    let id = match (group_by, value) {
+        (GroupBy::Domain, QueryOutputBox::Identifiable(v)) => match v {
+            IdentifiableBox::Domain(domain) => domain.id().clone().into(),
+            IdentifiableBox::Account(account) => account.id().domain_id().clone().into(),
+            IdentifiableBox::AssetDefinition(definition) => {
+                definition.id().domain_id().clone().into()
+            }
+            IdentifiableBox::Asset(asset) => asset.id().account_id().domain_id().clone().into(),
+            IdentifiableBox::Trigger(trigger) => trigger.id().domain_id().clone()?.into(),
+            _ => return None,
+        },
+        (GroupBy::AssetDefinition, QueryOutputBox::Identifiable(v)) => match v {
+            IdentifiableBox::AssetDefinition(definition) => definition.id().clone().into(),
+            IdentifiableBox::Asset(asset) => asset.id().definition_id().clone().into(),
+            _ => return None,
+        },
+        (GroupBy::Account, QueryOutputBox::Identifiable(v)) => match v {
+            IdentifiableBox::Account(account) => account.id().clone().into(),
+            IdentifiableBox::Asset(asset) => asset.id().account_id().clone().into(),
+            _ => return None,
+        },
+        (GroupBy::Account, QueryOutputBox::Transaction(tx)) => {
+            tx.transaction.as_ref().authority().clone().into()
+        }
+        _ => return None,
+    };
+
+    Some(id)
+}
+
+/// Extract the numeric value of `value` to compute sum, min or max of
+fn numeric_value(value: &QueryOutputBox) -> Option<Numeric> {
+    match value {
+        QueryOutputBox::Numeric(numeric) => Some(*numeric),
+        QueryOutputBox::Identifiable(IdentifiableBox::Asset(asset)) => match asset.value() {
+            AssetValue::Numeric(quantity) => Some(*quantity),
+            AssetValue::Store(_) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Extract the value of `field` from `value` to sort query results by
+fn sort_value(field: &SortField, value: &QueryOutputBox) -> Option<QueryOutputBox> {
+    match (field, value) {
+        (SortField::Id, QueryOutputBox::Id(id)) => Some(id.clone().into()),
+        (SortField::Id, QueryOutputBox::Identifiable(v)) => Some(v.id_box().into()),
+        (SortField::Id, QueryOutputBox::AggregateGroup(group)) => {
+            group.group.clone().map(Into::into)
+        }
+        (SortField::AssetQuantity, QueryOutputBox::Identifiable(IdentifiableBox::Asset(asset))) => {
+            match asset.value() {
+                AssetValue::Numeric(quantity) => Some((*quantity).into()),
+                AssetValue::Store(_) => None,
+            }
+        }
+        (SortField::BlockHeight, QueryOutputBox::Transaction(tx)) => {
+            Some(Numeric::from(tx.block_height).into())
+        }
+        (SortField::BlockHeight, QueryOutputBox::Block(block)) => {
+            Some(Numeric::from(block.header().height()).into())
+        }
+        (SortField::BlockHeight, QueryOutputBox::BlockHeader(header)) => {
+            Some(Numeric::from(header.height()).into())
+        }
+        (SortField::Timestamp, QueryOutputBox::Transaction(tx)) => {
+            let creation_time_ms = tx.transaction.as_ref().creation_time().as_millis();
+            u64::try_from(creation_time_ms)
+                .ok()
+                .map(|creation_time_ms| Numeric::from(creation_time_ms).into())
+        }
+        (SortField::Timestamp, QueryOutputBox::Block(block)) => {
+            Some(Numeric::from(block.header().timestamp_ms).into())
+        }
+        (SortField::Timestamp, QueryOutputBox::BlockHeader(header)) => {
+            Some(Numeric::from(header.timestamp_ms).into())
+        }
+        (SortField::Metadata(path), QueryOutputBox::Identifiable(v)) => {
+            let (key, path) = path.split_first()?;
+            let value = match v {
+                IdentifiableBox::Asset(asset) => match asset.value() {
+                    AssetValue::Store(store) => store.get(key),
+                    AssetValue::Numeric(_) => None,
+                },
+                v => TryInto::<&dyn HasMetadata>::try_into(v)
+                    .ok()
+                    .and_then(|has_metadata| has_metadata.metadata().get(key)),
+            };
+            path.iter()
+                .try_fold(value?, |value, key| match value {
+                    MetadataValueBox::LimitedMetadata(metadata) => metadata.get(key),
+                    _ => None,
+                })
+                .cloned()
+                .map(Into::into)
+        }
+        _ => None,
+    }
+}
+
+/// Compare values extracted with [`sort_value`] for each of the sorting `keys`
+fn compare_sort_values(
+    keys: &[SortKey],
+    left: &[Option<QueryOutputBox>],
+    right: &[Option<QueryOutputBox>],
+) -> Ordering {
+    keys.iter()
+        .zip(left.iter().zip(right))
+        .map(|(key, values)| match values {
+            (Some(l), Some(r)) => match key.order {
+                SortOrder::Ascending => l.cmp(r),
+                SortOrder::Descending => r.cmp(l),
+            },
+            // values without the field go last regardless of the order
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        })
+        .fold(Ordering::Equal, Ordering::then)
+}
+
 /// An evaluated & post-processed query output that is ready to be sent to the live query store
 ///
 /// It has all the parameters (filtering, sorting, pagination and batching) applied already
@@ -162,6 +412,7 @@ impl_lazy! {
     iroha_data_model::block::BlockHeader,
     iroha_data_model::metadata::MetadataValueBox,
     iroha_data_model::query::TransactionQueryOutput,
+    iroha_data_model::query::TransactionProof,
     iroha_data_model::executor::ExecutorDataModel,
     iroha_data_model::trigger::Trigger,
 }
@@ -182,10 +433,14 @@ impl ValidQueryRequest {
         query: SignedQuery,
         state_ro: &impl StateReadOnly,
     ) -> Result<Self, ValidationFail> {
-        if !query
-            .authority()
-            .signatory_matches(query.signature().public_key())
-        {
+        let authority = query.authority();
+        let signatory = query.signature().public_key();
+        let is_signatory = state_ro.world().account(authority).map_or_else(
+            // Account might not exist, fall back to the signatory from its id
+            |_| authority.signatory_matches(signatory),
+            |account| account.signatory_weight(signatory).is_some(),
+        );
+        if !is_signatory {
             return Err(Error::Signature(String::from(
                 "Signature public key doesn't correspond to the account.",
             ))
@@ -211,8 +466,10 @@ impl ValidQueryRequest {
 
         query.query().execute(state_ro)?.apply_postprocessing(
             query.filter(),
+            query.aggregation(),
             query.sorting(),
             query.pagination(),
+            query.projection(),
             query.fetch_size(),
         )
 
@@ -252,6 +509,7 @@ impl ValidQuery for QueryBox {
                 FindDomainById,
                 FindBlockHeaderByHash,
                 FindTransactionByHash,
+                FindTransactionProofByHash,
                 FindTriggerById,
                 FindRoleByRoleId,
                 FindDomainKeyValueByIdAndKey,
@@ -297,7 +555,7 @@ mod tests {
     use iroha_data_model::{
         metadata::MetadataValueBox, query::error::FindError, transaction::TransactionLimits,
     };
-    use iroha_primitives::unique_vec::UniqueVec;
+    use iroha_primitives::{numeric::numeric, unique_vec::UniqueVec};
     use test_samples::{gen_account_in, ALICE_ID, ALICE_KEYPAIR};
     use tokio::test;
 
@@ -600,6 +858,10 @@ mod tests {
                 found_accepted.as_ref().as_ref().hash()
             )
         }
+
+        let proof = FindTransactionProofByHash::new(va_tx.as_ref().hash()).execute(&state_view)?;
+        assert_eq!(proof.block_hash, found_accepted.block_hash);
+        assert!(proof.verify());
         Ok(())
     }
 
@@ -637,4 +899,137 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    async fn aggregate_assets() -> Result<()> {
+        let rose_id = AssetDefinitionId::from_str("rose#wonderland")?;
+        let tulip_id = AssetDefinitionId::from_str("tulip#wonderland")?;
+        let (bob_id, _) = gen_account_in("garden");
+        let assets = [
+            Asset::new(AssetId::new(rose_id.clone(), ALICE_ID.clone()), numeric!(3)),
+            Asset::new(
+                AssetId::new(tulip_id.clone(), ALICE_ID.clone()),
+                numeric!(5.5),
+            ),
+            Asset::new(AssetId::new(rose_id.clone(), bob_id.clone()), numeric!(7)),
+            Asset::new(
+                AssetId::new(
+                    AssetDefinitionId::from_str("letter#wonderland")?,
+                    bob_id.clone(),
+                ),
+                AssetValue::Store(Metadata::new()),
+            ),
+        ];
+        let aggregate_assets = |aggregation: Aggregation| {
+            aggregate(
+                &aggregation,
+                assets.iter().cloned().map(QueryOutputBox::from),
+            )
+        };
+
+        let group = |group: Option<IdBox>, value| AggregateGroup { group, value };
+        assert_eq!(
+            aggregate_assets(Aggregation::count())?,
+            [group(None, Some(numeric!(4)))]
+        );
+        assert_eq!(
+            aggregate_assets(Aggregation::sum().group_by(GroupBy::AssetDefinition))?,
+            [
+                group(
+                    Some(AssetDefinitionId::from_str("letter#wonderland")?.into()),
+                    Some(numeric!(0))
+                ),
+                group(Some(rose_id.clone().into()), Some(numeric!(10))),
+                group(Some(tulip_id.into()), Some(numeric!(5.5))),
+            ]
+        );
+
+        let by_account = aggregate_assets(Aggregation::count().group_by(GroupBy::Account))?;
+        assert_eq!(by_account.len(), 2);
+        for account_id in [ALICE_ID.clone(), bob_id.clone()] {
+            assert!(by_account.contains(&group(Some(account_id.into()), Some(numeric!(2)))));
+        }
+
+        let roses = assets
+            .iter()
+            .filter(|asset| asset.id().definition_id == rose_id)
+            .cloned()
+            .map(QueryOutputBox::from);
+        assert_eq!(
+            aggregate(&Aggregation::min().group_by(GroupBy::Domain), roses)?,
+            [
+                group(
+                    Some(DomainId::from_str("garden")?.into()),
+                    Some(numeric!(7))
+                ),
+                group(
+                    Some(DomainId::from_str("wonderland")?.into()),
+                    Some(numeric!(3))
+                ),
+            ]
+        );
+
+        for aggregation in [
+            Aggregation::sum(),
+            Aggregation::max().group_by(GroupBy::Account),
+            Aggregation::min().group_by(GroupBy::Domain),
+        ] {
+            assert!(matches!(
+                aggregate_assets(aggregation),
+                Err(Error::AggregationOfDifferentAssets)
+            ));
+        }
+
+        assert_eq!(
+            aggregate(&Aggregation::min(), core::iter::empty())?,
+            [group(None, None)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn project_accounts() -> Result<()> {
+        let mut metadata = Metadata::new();
+        for key in ["name", "email"] {
+            metadata.insert_with_limits(
+                Name::from_str(key)?,
+                MetadataValueBox::from(key.to_owned()),
+                MetadataLimits::new(10, 100),
+            )?;
+        }
+        let mut account = Account::new(ALICE_ID.clone())
+            .with_metadata(metadata)
+            .build(&ALICE_ID);
+        let rose_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+        assert!(account
+            .add_asset(Asset::new(rose_id, numeric!(13)))
+            .is_none());
+        let account = QueryOutputBox::from(account);
+
+        assert_eq!(project(&Projection::Full, account.clone()), account);
+        assert_eq!(
+            project(&Projection::Id, account.clone()),
+            QueryOutputBox::Id(ALICE_ID.clone().into())
+        );
+
+        let QueryOutputBox::Identifiable(IdentifiableBox::Account(summary)) = project(
+            &Projection::summary([Name::from_str("name")?]),
+            account.clone(),
+        ) else {
+            panic!("Projection should keep the account");
+        };
+        assert_eq!(summary.assets().count(), 0);
+        assert_eq!(
+            summary
+                .metadata()
+                .iter()
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>(),
+            [&Name::from_str("name")?]
+        );
+
+        let numeric = QueryOutputBox::Numeric(numeric!(7));
+        assert_eq!(project(&Projection::Id, numeric.clone()), numeric);
+        Ok(())
+    }
 }
@@ -562,6 +562,43 @@ impl Set {
             matched_ids: self.matched_ids.view(),
         }
     }
+
+    /// Create a copy of the triggers in `set` which doesn't share storage with it
+    pub fn copy_of(set: &impl SetReadOnly) -> Self {
+        Self {
+            data_triggers: set
+                .data_triggers()
+                .iter()
+                .map(|(id, action)| (id.clone(), action.clone()))
+                .collect(),
+            pipeline_triggers: set
+                .pipeline_triggers()
+                .iter()
+                .map(|(id, action)| (id.clone(), action.clone()))
+                .collect(),
+            time_triggers: set
+                .time_triggers()
+                .iter()
+                .map(|(id, action)| (id.clone(), action.clone()))
+                .collect(),
+            by_call_triggers: set
+                .by_call_triggers()
+                .iter()
+                .map(|(id, action)| (id.clone(), action.clone()))
+                .collect(),
+            ids: set
+                .ids()
+                .iter()
+                .map(|(id, event_type)| (id.clone(), *event_type))
+                .collect(),
+            contracts: set
+                .contracts()
+                .iter()
+                .map(|(hash, entry)| (*hash, entry.clone()))
+                .collect(),
+            matched_ids: Cell::new(set.matched_ids().to_vec()),
+        }
+    }
 }
 
 impl<'set> SetBlock<'set> {
@@ -3,13 +3,13 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use iroha_crypto::HashOf;
+use iroha_crypto::{HashOf, MerkleTree};
 use iroha_data_model::{
     block::SignedBlock,
     prelude::*,
     query::{
         error::{FindError, QueryExecutionFail},
-        TransactionQueryOutput,
+        TransactionProof, TransactionQueryOutput,
     },
     transaction::CommittedTransaction,
 };
@@ -46,6 +46,10 @@ impl BlockTransactionRef {
         self.0.hash()
     }
 
+    fn block_height(&self) -> u64 {
+        self.0.header().height()
+    }
+
     fn authority(&self) -> &AccountId {
         self.0
             .transactions()
@@ -75,6 +79,7 @@ impl ValidQuery for FindAllTransactions {
                 .flat_map(BlockTransactionIter::new)
                 .map(|tx| TransactionQueryOutput {
                     block_hash: tx.block_hash(),
+                    block_height: tx.block_height(),
                     transaction: tx.value(),
                 }),
         ))
@@ -96,6 +101,7 @@ impl ValidQuery for FindTransactionsByAccountId {
                 .filter(move |tx| *tx.authority() == account_id)
                 .map(|tx| TransactionQueryOutput {
                     block_hash: tx.block_hash(),
+                    block_height: tx.block_height(),
                     transaction: tx.value(),
                 }),
         ))
@@ -119,6 +125,7 @@ impl ValidQuery for FindTransactionByHash {
             .ok_or_else(|| FindError::Transaction(tx_hash))?;
 
         let block_hash = block.hash();
+        let block_height = block.header().height();
 
         let mut transactions = block.transactions();
         transactions
@@ -126,8 +133,43 @@ impl ValidQuery for FindTransactionByHash {
             .cloned()
             .map(|transaction| TransactionQueryOutput {
                 block_hash,
+                block_height,
                 transaction,
             })
             .ok_or_else(|| FindError::Transaction(tx_hash).into())
     }
 }
+
+impl ValidQuery for FindTransactionProofByHash {
+    #[metrics(+"find_transaction_proof_by_hash")]
+    fn execute(
+        &self,
+        state_ro: &impl StateReadOnly,
+    ) -> Result<TransactionProof, QueryExecutionFail> {
+        let tx_hash = self.hash;
+
+        iroha_logger::trace!(%tx_hash);
+        let block = state_ro
+            .block_with_tx(&tx_hash)
+            .ok_or_else(|| FindError::Transaction(tx_hash))?;
+
+        let (idx, transaction) = block
+            .transactions()
+            .enumerate()
+            .find(|(_, transaction)| transaction.value.hash() == tx_hash)
+            .ok_or_else(|| FindError::Transaction(tx_hash))?;
+        let audit_path = block
+            .transactions()
+            .map(|transaction| transaction.as_ref().hash())
+            .collect::<MerkleTree<_>>()
+            .get_proof(idx)
+            .expect("Transaction is in the block");
+
+        Ok(TransactionProof {
+            block_hash: block.hash(),
+            block_header: block.header().clone(),
+            transaction: transaction.clone(),
+            audit_path,
+        })
+    }
+}
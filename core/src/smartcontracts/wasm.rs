@@ -799,14 +799,20 @@ where
                 sorting,
                 pagination,
                 fetch_size,
+                projection,
             }) => {
                 let batched = {
                     let state_ro = state.state.state();
                     let state_ro = state_ro.borrow();
                     state.validate_query(&state.authority, query.clone())?;
-                    let output = query
-                        .execute(state_ro)?
-                        .apply_postprocessing(&filter, &sorting, pagination, fetch_size)?;
+                    let output = query.execute(state_ro)?.apply_postprocessing(
+                        &filter,
+                        None,
+                        &sorting,
+                        pagination,
+                        &projection,
+                        fetch_size,
+                    )?;
 
                     state_ro.query_handle().handle_query_output(output)
                 }?;
@@ -885,9 +891,11 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         );
 
         self.execute_smart_contract_with_state(bytes, state)
+            .map(|_fuel_consumed| ())
     }
 
-    /// Validates that the given smartcontract is eligible for execution
+    /// Validates that the given smartcontract is eligible for execution.
+    /// Returns the amount of fuel consumed by the smartcontract.
     ///
     /// # Errors
     ///
@@ -900,7 +908,7 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         authority: AccountId,
         bytes: impl AsRef<[u8]>,
         max_instruction_count: u64,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         let span = wasm_log_span!("Smart contract validation", %authority);
         let state = state::SmartContract::new(
             authority,
@@ -913,11 +921,12 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         self.execute_smart_contract_with_state(bytes, state)
     }
 
+    /// Returns the amount of fuel consumed by the smartcontract
     fn execute_smart_contract_with_state(
         &mut self,
         bytes: impl AsRef<[u8]>,
         state: state::SmartContract<'wrld, 'block, 'state>,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         let mut store = self.create_store(state);
         let smart_contract = self.create_smart_contract(&mut store, bytes)?;
 
@@ -928,9 +937,14 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         main_fn
             .call(&mut store, ())
             .map_err(ExportFnCallError::from)?;
+        let fuel_consumed = self.config.fuel_limit
+            - store
+                .get_fuel()
+                .expect("Fuel consumption is enabled in the engine config, this is a bug");
         let mut state = store.into_data();
         let executed_queries = state.take_executed_queries();
-        forget_all_executed_queries(state.state.0.query_handle, executed_queries)
+        forget_all_executed_queries(state.state.0.query_handle, executed_queries)?;
+        Ok(fuel_consumed)
     }
 
     #[codec::wrap]
@@ -1705,7 +1719,9 @@ impl<C: wasmtime::AsContextMut> GetExport for (&wasmtime::Instance, C) {
 
 #[cfg(test)]
 mod tests {
-    use iroha_data_model::query::{predicate::PredicateBox, sorting::Sorting, Pagination};
+    use iroha_data_model::query::{
+        predicate::PredicateBox, sorting::Sorting, Pagination, Projection,
+    };
     use parity_scale_codec::Encode;
     use test_samples::gen_account_in;
     use tokio::test;
@@ -1824,6 +1840,7 @@ mod tests {
                 Sorting::default(),
                 Pagination::default(),
                 FetchSize::default(),
+                Projection::default(),
             ),
         )));
 
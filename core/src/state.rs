@@ -1,12 +1,21 @@
 //! This module provides the [`State`] — an in-memory representation of the current blockchain state.
-use std::{borrow::Borrow, collections::BTreeSet, marker::PhantomData, sync::Arc, time::Duration};
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+    marker::PhantomData,
+    num::NonZeroU64,
+    ops::RangeInclusive,
+    sync::Arc,
+    time::Duration,
+};
 
 use eyre::Result;
 use iroha_config::parameters::actual::ChainWide as Config;
-use iroha_crypto::HashOf;
+use iroha_crypto::{Hash, HashOf, SparseMerkleProof, SparseMerkleTree};
 use iroha_data_model::{
-    account::AccountId,
-    block::SignedBlock,
+    account::{AccountId, AccountsMap},
+    asset::{AssetDefinitionsMap, AssetsMap},
+    block::{AccountPermission, AccountRole, SignedBlock, StateEntry},
     events::{
         pipeline::BlockEvent,
         time::TimeEvent,
@@ -22,7 +31,7 @@ use iroha_data_model::{
     role::RoleId,
 };
 use iroha_logger::prelude::*;
-use iroha_primitives::{must_use::MustUse, numeric::Numeric, small::SmallVec};
+use iroha_primitives::{cmpext::MinMaxExt, must_use::MustUse, numeric::Numeric, small::SmallVec};
 use parking_lot::Mutex;
 use range_bounds::RoleIdByAccountBounds;
 use serde::{
@@ -52,7 +61,7 @@ use crate::{
             },
             specialized::LoadedActionTrait,
         },
-        wasm, Execute,
+        wasm, Execute, Registrable,
     },
     tx::TransactionExecutor,
     Parameters, PeersIds,
@@ -80,6 +89,15 @@ pub struct World {
     pub(crate) executor: Cell<Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: Cell<ExecutorDataModel>,
+    /// Paths of the world state entries in the state tree
+    #[serde(skip)]
+    state_keys: Storage<StateKey, StatePath>,
+    /// Leaves of the state tree, i.e. hashes of the world state entries
+    #[serde(skip)]
+    state_leaves: Storage<StatePath, HashOf<StateEntry>>,
+    /// Hashes of the state tree nodes with more than one leaf below them
+    #[serde(skip)]
+    state_nodes: Storage<StateNodeKey, StateTreeHash>,
 }
 
 /// Struct for block's aggregated changes
@@ -102,6 +120,12 @@ pub struct WorldBlock<'world> {
     pub(crate) executor: CellBlock<'world, Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: CellBlock<'world, ExecutorDataModel>,
+    /// Paths of the world state entries in the state tree
+    state_keys: StorageBlock<'world, StateKey, StatePath>,
+    /// Leaves of the state tree, i.e. hashes of the world state entries
+    state_leaves: StorageBlock<'world, StatePath, HashOf<StateEntry>>,
+    /// Hashes of the state tree nodes with more than one leaf below them
+    state_nodes: StorageBlock<'world, StateNodeKey, StateTreeHash>,
     /// Events produced during execution of block
     events_buffer: Vec<EventBox>,
 }
@@ -126,6 +150,12 @@ pub struct WorldTransaction<'block, 'world> {
     pub(crate) executor: CellTransaction<'block, 'world, Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: CellTransaction<'block, 'world, ExecutorDataModel>,
+    /// Paths of the world state entries in the state tree
+    state_keys: StorageTransaction<'block, 'world, StateKey, StatePath>,
+    /// Leaves of the state tree, i.e. hashes of the world state entries
+    state_leaves: StorageTransaction<'block, 'world, StatePath, HashOf<StateEntry>>,
+    /// Hashes of the state tree nodes with more than one leaf below them
+    state_nodes: StorageTransaction<'block, 'world, StateNodeKey, StateTreeHash>,
     /// Events produced during execution of a transaction
     events_buffer: TransactionEventBuffer<'block>,
 }
@@ -158,6 +188,12 @@ pub struct WorldView<'world> {
     pub(crate) executor: CellView<'world, Executor>,
     /// Executor-defined data model
     pub(crate) executor_data_model: CellView<'world, ExecutorDataModel>,
+    /// Paths of the world state entries in the state tree
+    state_keys: StorageView<'world, StateKey, StatePath>,
+    /// Leaves of the state tree, i.e. hashes of the world state entries
+    state_leaves: StorageView<'world, StatePath, HashOf<StateEntry>>,
+    /// Hashes of the state tree nodes with more than one leaf below them
+    state_nodes: StorageView<'world, StateNodeKey, StateTreeHash>,
 }
 
 /// Current state of the blockchain
@@ -172,6 +208,10 @@ pub struct State {
     pub block_hashes: Cell<Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: Storage<HashOf<SignedTransaction>, u64>,
+    /// Hashes of payloads of transactions mapped onto block height where they stored.
+    /// Unlike [`Self::transactions`] they don't depend on the signatures,
+    /// so a transaction can't be committed again with another set of signatures.
+    pub transaction_payloads: Storage<Hash, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     #[serde(skip)]
     pub engine: wasmtime::Engine,
@@ -186,6 +226,9 @@ pub struct State {
     /// TODO: this should be done through events
     #[serde(skip)]
     pub new_tx_amounts: Arc<Mutex<Vec<f64>>>,
+    /// Snapshots to reconstruct the state at past heights from.
+    #[serde(skip)]
+    history: Option<History>,
 }
 
 /// Struct for block's aggregated changes
@@ -198,6 +241,8 @@ pub struct StateBlock<'state> {
     pub block_hashes: CellBlock<'state, Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageBlock<'state, HashOf<SignedTransaction>, u64>,
+    /// Hashes of payloads of transactions mapped onto block height where they stored
+    pub transaction_payloads: StorageBlock<'state, Hash, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -208,6 +253,8 @@ pub struct StateBlock<'state> {
     /// Temporary metrics buffer of amounts of any asset that has been transacted.
     /// TODO: this should be done through events
     pub new_tx_amounts: &'state Mutex<Vec<f64>>,
+    /// Snapshots to reconstruct the state at past heights from.
+    history: Option<&'state History>,
 }
 
 /// Struct for single transaction's aggregated changes
@@ -220,6 +267,8 @@ pub struct StateTransaction<'block, 'state> {
     pub block_hashes: CellTransaction<'block, 'state, Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageTransaction<'block, 'state, HashOf<SignedTransaction>, u64>,
+    /// Hashes of payloads of transactions mapped onto block height where they stored
+    pub transaction_payloads: StorageTransaction<'block, 'state, Hash, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -242,6 +291,8 @@ pub struct StateView<'state> {
     pub block_hashes: CellView<'state, Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageView<'state, HashOf<SignedTransaction>, u64>,
+    /// Hashes of payloads of transactions mapped onto block height where they stored
+    pub transaction_payloads: StorageView<'state, Hash, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -274,6 +325,61 @@ impl World {
             domains,
             ..World::new()
         }
+        .with_state_tree()
+    }
+
+    /// Commit to all the world state entries in the (empty) state tree
+    fn with_state_tree(self) -> Self {
+        let mut world_block = self.block();
+        world_block.build_state_tree();
+        world_block.commit();
+        self
+    }
+
+    /// Create a copy of `world` which doesn't share storage with it
+    pub fn copy_of(world: &impl WorldReadOnly) -> Self {
+        Self {
+            parameters: Cell::new(world.parameters().clone()),
+            trusted_peers_ids: Cell::new(world.trusted_peers_ids().clone()),
+            domains: world
+                .domains()
+                .iter()
+                .map(|(id, domain)| (id.clone(), domain.clone()))
+                .collect(),
+            roles: world
+                .roles()
+                .iter()
+                .map(|(id, role)| (id.clone(), role.clone()))
+                .collect(),
+            account_permissions: world
+                .account_permissions()
+                .iter()
+                .map(|(id, permissions)| (id.clone(), permissions.clone()))
+                .collect(),
+            account_roles: world
+                .account_roles()
+                .iter()
+                .map(|(role, ())| (role.clone(), ()))
+                .collect(),
+            triggers: TriggerSet::copy_of(world.triggers()),
+            executor: Cell::new(world.executor().clone()),
+            executor_data_model: Cell::new(world.executor_data_model().clone()),
+            state_keys: world
+                .state_keys()
+                .iter()
+                .map(|(key, path)| (key.clone(), *path))
+                .collect(),
+            state_leaves: world
+                .state_leaves()
+                .iter()
+                .map(|(path, leaf)| (*path, *leaf))
+                .collect(),
+            state_nodes: world
+                .state_nodes()
+                .iter()
+                .map(|(key, node)| (*key, *node))
+                .collect(),
+        }
     }
 
     /// Create struct to apply block's changes
@@ -288,6 +394,9 @@ impl World {
             triggers: self.triggers.block(),
             executor: self.executor.block(),
             executor_data_model: self.executor_data_model.block(),
+            state_keys: self.state_keys.block(),
+            state_leaves: self.state_leaves.block(),
+            state_nodes: self.state_nodes.block(),
             events_buffer: Vec::new(),
         }
     }
@@ -304,6 +413,9 @@ impl World {
             triggers: self.triggers.block_and_revert(),
             executor: self.executor.block_and_revert(),
             executor_data_model: self.executor_data_model.block_and_revert(),
+            state_keys: self.state_keys.block_and_revert(),
+            state_leaves: self.state_leaves.block_and_revert(),
+            state_nodes: self.state_nodes.block_and_revert(),
             events_buffer: Vec::new(),
         }
     }
@@ -320,6 +432,9 @@ impl World {
             triggers: self.triggers.view(),
             executor: self.executor.view(),
             executor_data_model: self.executor_data_model.view(),
+            state_keys: self.state_keys.view(),
+            state_leaves: self.state_leaves.view(),
+            state_nodes: self.state_nodes.view(),
         }
     }
 }
@@ -336,6 +451,9 @@ pub trait WorldReadOnly {
     fn triggers(&self) -> &impl TriggerSetReadOnly;
     fn executor(&self) -> &Executor;
     fn executor_data_model(&self) -> &ExecutorDataModel;
+    fn state_keys(&self) -> &impl StorageReadOnly<StateKey, StatePath>;
+    fn state_leaves(&self) -> &impl StorageReadOnly<StatePath, HashOf<StateEntry>>;
+    fn state_nodes(&self) -> &impl StorageReadOnly<StateNodeKey, StateTreeHash>;
 
     // Domain-related methods
 
@@ -556,6 +674,81 @@ pub trait WorldReadOnly {
     fn peers_ids(&self) -> &PeersIds {
         self.trusted_peers_ids()
     }
+
+    // State commitment methods
+
+    /// Iterate over all the world state entries committed to by
+    /// [`BlockHeader::state_hash`](iroha_data_model::block::BlockHeader::state_hash).
+    fn state_entries(&self) -> impl Iterator<Item = StateEntry> + '_ {
+        let domains = self.domains_iter().flat_map(domain_state_entries);
+        let roles = self
+            .roles()
+            .iter()
+            .map(|(_, role)| StateEntry::Role(role.clone()));
+        let account_permissions =
+            self.account_permissions()
+                .iter()
+                .flat_map(|(account_id, permissions)| {
+                    permissions.iter().map(move |permission| {
+                        StateEntry::AccountPermission(AccountPermission {
+                            account_id: account_id.clone(),
+                            permission: permission.clone(),
+                        })
+                    })
+                });
+        let account_roles = self.account_roles().iter().map(|(role, ())| {
+            StateEntry::AccountRole(AccountRole {
+                account_id: role.account_id.clone(),
+                role_id: role.role_id.clone(),
+            })
+        });
+        let triggers = self
+            .triggers()
+            .ids()
+            .iter()
+            .filter_map(|(id, _)| trigger_state_entry(self, id));
+        let parameters = self.parameters_iter().cloned().map(StateEntry::Parameter);
+
+        domains
+            .chain(roles)
+            .chain(account_permissions)
+            .chain(account_roles)
+            .chain(triggers)
+            .chain(parameters)
+    }
+
+    /// Get the root hash of the world state tree.
+    ///
+    /// The tree is updated when a block is applied, so while the block is being
+    /// executed this is the hash of the world state after the previous block.
+    fn state_hash(&self) -> Option<StateTreeHash> {
+        state_subtree_hash(self, &[0; Hash::LENGTH], 0)
+    }
+
+    /// Get the proof of inclusion of `entry` into the world state tree.
+    ///
+    /// Returns `None` if there is no such entry.
+    fn state_proof(&self, entry: &StateEntry) -> Option<SparseMerkleProof<StateEntry>> {
+        let path = entry.path();
+        if self.state_leaves().get(&path) != Some(&HashOf::new(entry)) {
+            return None;
+        }
+
+        let mut audit_path = Vec::new();
+        let mut prefix = [0; Hash::LENGTH];
+        for depth in 0.. {
+            if self.state_nodes().get(&(prefix, depth)).is_none() {
+                break;
+            }
+
+            let is_right = SparseMerkleTree::<StateEntry>::is_right(&path, depth.into());
+            let sibling = state_child_prefix(&prefix, depth, !is_right);
+            audit_path.push(state_subtree_hash(self, &sibling, depth + 1));
+            prefix = state_child_prefix(&prefix, depth, is_right);
+        }
+
+        Some(SparseMerkleProof::new(audit_path))
+    }
 }
 
 macro_rules! impl_world_ro {
@@ -588,6 +781,15 @@ macro_rules! impl_world_ro {
             fn executor_data_model(&self) -> &ExecutorDataModel {
                 &self.executor_data_model
             }
+            fn state_keys(&self) -> &impl StorageReadOnly<StateKey, StatePath> {
+                &self.state_keys
+            }
+            fn state_leaves(&self) -> &impl StorageReadOnly<StatePath, HashOf<StateEntry>> {
+                &self.state_leaves
+            }
+            fn state_nodes(&self) -> &impl StorageReadOnly<StateNodeKey, StateTreeHash> {
+                &self.state_nodes
+            }
         }
     )*};
 }
@@ -596,6 +798,387 @@ impl_world_ro! {
     WorldBlock<'_>, WorldTransaction<'_, '_>, WorldView<'_>
 }
 
+/// Path of the world state entry in the state tree, see [`StateEntry::path`]
+type StatePath = [u8; Hash::LENGTH];
+
+/// Node of the state tree identified by the common prefix of the paths below it and its depth
+type StateNodeKey = (StatePath, u16);
+
+/// Hash of the state tree node
+type StateTreeHash = HashOf<SparseMerkleTree<StateEntry>>;
+
+/// Key of the world state entry in the state tree.
+///
+/// Keys of the entries which belong to the same domain, account or scope are adjacent,
+/// so that they can be found with a range query once the scope has changed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StateKey {
+    /// Entry registered in the domain
+    Domain(DomainId, MinMaxExt<DomainStateKey>),
+    /// Role
+    Role(RoleId),
+    /// Permission granted to an account
+    AccountPermission(AccountId, MinMaxExt<Permission>),
+    /// Role granted to an account
+    AccountRole(AccountId, MinMaxExt<RoleId>),
+    /// Trigger which doesn't belong to any domain
+    Trigger(Name),
+    /// Chain-wide parameter
+    Parameter(ParameterId),
+}
+
+/// Key of the world state entry registered in the domain, see [`StateKey::Domain`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DomainStateKey {
+    /// Domain itself
+    Domain,
+    /// Asset definition
+    AssetDefinition(Name),
+    /// Account if the asset definition is `None`, its asset otherwise
+    Account(PublicKey, MinMaxExt<Option<AssetDefinitionId>>),
+    /// Trigger
+    Trigger(Name),
+}
+
+impl StateKey {
+    fn domain(domain_id: &DomainId, key: MinMaxExt<DomainStateKey>) -> Self {
+        Self::Domain(domain_id.clone(), key)
+    }
+
+    fn account(account_id: &AccountId, key: MinMaxExt<Option<AssetDefinitionId>>) -> Self {
+        Self::domain(
+            &account_id.domain_id,
+            DomainStateKey::Account(account_id.signatory.clone(), key).into(),
+        )
+    }
+
+    fn trigger(trigger_id: &TriggerId) -> Self {
+        trigger_id.domain_id.as_ref().map_or_else(
+            || Self::Trigger(trigger_id.name.clone()),
+            |domain_id| {
+                Self::domain(
+                    domain_id,
+                    DomainStateKey::Trigger(trigger_id.name.clone()).into(),
+                )
+            },
+        )
+    }
+
+    fn of(entry: &StateEntry) -> Self {
+        match entry {
+            StateEntry::Domain(domain) => Self::domain(&domain.id, DomainStateKey::Domain.into()),
+            StateEntry::AssetDefinition(asset_definition) => Self::domain(
+                &asset_definition.id.domain_id,
+                DomainStateKey::AssetDefinition(asset_definition.id.name.clone()).into(),
+            ),
+            StateEntry::Account(account) => Self::account(&account.id, None.into()),
+            StateEntry::Asset(asset) => Self::account(
+                &asset.id.account_id,
+                Some(asset.id.definition_id.clone()).into(),
+            ),
+            StateEntry::Role(role) => Self::Role(role.id.clone()),
+            StateEntry::AccountPermission(AccountPermission {
+                account_id,
+                permission,
+            }) => Self::AccountPermission(account_id.clone(), permission.clone().into()),
+            StateEntry::AccountRole(AccountRole {
+                account_id,
+                role_id,
+            }) => Self::AccountRole(account_id.clone(), role_id.clone().into()),
+            StateEntry::Trigger(trigger) => Self::trigger(&trigger.id),
+            StateEntry::Parameter(parameter) => Self::Parameter(parameter.id.clone()),
+        }
+    }
+}
+
+/// Part of the world state which has to be committed to again once it has changed
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum StateScope {
+    /// Domain with everything registered in it
+    DomainAll(DomainId),
+    /// Domain itself
+    Domain(DomainId),
+    AssetDefinition(AssetDefinitionId),
+    /// Account with its assets
+    AccountAll(AccountId),
+    /// Account itself
+    Account(AccountId),
+    Asset(AssetId),
+    Role(RoleId),
+    AccountPermissions(AccountId),
+    AccountRoles(AccountId),
+    Trigger(TriggerId),
+    Parameter(ParameterId),
+}
+
+impl StateScope {
+    /// Scopes of the world state changed by the `event`
+    fn changed_by(event: &EventBox) -> Vec<Self> {
+        match event {
+            EventBox::Data(DataEvent::Domain(event)) => match event {
+                DomainEvent::Created(domain) => vec![Self::DomainAll(domain.id.clone())],
+                DomainEvent::Deleted(domain_id) => vec![Self::DomainAll(domain_id.clone())],
+                DomainEvent::AssetDefinition(event) => {
+                    // Total quantities of assets are stored in the domain
+                    let asset_definition_id = event.origin_id();
+                    vec![
+                        Self::AssetDefinition(asset_definition_id.clone()),
+                        Self::Domain(asset_definition_id.domain_id.clone()),
+                    ]
+                }
+                DomainEvent::Account(event) => match event {
+                    AccountEvent::Asset(event) => vec![Self::Asset(event.origin_id().clone())],
+                    AccountEvent::Created(_) | AccountEvent::Deleted(_) => {
+                        vec![Self::AccountAll(event.origin_id().clone())]
+                    }
+                    AccountEvent::PermissionAdded(_) | AccountEvent::PermissionRemoved(_) => {
+                        vec![Self::AccountPermissions(event.origin_id().clone())]
+                    }
+                    AccountEvent::RoleGranted(_) | AccountEvent::RoleRevoked(_) => {
+                        vec![Self::AccountRoles(event.origin_id().clone())]
+                    }
+                    _ => vec![Self::Account(event.origin_id().clone())],
+                },
+                _ => vec![Self::Domain(event.origin_id().clone())],
+            },
+            EventBox::Data(DataEvent::Role(event)) => vec![Self::Role(event.origin_id().clone())],
+            EventBox::Data(DataEvent::Trigger(event)) => {
+                vec![Self::Trigger(event.origin_id().clone())]
+            }
+            EventBox::Data(DataEvent::Configuration(event)) => {
+                vec![Self::Parameter(event.origin_id().clone())]
+            }
+            EventBox::TriggerCompleted(event) => vec![Self::Trigger(event.trigger_id().clone())],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Keys of the entries which belong to the scope
+    fn keys(&self) -> RangeInclusive<StateKey> {
+        let single = |key: StateKey| key.clone()..=key;
+
+        match self {
+            Self::DomainAll(domain_id) => {
+                StateKey::domain(domain_id, MinMaxExt::Min)
+                    ..=StateKey::domain(domain_id, MinMaxExt::Max)
+            }
+            Self::Domain(domain_id) => {
+                single(StateKey::domain(domain_id, DomainStateKey::Domain.into()))
+            }
+            Self::AssetDefinition(asset_definition_id) => single(StateKey::domain(
+                &asset_definition_id.domain_id,
+                DomainStateKey::AssetDefinition(asset_definition_id.name.clone()).into(),
+            )),
+            Self::AccountAll(account_id) => {
+                StateKey::account(account_id, MinMaxExt::Min)
+                    ..=StateKey::account(account_id, MinMaxExt::Max)
+            }
+            Self::Account(account_id) => single(StateKey::account(account_id, None.into())),
+            Self::Asset(asset_id) => single(StateKey::account(
+                &asset_id.account_id,
+                Some(asset_id.definition_id.clone()).into(),
+            )),
+            Self::Role(role_id) => single(StateKey::Role(role_id.clone())),
+            Self::AccountPermissions(account_id) => {
+                StateKey::AccountPermission(account_id.clone(), MinMaxExt::Min)
+                    ..=StateKey::AccountPermission(account_id.clone(), MinMaxExt::Max)
+            }
+            Self::AccountRoles(account_id) => {
+                StateKey::AccountRole(account_id.clone(), MinMaxExt::Min)
+                    ..=StateKey::AccountRole(account_id.clone(), MinMaxExt::Max)
+            }
+            Self::Trigger(trigger_id) => single(StateKey::trigger(trigger_id)),
+            Self::Parameter(parameter_id) => single(StateKey::Parameter(parameter_id.clone())),
+        }
+    }
+
+    /// Current entries of the world state which belong to the scope
+    fn entries(&self, world: &impl WorldReadOnly) -> Vec<StateEntry> {
+        match self {
+            Self::DomainAll(domain_id) => {
+                let triggers = world
+                    .triggers()
+                    .inspect_by_domain_id(domain_id, |trigger_id, _| trigger_id.clone())
+                    .collect::<Vec<_>>();
+
+                world
+                    .domain(domain_id)
+                    .into_iter()
+                    .flat_map(domain_state_entries)
+                    .chain(
+                        triggers
+                            .iter()
+                            .filter_map(|trigger_id| trigger_state_entry(world, trigger_id)),
+                    )
+                    .collect()
+            }
+            Self::Domain(domain_id) => world
+                .domain(domain_id)
+                .map(domain_state_entry)
+                .into_iter()
+                .collect(),
+            Self::AssetDefinition(asset_definition_id) => world
+                .asset_definition(asset_definition_id)
+                .map(StateEntry::AssetDefinition)
+                .into_iter()
+                .collect(),
+            Self::AccountAll(account_id) => world
+                .account(account_id)
+                .into_iter()
+                .flat_map(account_state_entries)
+                .collect(),
+            Self::Account(account_id) => world
+                .account(account_id)
+                .map(account_state_entry)
+                .into_iter()
+                .collect(),
+            Self::Asset(asset_id) => world
+                .asset(asset_id)
+                .map(StateEntry::Asset)
+                .into_iter()
+                .collect(),
+            Self::Role(role_id) => world
+                .roles()
+                .get(role_id)
+                .cloned()
+                .map(StateEntry::Role)
+                .into_iter()
+                .collect(),
+            Self::AccountPermissions(account_id) => world
+                .account_permissions()
+                .get(account_id)
+                .into_iter()
+                .flatten()
+                .map(|permission| {
+                    StateEntry::AccountPermission(AccountPermission {
+                        account_id: account_id.clone(),
+                        permission: permission.clone(),
+                    })
+                })
+                .collect(),
+            Self::AccountRoles(account_id) => world
+                .account_roles_iter(account_id)
+                .map(|role_id| {
+                    StateEntry::AccountRole(AccountRole {
+                        account_id: account_id.clone(),
+                        role_id: role_id.clone(),
+                    })
+                })
+                .collect(),
+            Self::Trigger(trigger_id) => {
+                trigger_state_entry(world, trigger_id).into_iter().collect()
+            }
+            Self::Parameter(parameter_id) => world
+                .parameters()
+                .get::<str>(parameter_id.borrow())
+                .cloned()
+                .map(StateEntry::Parameter)
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+/// Entries of the domain with everything registered in it except for triggers
+fn domain_state_entries(domain: &Domain) -> impl Iterator<Item = StateEntry> + '_ {
+    let asset_definitions = domain
+        .asset_definitions
+        .values()
+        .cloned()
+        .map(StateEntry::AssetDefinition);
+    let accounts = domain.accounts.values().flat_map(account_state_entries);
+
+    core::iter::once(domain_state_entry(domain))
+        .chain(asset_definitions)
+        .chain(accounts)
+}
+
+/// Entry of the domain without its accounts and asset definitions
+fn domain_state_entry(domain: &Domain) -> StateEntry {
+    StateEntry::Domain(Domain {
+        id: domain.id.clone(),
+        accounts: AccountsMap::new(),
+        asset_definitions: AssetDefinitionsMap::new(),
+        asset_total_quantities: domain.asset_total_quantities.clone(),
+        logo: domain.logo.clone(),
+        metadata: domain.metadata.clone(),
+        owned_by: domain.owned_by.clone(),
+    })
+}
+
+/// Entries of the account with its assets
+fn account_state_entries(account: &Account) -> impl Iterator<Item = StateEntry> + '_ {
+    let assets = account.assets.values().cloned().map(StateEntry::Asset);
+
+    core::iter::once(account_state_entry(account)).chain(assets)
+}
+
+/// Entry of the account without its assets
+fn account_state_entry(account: &Account) -> StateEntry {
+    StateEntry::Account(Account {
+        id: account.id.clone(),
+        assets: AssetsMap::new(),
+        metadata: account.metadata.clone(),
+        signatory_policy: account.signatory_policy.clone(),
+    })
+}
+
+fn trigger_state_entry(
+    world: &(impl WorldReadOnly + ?Sized),
+    trigger_id: &TriggerId,
+) -> Option<StateEntry> {
+    // Can't use just `LoadedActionTrait::clone_and_box` cause this will trigger lifetime mismatch
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    let loaded_action = world
+        .triggers()
+        .inspect_by_id(trigger_id, |action| action.clone_and_box())?;
+    let action = world.triggers().get_original_action(loaded_action).into();
+
+    Some(StateEntry::Trigger(Trigger::new(
+        trigger_id.clone(),
+        action,
+    )))
+}
+
+/// Paths which belong to the subtree of the state tree with `prefix` at `depth`
+fn state_subtree_paths(prefix: &StatePath, depth: u16) -> RangeInclusive<StatePath> {
+    let mut last = *prefix;
+    for bit in usize::from(depth)..SparseMerkleTree::<StateEntry>::DEPTH {
+        last[bit / 8] |= 0x80 >> (bit % 8);
+    }
+
+    *prefix..=last
+}
+
+/// Prefix of the left or right child of the state tree node with `prefix` at `depth`
+fn state_child_prefix(prefix: &StatePath, depth: u16, is_right: bool) -> StatePath {
+    let mut child = *prefix;
+    if is_right {
+        let bit = usize::from(depth);
+        child[bit / 8] |= 0x80 >> (bit % 8);
+    }
+
+    child
+}
+
+/// Hash of the subtree of the state tree with `prefix` at `depth`
+fn state_subtree_hash(
+    world: &(impl WorldReadOnly + ?Sized),
+    prefix: &StatePath,
+    depth: u16,
+) -> Option<StateTreeHash> {
+    if let Some(node) = world.state_nodes().get(&(*prefix, depth)) {
+        return Some(*node);
+    }
+
+    // Subtree without node has at most one leaf
+    world
+        .state_leaves()
+        .range(state_subtree_paths(prefix, depth))
+        .next()
+        .map(|(path, leaf)| SparseMerkleTree::leaf_hash(path, leaf))
+}
+
 impl<'world> WorldBlock<'world> {
     /// Create struct to apply transaction's changes
     pub fn trasaction(&mut self) -> WorldTransaction<'_, 'world> {
@@ -609,6 +1192,9 @@ impl<'world> WorldBlock<'world> {
             triggers: self.triggers.transaction(),
             executor: self.executor.transaction(),
             executor_data_model: self.executor_data_model.transaction(),
+            state_keys: self.state_keys.transaction(),
+            state_leaves: self.state_leaves.transaction(),
+            state_nodes: self.state_nodes.transaction(),
             events_buffer: TransactionEventBuffer {
                 events_buffer: &mut self.events_buffer,
                 events_created_in_transaction: 0,
@@ -616,9 +1202,123 @@ impl<'world> WorldBlock<'world> {
         }
     }
 
+    /// Commit to all the world state entries in the empty state tree
+    fn build_state_tree(&mut self) {
+        let entries = self.state_entries().collect::<Vec<_>>();
+
+        let mut paths = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let path = entry.path();
+            self.state_keys.insert(StateKey::of(&entry), path);
+            self.state_leaves.insert(path, HashOf::new(&entry));
+            paths.push(path);
+        }
+        paths.sort_unstable();
+
+        self.update_state_subtree(&[0; Hash::LENGTH], 0, &paths);
+    }
+
+    /// Commit to the world state entries changed by the events produced so far
+    fn update_state_tree(&mut self) {
+        let scopes = self
+            .events_buffer
+            .iter()
+            .flat_map(StateScope::changed_by)
+            .collect::<BTreeSet<_>>();
+
+        let mut changed = BTreeSet::new();
+        for scope in scopes {
+            let entries = scope.entries(&*self);
+            let mut removed = self
+                .state_keys
+                .range(scope.keys())
+                .map(|(key, path)| (key.clone(), *path))
+                .collect::<BTreeMap<_, _>>();
+
+            for entry in entries {
+                let key = StateKey::of(&entry);
+                let path = entry.path();
+                let leaf = HashOf::new(&entry);
+
+                removed.remove(&key);
+                if self.state_leaves.get(&path) != Some(&leaf) {
+                    self.state_keys.insert(key, path);
+                    self.state_leaves.insert(path, leaf);
+                    changed.insert(path);
+                }
+            }
+            for (key, path) in removed {
+                self.state_keys.remove(key);
+                self.state_leaves.remove(path);
+                changed.insert(path);
+            }
+        }
+
+        if !changed.is_empty() {
+            let changed = changed.into_iter().collect::<Vec<_>>();
+            self.update_state_subtree(&[0; Hash::LENGTH], 0, &changed);
+        }
+    }
+
+    /// Recompute hashes of the subtree of the state tree with `prefix` at `depth`
+    /// along the `changed` paths which are sorted and belong to the subtree.
+    fn update_state_subtree(
+        &mut self,
+        prefix: &StatePath,
+        depth: u16,
+        changed: &[StatePath],
+    ) -> Option<StateTreeHash> {
+        let paths = state_subtree_paths(prefix, depth);
+        let mut leaves = self.state_leaves.range(paths.clone());
+        let first_leaf = leaves
+            .next()
+            .map(|(path, leaf)| SparseMerkleTree::leaf_hash(path, leaf));
+        let is_node = leaves.next().is_some();
+
+        if !is_node {
+            // The subtree might have had more leaves before, so its nodes are stale
+            let stale = self
+                .state_nodes
+                .range((*paths.start(), depth)..=(*paths.end(), u16::MAX))
+                .map(|(key, _)| *key)
+                .collect::<Vec<_>>();
+            for key in stale {
+                self.state_nodes.remove(key);
+            }
+
+            return first_leaf;
+        }
+
+        let split = changed
+            .partition_point(|path| !SparseMerkleTree::<StateEntry>::is_right(path, depth.into()));
+        let (left_changed, right_changed) = changed.split_at(split);
+        let [left, right] =
+            [(false, left_changed), (true, right_changed)].map(|(is_right, changed)| {
+                let child = state_child_prefix(prefix, depth, is_right);
+
+                if changed.is_empty() {
+                    state_subtree_hash(&*self, &child, depth + 1)
+                } else {
+                    self.update_state_subtree(&child, depth + 1, changed)
+                }
+            });
+
+        let node = SparseMerkleTree::node_hash(left.as_ref(), right.as_ref());
+        self.state_nodes.insert((*prefix, depth), node);
+        Some(node)
+    }
+
+    /// Take the events produced by the transactions applied to the block so far
+    pub(crate) fn take_events(&mut self) -> Vec<EventBox> {
+        core::mem::take(&mut self.events_buffer)
+    }
+
     /// Commit block's changes
     pub fn commit(self) {
         // IMPORTANT!!! Commit fields in reverse order, this way consistent results are insured
+        self.state_nodes.commit();
+        self.state_leaves.commit();
+        self.state_keys.commit();
         self.executor_data_model.commit();
         self.executor.commit();
         self.triggers.commit();
@@ -634,6 +1334,9 @@ impl<'world> WorldBlock<'world> {
 impl WorldTransaction<'_, '_> {
     /// Apply transaction's changes
     pub fn apply(mut self) {
+        self.state_nodes.apply();
+        self.state_leaves.apply();
+        self.state_keys.apply();
         self.executor_data_model.apply();
         self.executor.apply();
         self.triggers.apply();
@@ -933,11 +1636,45 @@ impl State {
             world,
             config: Cell::new(config),
             transactions: Storage::new(),
+            transaction_payloads: Storage::new(),
             block_hashes: Cell::new(Vec::new()),
             new_tx_amounts: Arc::new(Mutex::new(Vec::new())),
             engine: wasm::create_engine(),
             kura,
             query_handle,
+            history: None,
+        }
+    }
+
+    /// Copy the current state not sharing storage with it.
+    ///
+    /// Blocks can be executed on the copy without blocking the execution of blocks on `self`.
+    #[must_use]
+    pub fn detached_copy(&self) -> Self {
+        Self::copy_of(&self.view(), Arc::clone(&self.kura))
+    }
+
+    /// Copy `state` not sharing storage with it
+    fn copy_of(state: &impl StateReadOnly, kura: Arc<Kura>) -> Self {
+        Self {
+            world: World::copy_of(state.world()),
+            config: Cell::new(*state.config()),
+            block_hashes: Cell::new(state.block_hashes().to_vec()),
+            transactions: state
+                .transactions()
+                .iter()
+                .map(|(hash, height)| (*hash, *height))
+                .collect(),
+            transaction_payloads: state
+                .transaction_payloads()
+                .iter()
+                .map(|(hash, height)| (*hash, *height))
+                .collect(),
+            engine: state.engine().clone(),
+            kura,
+            query_handle: state.query_handle().clone(),
+            new_tx_amounts: Arc::new(Mutex::new(Vec::new())),
+            history: None,
         }
     }
 
@@ -948,10 +1685,12 @@ impl State {
             config: self.config.block(),
             block_hashes: self.block_hashes.block(),
             transactions: self.transactions.block(),
+            transaction_payloads: self.transaction_payloads.block(),
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
+            history: self.history.as_ref(),
         }
     }
 
@@ -962,10 +1701,12 @@ impl State {
             config: self.config.block_and_revert(),
             block_hashes: self.block_hashes.block_and_revert(),
             transactions: self.transactions.block_and_revert(),
+            transaction_payloads: self.transaction_payloads.block_and_revert(),
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
+            history: self.history.as_ref(),
         }
     }
 
@@ -976,12 +1717,257 @@ impl State {
             config: self.config.view(),
             block_hashes: self.block_hashes.view(),
             transactions: self.transactions.view(),
+            transaction_payloads: self.transaction_payloads.view(),
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
         }
     }
+
+    /// Keep the snapshots needed to reconstruct the state at any of the latest `depth` heights.
+    ///
+    /// The state is copied every `depth` blocks by [`Self::keep_history`], so about `depth` blocks
+    /// are replayed by [`Self::at_height`] and [`Self::replay_events`].
+    /// History is disabled if `depth` is zero.
+    #[must_use]
+    pub fn with_history_depth(mut self, depth: u64) -> Self {
+        self.history = NonZeroU64::new(depth).map(|depth| History {
+            depth,
+            kura: Arc::clone(&self.kura),
+            snapshots: Mutex::new(Vec::new()),
+            due: tokio::sync::Notify::new(),
+        });
+        // Heights below the one the state was loaded at can't be reconstructed otherwise
+        if self.view().height() > 0 {
+            self.snapshot_history();
+        }
+        self
+    }
+
+    /// Take the snapshots of the history as they become due on the commit of blocks.
+    ///
+    /// The state is copied in a blocking task, so the commit of blocks doesn't wait for the copy.
+    /// Returns immediately if the history is disabled.
+    pub async fn keep_history(self: Arc<Self>) {
+        let Some(history) = &self.history else {
+            return;
+        };
+
+        loop {
+            history.due.notified().await;
+            let state = Arc::clone(&self);
+            if let Err(panic) = tokio::task::spawn_blocking(move || state.snapshot_history()).await
+            {
+                error!(%panic, "Task panicked during creation of a history snapshot");
+            }
+        }
+    }
+
+    /// Snapshot the latest state into the history
+    fn snapshot_history(&self) {
+        if let Some(history) = &self.history {
+            let state_view = self.view();
+            history.push(state_view.height(), history.snapshot(&state_view));
+        }
+    }
+
+    /// Run `f` on the [`State`] as it was right after the block at `height` was applied.
+    ///
+    /// The state is reconstructed by replaying blocks stored in [`Kura`] on top of
+    /// the closest snapshot kept according to [`Self::with_history_depth`].
+    ///
+    /// # Errors
+    /// - If there is no block at `height`
+    /// - If `height` is deeper than the history depth
+    /// - If a committed transaction fails on replay
+    pub fn at_height<R>(
+        &self,
+        height: NonZeroU64,
+        f: impl FnOnce(&StateBlock<'_>) -> R,
+    ) -> Result<R, HistoryError> {
+        if height.get() > self.view().height() {
+            return Err(HistoryError::NoBlock(height.get()));
+        }
+
+        let base = self.history_base(height.get())?;
+        let mut state_block = base.block();
+        for block_height in state_block.height() + 1..=height.get() {
+            self.replay_block(&mut state_block, block_height)?;
+        }
+
+        Ok(f(&state_block))
+    }
+
+    /// Reconstruct the events emitted for the blocks stored in [`Kura`]
+    /// starting from `from_height` by replaying them like [`Self::at_height`] does.
+    ///
+    /// `f` is called for each block with its height and its events in the order of emission:
+    /// pipeline events of the block validation and commit followed by the events of its application.
+    /// Replay stops at the latest block at the moment of the call, or as soon as `f` returns `false`.
+    ///
+    /// # Errors
+    /// - If the block before `from_height` is deeper than the history depth
+    /// - If a committed transaction fails on replay
+    pub fn replay_events(
+        &self,
+        from_height: NonZeroU64,
+        mut f: impl FnMut(NonZeroU64, Vec<EventBox>) -> bool,
+    ) -> Result<(), HistoryError> {
+        let height = self.view().height();
+        if from_height.get() > height {
+            return Ok(());
+        }
+
+        let base = self.history_base(from_height.get() - 1)?;
+        let mut state_block = base.block();
+        for block_height in state_block.height() + 1..=height {
+            let (block, events) = self.replay_block(&mut state_block, block_height)?;
+            if block_height < from_height.get() {
+                continue;
+            }
+
+            let events = block
+                .pipeline_events()
+                .map(EventBox::from)
+                .chain(events)
+                .collect();
+            let block_height = NonZeroU64::new(block_height).expect("Block height can't be zero");
+            if !f(block_height, events) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// State to reconstruct the state at `height` from: the closest snapshot at or below it
+    /// or, while `height` is within the history depth from genesis, the state before genesis.
+    fn history_base(&self, height: u64) -> Result<Arc<State>, HistoryError> {
+        let latest_height = self.view().height();
+        let history = self
+            .history
+            .as_ref()
+            .filter(|history| latest_height.saturating_sub(height) <= history.depth.get())
+            .ok_or(HistoryError::NotKept(height))?;
+
+        if let Some(snapshot) = history.closest(height) {
+            return Ok(snapshot);
+        }
+        if height > history.depth.get() {
+            return Err(HistoryError::NotKept(height));
+        }
+
+        let config = *self.view().config();
+        let genesis = self
+            .kura
+            .get_block_by_height(1)
+            .ok_or(HistoryError::NoBlock(1))?;
+        let genesis_public_key = genesis
+            .transactions()
+            .next()
+            .ok_or(HistoryError::NoBlock(1))?
+            .as_ref()
+            .authority()
+            .signatory()
+            .clone();
+        let world = World::with([genesis_domain(genesis_public_key)], PeersIds::new());
+        Ok(Arc::new(State::from_config(
+            config,
+            world,
+            Arc::clone(&self.kura),
+            self.query_handle.clone(),
+        )))
+    }
+
+    /// Replay the block at `height` stored in [`Kura`] on top of `state_block`
+    fn replay_block(
+        &self,
+        state_block: &mut StateBlock<'_>,
+        height: u64,
+    ) -> Result<(CommittedBlock, Vec<EventBox>), HistoryError> {
+        let block = self
+            .kura
+            .get_block_by_height(height)
+            .ok_or(HistoryError::NoBlock(height))?;
+        let block = CommittedBlock::replay(SignedBlock::clone(&block), state_block)
+            .map_err(|(hash, reason)| HistoryError::Replay { hash, reason })?;
+        let events = state_block.apply_without_execution(&block);
+        Ok((block, events))
+    }
+}
+
+/// Error of reconstructing the [`State`] at a past height
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum HistoryError {
+    /// There is no block at height {0}
+    NoBlock(u64),
+    /// State at height {0} is deeper than the history kept by this peer
+    NotKept(u64),
+    /// Committed transaction {hash} failed on replay
+    Replay {
+        /// Hash of the transaction
+        hash: HashOf<SignedTransaction>,
+        /// Reason of the failure
+        #[source]
+        reason: TransactionRejectionReason,
+    },
+}
+
+/// Snapshots of the [`State`] the states at the latest `depth` heights are reconstructed from
+struct History {
+    /// Number of the latest heights served
+    depth: NonZeroU64,
+    /// Reference to Kura subsystem for the snapshots
+    kura: Arc<Kura>,
+    /// Snapshots with their heights in ascending order
+    snapshots: Mutex<Vec<(u64, Arc<State>)>>,
+    /// Notified on the commit of a block after which a snapshot is due
+    due: tokio::sync::Notify,
+}
+
+impl History {
+    /// Check if the state after the block at `height` should be snapshotted
+    fn is_due(&self, height: u64) -> bool {
+        let depth = self.depth.get();
+        // While catching up with the blocks stored in Kura
+        // the snapshot would be dropped before it's needed
+        height % depth == 0 && self.kura.get_block_hash(height + 2 * depth).is_none()
+    }
+
+    /// Copy `state` not sharing storage with it
+    fn snapshot(&self, state: &impl StateReadOnly) -> State {
+        State::copy_of(state, Arc::clone(&self.kura))
+    }
+
+    /// Store the `snapshot` of the state at `height`
+    /// dropping the snapshots it supersedes and the ones not needed anymore
+    fn push(&self, height: u64, snapshot: State) {
+        let depth = self.depth.get();
+        let mut snapshots = self.snapshots.lock();
+        snapshots.retain(|(kept, _)| *kept < height && *kept + 2 * depth > height);
+        snapshots.push((height, Arc::new(snapshot)));
+    }
+
+    /// Find the latest snapshot at or below `height`
+    fn closest(&self, height: u64) -> Option<Arc<State>> {
+        self.snapshots
+            .lock()
+            .iter()
+            .rev()
+            .find(|(kept, _)| *kept <= height)
+            .map(|(_, snapshot)| Arc::clone(snapshot))
+    }
+}
+
+/// Genesis domain with the genesis account which every [`World`] starts with.
+fn genesis_domain(public_key: PublicKey) -> Domain {
+    let genesis_account_id = AccountId::new(iroha_genesis::GENESIS_DOMAIN_ID.clone(), public_key);
+    let genesis_account = Account::new(genesis_account_id.clone()).build(&genesis_account_id);
+    let mut domain =
+        Domain::new(iroha_genesis::GENESIS_DOMAIN_ID.clone()).build(&genesis_account_id);
+    domain.accounts.insert(genesis_account_id, genesis_account);
+    domain
 }
 
 /// Trait to perform read-only operations on [`StateBlock`], [`StateTransaction`] and [`StateView`]
@@ -991,6 +1977,7 @@ pub trait StateReadOnly {
     fn config(&self) -> &Config;
     fn block_hashes(&self) -> &[HashOf<SignedBlock>];
     fn transactions(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, u64>;
+    fn transaction_payloads(&self) -> &impl StorageReadOnly<Hash, u64>;
     fn engine(&self) -> &wasmtime::Engine;
     fn kura(&self) -> &Kura;
     fn query_handle(&self) -> &LiveQueryStoreHandle;
@@ -1096,6 +2083,13 @@ pub trait StateReadOnly {
         self.transactions().get(&hash).is_some()
     }
 
+    /// Check if a transaction with the payload of the given hash is already committed or rejected,
+    /// whatever signatures it was committed with. See [`SignedTransaction::hash_of_payload`].
+    #[inline]
+    fn has_transaction_payload(&self, hash: Hash) -> bool {
+        self.transaction_payloads().get(&hash).is_some()
+    }
+
     /// Get transaction executor
     fn transaction_executor(&self) -> TransactionExecutor {
         TransactionExecutor::new(self.config().transaction_limits)
@@ -1117,6 +2111,9 @@ macro_rules! impl_state_ro {
             fn transactions(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, u64> {
                 &self.transactions
             }
+            fn transaction_payloads(&self) -> &impl StorageReadOnly<Hash, u64> {
+                &self.transaction_payloads
+            }
             fn engine(&self) -> &wasmtime::Engine {
                 &self.engine
             }
@@ -1145,6 +2142,7 @@ impl<'state> StateBlock<'state> {
             config: self.config.transaction(),
             block_hashes: self.block_hashes.transaction(),
             transactions: self.transactions.transaction(),
+            transaction_payloads: self.transaction_payloads.transaction(),
             engine: self.engine,
             kura: self.kura,
             query_handle: self.query_handle,
@@ -1154,10 +2152,19 @@ impl<'state> StateBlock<'state> {
 
     /// Commit changes aggregated during application of block
     pub fn commit(self) {
+        let height = self.height();
+        let history = self.history;
+
         self.transactions.commit();
+        self.transaction_payloads.commit();
         self.block_hashes.commit();
         self.config.commit();
         self.world.commit();
+
+        // The snapshot is taken by `State::keep_history` off the commit path
+        if let Some(history) = history.filter(|history| history.is_due(height)) {
+            history.due.notify_one();
+        }
     }
 
     /// Commit `CommittedBlock` with changes in form of **Iroha Special
@@ -1221,9 +2228,10 @@ impl<'state> StateBlock<'state> {
             .as_ref()
             .transactions()
             .map(|tx| &tx.value)
-            .map(SignedTransaction::hash)
-            .for_each(|tx_hash| {
-                self.transactions.insert(tx_hash, block_height);
+            .for_each(|tx| {
+                self.transactions.insert(tx.hash(), block_height);
+                self.transaction_payloads
+                    .insert(tx.hash_of_payload(), block_height);
             });
 
         self.world.triggers.handle_time_event(time_event);
@@ -1238,6 +2246,7 @@ impl<'state> StateBlock<'state> {
         }
 
         self.block_hashes.push(block_hash);
+        self.world.update_state_tree();
 
         self.apply_parameters();
         self.world.events_buffer.push(
@@ -1355,6 +2364,7 @@ impl StateTransaction<'_, '_> {
     /// Apply transaction making it's changes visible
     pub fn apply(self) {
         self.transactions.apply();
+        self.transaction_payloads.apply();
         self.block_hashes.apply();
         self.config.apply();
         self.world.apply();
@@ -1642,7 +2652,11 @@ pub(crate) mod deserialize {
                         executor_data_model: executor_data_model.ok_or_else(|| {
                             serde::de::Error::missing_field("executor_data_model")
                         })?,
-                    })
+                        state_keys: Storage::new(),
+                        state_leaves: Storage::new(),
+                        state_nodes: Storage::new(),
+                    }
+                    .with_state_tree())
                 }
             }
 
@@ -1698,6 +2712,7 @@ pub(crate) mod deserialize {
                     let mut config = None;
                     let mut block_hashes = None;
                     let mut transactions = None;
+                    let mut transaction_payloads = None;
 
                     let engine = wasm::create_engine();
 
@@ -1720,6 +2735,9 @@ pub(crate) mod deserialize {
                             "transactions" => {
                                 transactions = Some(map.next_value()?);
                             }
+                            "transaction_payloads" => {
+                                transaction_payloads = Some(map.next_value()?);
+                            }
                             _ => { /* Skip unknown fields */ }
                         }
                     }
@@ -1731,17 +2749,27 @@ pub(crate) mod deserialize {
                             .ok_or_else(|| serde::de::Error::missing_field("block_hashes"))?,
                         transactions: transactions
                             .ok_or_else(|| serde::de::Error::missing_field("transactions"))?,
+                        transaction_payloads: transaction_payloads.ok_or_else(|| {
+                            serde::de::Error::missing_field("transaction_payloads")
+                        })?,
                         kura: self.loader.kura,
                         query_handle: self.loader.query_handle,
                         engine,
                         new_tx_amounts: Arc::new(Mutex::new(Vec::new())),
+                        history: None,
                     })
                 }
             }
 
             deserializer.deserialize_struct(
                 "WorldState",
-                &["world", "config", "block_hashes", "transactions"],
+                &[
+                    "world",
+                    "config",
+                    "block_hashes",
+                    "transactions",
+                    "transaction_payloads",
+                ],
                 StateVisitor { loader: self },
             )
         }
@@ -1844,4 +2872,96 @@ mod tests {
             assert_eq!(&role.account_id, &account_id);
         }
     }
+
+    #[tokio::test]
+    async fn state_proof() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        assert!(domain.add_account(account.clone()).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        let state_view = state.view();
+
+        let state_hash = state_view.world.state_hash().expect("World isn't empty");
+        let entry = StateEntry::Account(account);
+        let proof = state_view
+            .world
+            .state_proof(&entry)
+            .expect("Account is in the world");
+        assert!(proof.verify(&entry.path(), &HashOf::new(&entry), &state_hash));
+
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let missing_entry = StateEntry::Account(Account::new(bob_id.clone()).build(&bob_id));
+        assert!(state_view.world.state_proof(&missing_entry).is_none());
+    }
+
+    fn rebuilt_state_hash(
+        world: &impl WorldReadOnly,
+    ) -> Option<HashOf<SparseMerkleTree<StateEntry>>> {
+        let mut leaves = world
+            .state_entries()
+            .map(|entry| (entry.path(), HashOf::new(&entry)))
+            .collect::<Vec<_>>();
+        leaves.sort_unstable_by_key(|(path, _)| *path);
+
+        SparseMerkleTree::root(&leaves)
+    }
+
+    #[tokio::test]
+    async fn state_tree_is_updated_incrementally() {
+        let (alice_id, _alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let mut domain = Domain::new("wonderland".parse().unwrap()).build(&alice_id);
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], UniqueVec::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world, kura, query_handle);
+        assert_eq!(
+            state.view().world.state_hash(),
+            rebuilt_state_hash(&state.view().world)
+        );
+
+        let looking_glass_id: DomainId = "looking_glass".parse().unwrap();
+        let rose_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let alice_rose_id = AssetId::new(rose_id.clone(), alice_id.clone());
+        let blocks: [Vec<InstructionBox>; 2] = [
+            vec![
+                Register::domain(Domain::new(looking_glass_id.clone())).into(),
+                Register::account(Account::new(bob_id.clone())).into(),
+                Register::asset_definition(AssetDefinition::numeric(rose_id)).into(),
+                Mint::asset_numeric(13u32, alice_rose_id.clone()).into(),
+            ],
+            vec![
+                Transfer::asset_numeric(alice_rose_id, 3u32, bob_id).into(),
+                Unregister::account(alice_id.clone()).into(),
+                Unregister::domain(looking_glass_id).into(),
+            ],
+        ];
+        for (height, instructions) in (1..).zip(blocks) {
+            let mut state_block = state.block();
+            let mut state_transaction = state_block.transaction();
+            for instruction in instructions {
+                instruction
+                    .execute(&alice_id, &mut state_transaction)
+                    .expect("Instruction is valid");
+            }
+            state_transaction.apply();
+            let block = new_dummy_block_with_payload(|payload| {
+                payload.header.height = height;
+            });
+            let _events = state_block.apply(&block).unwrap();
+            state_block.commit();
+
+            let state_view = state.view();
+            assert_eq!(
+                state_view.world.state_hash(),
+                rebuilt_state_hash(&state_view.world)
+            );
+        }
+    }
 }
@@ -49,6 +49,8 @@ pub struct Sumeragi {
     pub transaction_cache: Vec<AcceptedTransaction>,
     /// Metrics for reporting number of view changes in current round
     pub view_changes_metric: iroha_telemetry::metrics::ViewChangesGauge,
+    /// Sender of the role of this peer whenever the topology changes
+    pub role_sender: tokio::sync::watch::Sender<Role>,
 }
 
 #[allow(clippy::missing_fields_in_debug)]
@@ -374,7 +376,8 @@ impl Sumeragi {
 
     fn cache_transaction(&mut self, state_block: &StateBlock<'_>) {
         self.transaction_cache.retain(|tx| {
-            !state_block.has_transaction(tx.as_ref().hash()) && !self.queue.is_expired(tx)
+            !state_block.has_transaction_payload(tx.as_ref().hash_of_payload())
+                && !self.queue.is_expired(tx)
         });
     }
 
@@ -881,6 +884,13 @@ pub(crate) fn run(
         let span_for_sumeragi_cycle = span!(Level::TRACE, "main_thread_cycle");
         let _enter_for_sumeragi_cycle = span_for_sumeragi_cycle.enter();
 
+        let role = sumeragi.current_topology.role(&sumeragi.peer_id);
+        sumeragi.role_sender.send_if_modified(|current| {
+            let modified = *current != role;
+            *current = role;
+            modified
+        });
+
         let state_view = state.view();
 
         sumeragi
@@ -1330,6 +1340,36 @@ mod tests {
         assert!(matches!(result, Err((_, BlockSyncError::BlockNotValid(_)))))
     }
 
+    #[test]
+    async fn block_sync_state_hash_mismatch() {
+        let chain_id = ChainId::from("0");
+
+        let leader_key_pair = KeyPair::random();
+        let topology = Topology::new(unique_vec![PeerId::new(
+            "127.0.0.1:8080".parse().unwrap(),
+            leader_key_pair.public_key().clone(),
+        )]);
+        let (state, _, block, genesis_public_key) =
+            create_data_for_test(&chain_id, &topology, &leader_key_pair);
+
+        // Malform block to make it invalid
+        let block = clone_and_modify_payload(&block, &leader_key_pair, |payload| {
+            payload.header.state_hash = None;
+        });
+
+        let result = handle_block_sync(&chain_id, &genesis_public_key, block, &state, &|_| {});
+        assert!(matches!(
+            result,
+            Err((
+                _,
+                BlockSyncError::BlockNotValid(BlockValidationError::StateHashMismatch {
+                    actual: None,
+                    ..
+                })
+            ))
+        ))
+    }
+
     #[test]
     async fn block_sync_invalid_soft_fork_block() {
         let chain_id = ChainId::from("0");
@@ -39,9 +39,15 @@ pub struct SumeragiHandle {
     // Should be dropped after `_thread_handle` to prevent sumeargi thread from panicking
     control_message_sender: mpsc::SyncSender<ControlFlowMessage>,
     message_sender: mpsc::SyncSender<BlockMessage>,
+    role_receiver: tokio::sync::watch::Receiver<Role>,
 }
 
 impl SumeragiHandle {
+    /// Role of this peer in the current topology
+    pub fn role(&self) -> Role {
+        *self.role_receiver.borrow()
+    }
+
     /// Deposit a sumeragi control flow network message.
     pub fn incoming_control_flow_message(&self, msg: ControlFlowMessage) {
         if let Err(error) = self.control_message_sender.try_send(msg) {
@@ -197,6 +203,8 @@ impl SumeragiHandle {
         let debug_force_soft_fork = false;
 
         let peer_id = common_config.peer_id();
+        let (role_sender, role_receiver) =
+            tokio::sync::watch::channel(current_topology.role(&peer_id));
 
         let sumeragi = main_loop::Sumeragi {
             chain_id: common_config.chain_id,
@@ -215,6 +223,7 @@ impl SumeragiHandle {
             current_topology,
             transaction_cache: Vec::new(),
             view_changes_metric: view_changes,
+            role_sender,
         };
 
         // Oneshot channel to allow forcefully stopping the thread.
@@ -241,6 +250,7 @@ impl SumeragiHandle {
             dropped_messages_metric: dropped_messages,
             control_message_sender,
             message_sender,
+            role_receiver,
             _thread_handle: Arc::new(thread_handle),
         }
     }
@@ -63,8 +63,11 @@ impl AcceptedTransaction {
             }));
         }
 
-        let signature = tx.0.signature();
-        if signature.public_key() != genesis_public_key {
+        if let Some(signature) =
+            tx.0.signatures()
+                .iter()
+                .find(|signature| signature.public_key() != genesis_public_key)
+        {
             return Err(SignatureVerificationFail {
                 signature: signature.clone().into(),
                 reason: "Signature doesn't correspond to genesis public key".to_string(),
@@ -137,6 +140,53 @@ impl AcceptedTransaction {
     fn len_u64(instruction_count: usize) -> u64 {
         u64::try_from(instruction_count).expect("`usize` should always fit into `u64`")
     }
+
+    /// Check that the transaction is signed only by signatories of its authority
+    /// and that their combined weight reaches the quorum of the authority.
+    ///
+    /// # Errors
+    ///
+    /// - if the authority account doesn't exist
+    /// - if the transaction is signed by a key which isn't a signatory of the authority
+    /// - if the combined weight of signatories doesn't reach the quorum
+    pub fn check_signatories(
+        &self,
+        world: &impl WorldReadOnly,
+    ) -> Result<(), TransactionRejectionReason> {
+        let authority = self.0.authority();
+        let account = world
+            .account(authority)
+            .map_err(TransactionRejectionReason::AccountDoesNotExist)?;
+        let signatories = || {
+            self.0
+                .signatures()
+                .iter()
+                .map(|signature| signature.public_key())
+        };
+
+        if let Some(public_key) =
+            signatories().find(|public_key| account.signatory_weight(public_key).is_none())
+        {
+            return Err(TransactionRejectionReason::SignatoryCheck(
+                SignatoryCheckFail {
+                    reason: format!("{public_key} isn't a signatory of {authority}"),
+                },
+            ));
+        }
+
+        if !account.is_quorum_reached(signatories()) {
+            return Err(TransactionRejectionReason::SignatoryCheck(
+                SignatoryCheckFail {
+                    reason: format!(
+                        "Combined weight of signatories doesn't reach the quorum of {}",
+                        account.quorum()
+                    ),
+                },
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 impl From<AcceptedTransaction> for SignedTransaction {
@@ -193,13 +243,62 @@ impl TransactionExecutor {
         Ok(tx.0)
     }
 
+    /// Run transaction against the [`StateBlock`] the same way [`Self::validate`] does,
+    /// returning the data events emitted and the fuel consumed by it.
+    ///
+    /// Signatories of the transaction are checked only if `check_signatories` is set,
+    /// so that transactions which aren't signed by their authority can be simulated too.
+    ///
+    /// Changes made by the transaction are applied to the `state_block`,
+    /// so it should be dropped without being committed afterwards.
+    ///
+    /// # Errors
+    /// Fails if the transaction would be rejected
+    pub fn simulate(
+        &self,
+        tx: AcceptedTransaction,
+        check_signatories: bool,
+        state_block: &mut StateBlock<'_>,
+    ) -> TransactionSimulationResult {
+        let mut state_transaction = state_block.transaction();
+        Self::check_authority_exists(tx.as_ref().authority(), &state_transaction)?;
+        if check_signatories {
+            tx.check_signatories(&state_transaction.world)?;
+        }
+        let fuel_consumed = self.validate_execution(tx, &mut state_transaction)?;
+        state_transaction.apply();
+
+        let events = state_block
+            .world
+            .take_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+
+        Ok(SimulatedTransaction {
+            events,
+            fuel_consumed,
+        })
+    }
+
     fn validate_internal(
         &self,
         tx: AcceptedTransaction,
         state_transaction: &mut StateTransaction<'_, '_>,
     ) -> Result<(), TransactionRejectionReason> {
-        let authority = tx.as_ref().authority();
+        Self::check_authority_exists(tx.as_ref().authority(), state_transaction)?;
+        tx.check_signatories(&state_transaction.world)?;
+        self.validate_execution(tx, state_transaction)
+            .map(|_fuel_consumed| ())
+    }
 
+    fn check_authority_exists(
+        authority: &AccountId,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), TransactionRejectionReason> {
         if !state_transaction
             .world
             .domain(&authority.domain_id)
@@ -216,15 +315,27 @@ impl TransactionExecutor {
             ));
         }
 
+        Ok(())
+    }
+
+    /// Validate and execute the instructions or the smart contract of the transaction.
+    /// Returns the amount of fuel consumed by the smart contract.
+    fn validate_execution(
+        &self,
+        tx: AcceptedTransaction,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<u64, TransactionRejectionReason> {
         debug!("Validating transaction: {:?}", tx);
         Self::validate_with_runtime_executor(tx.clone(), state_transaction)?;
 
-        if let (authority, Executable::Wasm(bytes)) = tx.into() {
+        let fuel_consumed = if let (authority, Executable::Wasm(bytes)) = tx.into() {
             self.validate_wasm(authority, state_transaction, bytes)?
-        }
+        } else {
+            0
+        };
 
         debug!("Validation successful");
-        Ok(())
+        Ok(fuel_consumed)
     }
 
     fn validate_wasm(
@@ -232,7 +343,7 @@ impl TransactionExecutor {
         authority: AccountId,
         state_transaction: &mut StateTransaction<'_, '_>,
         wasm: WasmSmartContract,
-    ) -> Result<(), TransactionRejectionReason> {
+    ) -> Result<u64, TransactionRejectionReason> {
         debug!("Validating wasm");
 
         wasm::RuntimeBuilder::<wasm::state::SmartContract>::new()
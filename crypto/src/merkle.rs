@@ -1,6 +1,7 @@
 //! Merkle tree implementation.
 #[cfg(not(feature = "std"))]
 use alloc::{collections::VecDeque, format, string::String, vec, vec::Vec};
+use core::marker::PhantomData;
 #[cfg(feature = "std")]
 use std::collections::VecDeque;
 
@@ -15,6 +16,35 @@ use crate::{Hash, HashOf};
 #[repr(transparent)]
 pub struct MerkleTree<T>(Vec<Option<HashOf<T>>>);
 
+/// Proof of inclusion of a leaf into [`MerkleTree`]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+pub struct MerkleProof<T> {
+    /// Index of the leaf in the order of insertion
+    leaf_index: u32,
+    /// Hashes of sibling nodes on the path from the leaf to the root
+    audit_path: Vec<Option<HashOf<T>>>,
+}
+
+/// Sparse Merkle tree of `T` keyed by 256-bit paths.
+///
+/// The root doesn't depend on the order in which leaves were inserted, so the tree can be
+/// kept up to date incrementally. Nodes are owned by the user of the tree, this type only
+/// defines how they are hashed: an empty subtree has no hash, a subtree with a single leaf
+/// is hashed with [`Self::leaf_hash`] and any other subtree with [`Self::node_hash`].
+#[derive(Debug, TypeId, Decode, Encode, Deserialize, Serialize)]
+pub struct SparseMerkleTree<T>(PhantomData<T>);
+
+/// Proof of inclusion of a leaf into [`SparseMerkleTree`]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+pub struct SparseMerkleProof<T> {
+    /// Hashes of sibling subtrees on the path from the root to the leaf
+    audit_path: Vec<Option<HashOf<SparseMerkleTree<T>>>>,
+}
+
 /// Iterator over leaves of [`MerkleTree`]
 pub struct LeafHashIterator<T> {
     tree: MerkleTree<T>,
@@ -134,6 +164,19 @@ impl<T: IntoSchema> IntoSchema for MerkleTree<T> {
     }
 }
 
+impl<T: IntoSchema> IntoSchema for SparseMerkleTree<T> {
+    fn type_name() -> String {
+        format!("SparseMerkleTree<{}>", T::type_name())
+    }
+    fn update_schema_map(map: &mut iroha_schema::MetaMap) {
+        if !map.contains_key::<Self>() {
+            map.insert::<Self>(iroha_schema::Metadata::Tuple(
+                iroha_schema::UnnamedFieldsMeta { types: Vec::new() },
+            ));
+        }
+    }
+}
+
 impl<T> Default for MerkleTree<T> {
     fn default() -> Self {
         Self::new()
@@ -159,6 +202,29 @@ impl<T> MerkleTree<T> {
         None
     }
 
+    /// Get the proof of inclusion of the `idx`-th leaf.
+    pub fn get_proof(&self, idx: usize) -> Option<MerkleProof<T>> {
+        let leaf_index = u32::try_from(idx).ok()?;
+        self.get_leaf_hash(idx)?;
+
+        let mut node_idx = 2_usize.pow(self.height()) - 1 + idx;
+        let mut audit_path = Vec::new();
+        while let Some(parent_idx) = self.parent(node_idx) {
+            let sibling_idx = match node_idx % 2 {
+                0 => node_idx - 1,
+                1 => node_idx + 1,
+                _ => unreachable!(),
+            };
+            audit_path.push(self.get(sibling_idx).copied().flatten());
+            node_idx = parent_idx;
+        }
+
+        Some(MerkleProof {
+            leaf_index,
+            audit_path,
+        })
+    }
+
     /// Add `hash` to the tail of the tree.
     pub fn add(&mut self, hash: HashOf<T>) {
         // If the tree is perfect, increment its height to double the leaf capacity.
@@ -225,6 +291,123 @@ impl<T> MerkleTree<T> {
     }
 }
 
+impl<T> SparseMerkleTree<T> {
+    /// Depth of the tree, i.e. the length of leaf paths in bits.
+    pub const DEPTH: usize = Hash::LENGTH * 8;
+
+    /// Hash of the subtree which only contains `leaf_hash` at `path`.
+    pub fn leaf_hash(path: &[u8; Hash::LENGTH], leaf_hash: &HashOf<T>) -> HashOf<Self> {
+        // NOTE: Leaves encode to 64 bytes while nodes encode to 2, 34 or 66 bytes
+        // depending on which children they have, so the two can't collide
+        HashOf::from_untyped_unchecked(Hash::new((path, leaf_hash).encode()))
+    }
+
+    /// Hash of the subtree with `left` and `right` children which contains more than one leaf.
+    pub fn node_hash(left: Option<&HashOf<Self>>, right: Option<&HashOf<Self>>) -> HashOf<Self> {
+        HashOf::from_untyped_unchecked(Hash::new((left, right).encode()))
+    }
+
+    /// Whether `path` goes to the right child of the node at `depth`.
+    ///
+    /// # Panics
+    /// If `depth` isn't less than [`Self::DEPTH`]
+    pub fn is_right(path: &[u8; Hash::LENGTH], depth: usize) -> bool {
+        path[depth / 8] & (0x80 >> (depth % 8)) != 0
+    }
+
+    /// Compute the root hash of the tree from scratch.
+    ///
+    /// `leaves` must be sorted by their paths and the paths must be unique.
+    pub fn root(leaves: &[([u8; Hash::LENGTH], HashOf<T>)]) -> Option<HashOf<Self>> {
+        Self::subtree_hash(leaves, 0)
+    }
+
+    fn subtree_hash(
+        leaves: &[([u8; Hash::LENGTH], HashOf<T>)],
+        depth: usize,
+    ) -> Option<HashOf<Self>> {
+        match leaves {
+            [] => None,
+            [(path, leaf_hash)] => Some(Self::leaf_hash(path, leaf_hash)),
+            _ => {
+                let (left, right) = leaves
+                    .split_at(leaves.partition_point(|(path, _)| !Self::is_right(path, depth)));
+                Some(Self::node_hash(
+                    Self::subtree_hash(left, depth + 1).as_ref(),
+                    Self::subtree_hash(right, depth + 1).as_ref(),
+                ))
+            }
+        }
+    }
+}
+
+impl<T> SparseMerkleProof<T> {
+    /// Construct [`SparseMerkleProof`] from hashes of sibling subtrees on the path from the root to the leaf.
+    pub fn new(audit_path: Vec<Option<HashOf<SparseMerkleTree<T>>>>) -> Self {
+        Self { audit_path }
+    }
+
+    /// Hashes of sibling subtrees on the path from the root to the leaf.
+    pub fn audit_path(&self) -> &[Option<HashOf<SparseMerkleTree<T>>>] {
+        &self.audit_path
+    }
+
+    /// Check that `leaf_hash` is included into the tree with `root_hash` at `path`.
+    pub fn verify(
+        &self,
+        path: &[u8; Hash::LENGTH],
+        leaf_hash: &HashOf<T>,
+        root_hash: &HashOf<SparseMerkleTree<T>>,
+    ) -> bool {
+        if self.audit_path.len() > SparseMerkleTree::<T>::DEPTH {
+            return false;
+        }
+
+        let mut node = SparseMerkleTree::leaf_hash(path, leaf_hash);
+        for (depth, sibling) in self.audit_path.iter().enumerate().rev() {
+            node = if SparseMerkleTree::<T>::is_right(path, depth) {
+                SparseMerkleTree::node_hash(sibling.as_ref(), Some(&node))
+            } else {
+                SparseMerkleTree::node_hash(Some(&node), sibling.as_ref())
+            };
+        }
+
+        node == *root_hash
+    }
+}
+
+impl<T> MerkleProof<T> {
+    /// Index of the leaf in the order of insertion.
+    pub fn leaf_index(&self) -> u32 {
+        self.leaf_index
+    }
+
+    /// Hashes of sibling nodes on the path from the leaf to the root.
+    pub fn audit_path(&self) -> &[Option<HashOf<T>>] {
+        &self.audit_path
+    }
+
+    /// Check that `leaf_hash` is included into the tree with `root_hash`.
+    pub fn verify(&self, leaf_hash: &HashOf<T>, root_hash: &HashOf<MerkleTree<T>>) -> bool {
+        let mut idx = self.leaf_index;
+        let mut node = Some(*leaf_hash);
+        for sibling in &self.audit_path {
+            let (l_node, r_node) = match idx % 2 {
+                0 => (node, *sibling),
+                1 => (*sibling, node),
+                _ => unreachable!(),
+            };
+            if l_node.is_none() && r_node.is_some() {
+                return false;
+            }
+            node = MerkleTree::nodes_pair_hash(l_node.as_ref(), r_node.as_ref());
+            idx /= 2;
+        }
+
+        idx == 0 && node.map(HashOf::transmute).as_ref() == Some(root_hash)
+    }
+}
+
 impl<T> Iterator for LeafHashIterator<T> {
     type Item = HashOf<T>;
 
@@ -312,4 +495,84 @@ mod tests {
             assert_eq!(testee_leaf, tester_leaf);
         }
     }
+
+    #[test]
+    fn proof() {
+        for n_leaves in 1..=9 {
+            let hashes = test_hashes(n_leaves);
+            let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+            let root_hash = tree.hash().expect("Tree isn't empty");
+
+            for (idx, leaf_hash) in hashes.iter().enumerate() {
+                let proof = tree.get_proof(idx).expect("Leaf exists");
+                assert!(proof.verify(leaf_hash, &root_hash));
+
+                let other_leaf_hash = &hashes[(idx + 1) % hashes.len()];
+                if other_leaf_hash != leaf_hash {
+                    assert!(!proof.verify(other_leaf_hash, &root_hash));
+                }
+            }
+            assert!(tree.get_proof(n_leaves as usize).is_none());
+        }
+    }
+
+    fn sparse_proof(
+        leaves: &[([u8; Hash::LENGTH], HashOf<()>)],
+        path: &[u8; Hash::LENGTH],
+    ) -> SparseMerkleProof<()> {
+        let mut leaves = leaves;
+        let mut audit_path = Vec::new();
+        while leaves.len() > 1 {
+            let depth = audit_path.len();
+            let (left, right) = leaves.split_at(
+                leaves.partition_point(|(p, _)| !SparseMerkleTree::<()>::is_right(p, depth)),
+            );
+            let (subtree, sibling) = if SparseMerkleTree::<()>::is_right(path, depth) {
+                (right, left)
+            } else {
+                (left, right)
+            };
+            audit_path.push(SparseMerkleTree::subtree_hash(sibling, depth + 1));
+            leaves = subtree;
+        }
+        SparseMerkleProof::new(audit_path)
+    }
+
+    #[test]
+    fn sparse_proof_verifies() {
+        for n_leaves in 1..=9 {
+            let mut leaves = test_hashes(n_leaves)
+                .into_iter()
+                .map(|leaf_hash| (*Hash::new(leaf_hash.as_ref()).as_ref(), leaf_hash))
+                .collect::<Vec<_>>();
+            leaves.sort();
+            let root_hash = SparseMerkleTree::root(&leaves).expect("Tree isn't empty");
+
+            for (idx, (path, leaf_hash)) in leaves.iter().enumerate() {
+                let proof = sparse_proof(&leaves, path);
+                assert!(proof.verify(path, leaf_hash, &root_hash));
+
+                let (other_path, other_leaf_hash) = &leaves[(idx + 1) % leaves.len()];
+                if other_leaf_hash != leaf_hash {
+                    assert!(!proof.verify(path, other_leaf_hash, &root_hash));
+                    assert!(!proof.verify(other_path, leaf_hash, &root_hash));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sparse_root_changes_with_leaves() {
+        let mut leaves = test_hashes(4)
+            .into_iter()
+            .map(|leaf_hash| (*Hash::new(leaf_hash.as_ref()).as_ref(), leaf_hash))
+            .collect::<Vec<_>>();
+        leaves.sort();
+        let root_hash = SparseMerkleTree::root(&leaves);
+
+        assert_ne!(SparseMerkleTree::root(&leaves[1..]), root_hash);
+        leaves[0].1 = leaves[1].1;
+        assert_ne!(SparseMerkleTree::root(&leaves), root_hash);
+        assert!(SparseMerkleTree::<()>::root(&[]).is_none());
+    }
 }
@@ -6,7 +6,7 @@ use core::str::FromStr;
 use std::collections::btree_map;
 
 use derive_more::{Constructor, DebugCustom, Display};
-use getset::Getters;
+use getset::{CopyGetters, Getters};
 use iroha_data_model_derive::{model, IdEqOrdHash};
 use iroha_schema::IntoSchema;
 use parity_scale_codec::{Decode, Encode};
@@ -31,8 +31,10 @@ pub type AccountsMap = btree_map::BTreeMap<AccountId, Account>;
 mod model {
     use super::*;
 
-    /// Identification of [`Account`] by the combination of the [`PublicKey`] as its sole signatory and the [`Domain`](crate::domain::Domain) it belongs to.
-    /// TODO #4373 include multi-signatory use.
+    /// Identification of [`Account`] by the combination of the [`PublicKey`] as its initial signatory and the [`Domain`](crate::domain::Domain) it belongs to.
+    ///
+    /// Accounts controlled by several keys keep this identifier,
+    /// the actual set of signatories is described by [`SignatoryPolicy`].
     ///
     /// # Examples
     ///
@@ -68,10 +70,39 @@ mod model {
     pub struct AccountId {
         /// [`Domain`](crate::domain::Domain) that the [`Account`] belongs to.
         pub domain_id: DomainId,
-        /// Sole signatory of the [`Account`].
+        /// Initial signatory of the [`Account`].
         pub signatory: PublicKey,
     }
 
+    /// Weighted set of keys authorized to sign transactions on behalf of an [`Account`].
+    ///
+    /// Transaction is authorized once the combined weight of its signatories reaches the `quorum`.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Getters,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct SignatoryPolicy {
+        /// Signatories of the account mapped to their weights.
+        #[getset(get = "pub")]
+        pub signatories: btree_map::BTreeMap<PublicKey, u8>,
+        /// Minimal combined weight of signatories required to authorize a transaction.
+        #[getset(get_copy = "pub")]
+        pub quorum: u16,
+    }
+
     /// Account entity is an authority which is used to execute `Iroha Special Instructions`.
     #[derive(
         Debug,
@@ -95,6 +126,9 @@ mod model {
         pub assets: AssetsMap,
         /// Metadata of this account as a key-value store.
         pub metadata: Metadata,
+        /// Signatories of this account. If absent, the signatory from the account id is the only one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub signatory_policy: Option<SignatoryPolicy>,
     }
 
     /// Builder which should be submitted in a transaction to create a new [`Account`]
@@ -109,6 +143,38 @@ mod model {
         pub id: AccountId,
         /// Metadata that should be submitted with the builder
         pub metadata: Metadata,
+        /// Signatories of the account that should be submitted with the builder
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub signatory_policy: Option<SignatoryPolicy>,
+    }
+}
+
+impl SignatoryPolicy {
+    /// Construct [`Self`] from signatories with their weights and the quorum.
+    #[inline]
+    pub fn new(signatories: impl IntoIterator<Item = (PublicKey, u8)>, quorum: u16) -> Self {
+        Self {
+            signatories: signatories.into_iter().collect(),
+            quorum,
+        }
+    }
+
+    /// Construct [`Self`] where the given key is the only signatory.
+    #[inline]
+    pub fn single(signatory: PublicKey) -> Self {
+        Self::new([(signatory, 1)], 1)
+    }
+
+    /// Return the weight of the given key or `None` if it isn't a signatory.
+    #[inline]
+    pub fn weight(&self, public_key: &PublicKey) -> Option<u8> {
+        self.signatories.get(public_key).copied()
+    }
+
+    /// Return `true` if the quorum is non-zero and can be reached by signatories.
+    pub fn is_reachable(&self) -> bool {
+        let total_weight: u32 = self.signatories.values().copied().map(u32::from).sum();
+        self.quorum > 0 && total_weight >= u32::from(self.quorum)
     }
 }
 
@@ -146,6 +212,45 @@ impl Account {
     pub fn assets(&self) -> impl ExactSizeIterator<Item = &Asset> {
         self.assets.values()
     }
+
+    /// Return a reference to the [`SignatoryPolicy`] of the `Account` if it's set.
+    #[inline]
+    pub fn signatory_policy(&self) -> Option<&SignatoryPolicy> {
+        self.signatory_policy.as_ref()
+    }
+
+    /// Return the weight of the given key or `None` if it isn't a signatory of the `Account`.
+    pub fn signatory_weight(&self, public_key: &PublicKey) -> Option<u8> {
+        self.signatory_policy.as_ref().map_or_else(
+            || (self.id.signatory == *public_key).then_some(1),
+            |policy| policy.weight(public_key),
+        )
+    }
+
+    /// Minimal combined weight of signatories required to authorize a transaction.
+    #[inline]
+    pub fn quorum(&self) -> u16 {
+        self.signatory_policy
+            .as_ref()
+            .map_or(1, SignatoryPolicy::quorum)
+    }
+
+    /// Return `true` if the combined weight of the given keys reaches the quorum.
+    ///
+    /// Keys which aren't signatories of the `Account` don't contribute to the weight.
+    #[allow(single_use_lifetimes)] // TODO: remove when anonymous lifetimes are stable
+    pub fn is_quorum_reached<'key>(
+        &self,
+        public_keys: impl IntoIterator<Item = &'key PublicKey>,
+    ) -> bool {
+        let weight: u32 = public_keys
+            .into_iter()
+            .filter_map(|public_key| self.signatory_weight(public_key))
+            .map(u32::from)
+            .sum();
+
+        weight >= u32::from(self.quorum())
+    }
 }
 
 #[cfg(feature = "transparent_api")]
@@ -168,6 +273,7 @@ impl NewAccount {
         Self {
             id,
             metadata: Metadata::default(),
+            signatory_policy: None,
         }
     }
 
@@ -178,6 +284,20 @@ impl NewAccount {
         self.metadata = metadata;
         self
     }
+
+    /// Add [`SignatoryPolicy`] to the account making it controlled by the given signatories
+    #[inline]
+    #[must_use]
+    pub fn with_signatory_policy(mut self, signatory_policy: SignatoryPolicy) -> Self {
+        self.signatory_policy = Some(signatory_policy);
+        self
+    }
+
+    /// Return a reference to the [`SignatoryPolicy`] submitted with the builder if it's set.
+    #[inline]
+    pub fn signatory_policy(&self) -> Option<&SignatoryPolicy> {
+        self.signatory_policy.as_ref()
+    }
 }
 
 #[cfg(feature = "transparent_api")]
@@ -188,6 +308,7 @@ impl NewAccount {
             id: self.id,
             assets: AssetsMap::default(),
             metadata: self.metadata,
+            signatory_policy: self.signatory_policy,
         }
     }
 }
@@ -237,7 +358,7 @@ impl FromStr for AccountId {
 
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Account, AccountId};
+    pub use super::{Account, AccountId, SignatoryPolicy};
 }
 
 #[cfg(test)]
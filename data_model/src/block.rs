@@ -9,7 +9,7 @@ use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::{fmt::Display, time::Duration};
 
 use derive_more::Display;
-use iroha_crypto::{HashOf, MerkleTree, SignaturesOf};
+use iroha_crypto::{Hash, HashOf, MerkleTree, SignaturesOf, SparseMerkleTree};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_primitives::unique_vec::UniqueVec;
@@ -19,7 +19,18 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::{events::prelude::*, peer, transaction::prelude::*};
+use crate::{
+    account::{Account, AccountId},
+    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
+    domain::{Domain, DomainId},
+    events::prelude::*,
+    parameter::{Parameter, ParameterId},
+    peer,
+    permission::Permission,
+    role::{Role, RoleId},
+    transaction::prelude::*,
+    trigger::{Trigger, TriggerId},
+};
 
 #[model]
 mod model {
@@ -60,6 +71,13 @@ mod model {
         /// Hash of merkle tree root of transactions' hashes.
         #[getset(get = "pub")]
         pub transactions_hash: Option<HashOf<MerkleTree<SignedTransaction>>>,
+        /// Hash of sparse merkle tree root of world state entries after the previous block was applied.
+        ///
+        /// The header is signed before the block is applied, as the block is only applied on commit,
+        /// along with the triggers it sets off. So each block commits to the state it's applied to,
+        /// and the state after a block is committed to by the header of the next one.
+        #[getset(get = "pub")]
+        pub state_hash: Option<HashOf<SparseMerkleTree<StateEntry>>>,
         /// Creation timestamp (unix time in milliseconds).
         #[getset(skip)]
         pub timestamp_ms: u64,
@@ -97,6 +115,74 @@ mod model {
         pub event_recommendations: Vec<EventBox>,
     }
 
+    /// Entry of the world state committed to by [`BlockHeader::state_hash`].
+    ///
+    /// Nested collections (accounts and asset definitions of a domain, assets of an account)
+    /// are committed to as separate entries and are left empty in the parent entry.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub enum StateEntry {
+        /// Domain without its accounts and asset definitions
+        Domain(Domain),
+        /// Asset definition
+        AssetDefinition(AssetDefinition),
+        /// Account without its assets
+        Account(Account),
+        /// Asset
+        Asset(Asset),
+        /// Role
+        Role(Role),
+        /// Permission granted to an account
+        AccountPermission(AccountPermission),
+        /// Role granted to an account
+        AccountRole(AccountRole),
+        /// Trigger
+        Trigger(Trigger),
+        /// Chain-wide parameter
+        Parameter(Parameter),
+    }
+
+    /// [`Permission`] granted to an account directly
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct AccountPermission {
+        /// Account which has the permission
+        pub account_id: AccountId,
+        /// Granted permission
+        pub permission: Permission,
+    }
+
+    /// [`Role`] granted to an account
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct AccountRole {
+        /// Account which has the role
+        pub account_id: AccountId,
+        /// Granted role
+        pub role_id: RoleId,
+    }
+
     /// Signed block
     #[version_with_scale(version = 1, versioned_alias = "SignedBlock")]
     #[derive(
@@ -132,6 +218,49 @@ impl BlockHeader {
     }
 }
 
+impl StateEntry {
+    /// Path of the entry in the sparse merkle tree committed to by [`BlockHeader::state_hash`].
+    ///
+    /// The path only depends on the identifier of the entry.
+    pub fn path(&self) -> [u8; Hash::LENGTH] {
+        let id = match self {
+            Self::Domain(domain) => StateEntryId::Domain(&domain.id),
+            Self::AssetDefinition(asset_definition) => {
+                StateEntryId::AssetDefinition(&asset_definition.id)
+            }
+            Self::Account(account) => StateEntryId::Account(&account.id),
+            Self::Asset(asset) => StateEntryId::Asset(&asset.id),
+            Self::Role(role) => StateEntryId::Role(&role.id),
+            Self::AccountPermission(AccountPermission {
+                account_id,
+                permission,
+            }) => StateEntryId::AccountPermission(account_id, permission),
+            Self::AccountRole(AccountRole {
+                account_id,
+                role_id,
+            }) => StateEntryId::AccountRole(account_id, role_id),
+            Self::Trigger(trigger) => StateEntryId::Trigger(&trigger.id),
+            Self::Parameter(parameter) => StateEntryId::Parameter(&parameter.id),
+        };
+
+        *Hash::new(id.encode()).as_ref()
+    }
+}
+
+/// Identifier of [`StateEntry`] from which its path is derived
+#[derive(Encode)]
+enum StateEntryId<'entry> {
+    Domain(&'entry DomainId),
+    AssetDefinition(&'entry AssetDefinitionId),
+    Account(&'entry AccountId),
+    Asset(&'entry AssetId),
+    Role(&'entry RoleId),
+    AccountPermission(&'entry AccountId, &'entry Permission),
+    AccountRole(&'entry AccountId, &'entry RoleId),
+    Trigger(&'entry TriggerId),
+    Parameter(&'entry ParameterId),
+}
+
 impl SignedBlockV1 {
     /// Create new signed block, using `key_pair` to sign `payload`
     #[cfg(feature = "transparent_api")]
@@ -317,6 +317,7 @@ mod account {
             MetadataInserted(AccountMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target_id)]
             MetadataRemoved(AccountMetadataChanged),
+            SignatoryRotated(AccountId),
         }
     }
 
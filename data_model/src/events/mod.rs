@@ -322,7 +322,9 @@ mod conversions {
 pub mod stream {
     //! Structures related to event streaming over HTTP
 
-    use derive_more::Constructor;
+    use core::num::NonZeroU64;
+
+    use getset::{CopyGetters, Getters};
     use iroha_data_model_derive::model;
     use iroha_version::prelude::*;
 
@@ -333,22 +335,97 @@ pub mod stream {
     mod model {
         use super::*;
 
+        /// Position of an event in the blockchain.
+        ///
+        /// Events emitted while a block is validated, committed and applied
+        /// are numbered from zero in the order of emission.
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            CopyGetters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get_copy = "pub")]
+        pub struct EventCursor {
+            /// Height of the block the event belongs to.
+            pub height: NonZeroU64,
+            /// Index of the event among the events of the block.
+            pub index: u64,
+        }
+
         /// Message sent by the stream producer.
         /// Event sent by the peer.
-        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-        #[repr(transparent)]
-        pub struct EventMessage(pub EventBox);
+        #[derive(
+            Debug, Clone, Getters, CopyGetters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        )]
+        pub struct EventMessage {
+            /// Event emitted by the peer.
+            #[getset(get = "pub")]
+            pub event: EventBox,
+            /// Position of the event in the blockchain,
+            /// `None` for events which don't belong to a committed block
+            /// (e.g. queued or expired transactions, rejected blocks).
+            #[getset(get_copy = "pub")]
+            pub cursor: Option<EventCursor>,
+        }
 
         /// Message sent by the stream consumer.
         /// Request sent by the client to subscribe to events.
-        #[derive(Debug, Clone, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-        #[repr(transparent)]
-        pub struct EventSubscriptionRequest(pub Vec<EventFilterBox>);
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct EventSubscriptionRequest {
+            /// Events matching any of the filters are sent.
+            pub filters: Vec<EventFilterBox>,
+            /// Cursor of the first event to send. Events of the blocks which are
+            /// already committed are replayed before switching to the live events.
+            pub from: Option<EventCursor>,
+        }
+    }
+
+    impl EventCursor {
+        /// Cursor of the first event of the block at `height`.
+        pub const fn start_of(height: NonZeroU64) -> Self {
+            Self { height, index: 0 }
+        }
+
+        /// Cursor of the event following this one in the same block.
+        #[must_use]
+        pub const fn next(self) -> Self {
+            Self {
+                height: self.height,
+                index: self.index + 1,
+            }
+        }
+    }
+
+    impl EventSubscriptionRequest {
+        /// Subscribe to the live events matching any of the `filters`.
+        pub fn new(filters: Vec<EventFilterBox>) -> Self {
+            Self {
+                filters,
+                from: None,
+            }
+        }
+
+        /// Replay the events of the committed blocks starting from `cursor` before sending the live events.
+        #[must_use]
+        pub fn starting_from(mut self, cursor: EventCursor) -> Self {
+            self.from = Some(cursor);
+            self
+        }
     }
 
     impl From<EventMessage> for EventBox {
         fn from(source: EventMessage) -> Self {
-            source.0
+            source.event
         }
     }
 }
@@ -356,7 +433,7 @@ pub mod stream {
 /// Exports common structs and enums from this module.
 pub mod prelude {
     #[cfg(feature = "http")]
-    pub use super::stream::{EventMessage, EventSubscriptionRequest};
+    pub use super::stream::{EventCursor, EventMessage, EventSubscriptionRequest};
     #[cfg(feature = "transparent_api")]
     pub use super::EventFilter;
     pub use super::{
@@ -355,6 +355,7 @@ mod tests {
                 height,
                 previous_block_hash: None,
                 transactions_hash: None,
+                state_hash: None,
                 timestamp_ms: 0,
                 view_change_index: 0,
                 consensus_estimation_ms: 0,
@@ -119,6 +119,8 @@ mod model {
 
         #[debug(fmt = "{_0:?}")]
         Fail(Fail),
+        #[debug(fmt = "{_0:?}")]
+        RotateSignatory(RotateSignatory),
     }
 }
 
@@ -176,6 +178,7 @@ impl_instruction! {
     Upgrade,
     ExecuteTrigger,
     Log,
+    RotateSignatory,
     Fail,
 }
 
@@ -997,6 +1000,31 @@ mod transparent {
             pub msg: String,
         }
     }
+
+    isi! {
+        /// Instruction to replace the keys controlling an account.
+        ///
+        /// The [`AccountId`] of the account is left untouched, so assets, roles,
+        /// permissions and triggers keep referring to the same account after rotation.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ROTATE SIGNATORY OF `{account_id}`")]
+        pub struct RotateSignatory {
+            /// Account whose signatories are replaced.
+            pub account_id: AccountId,
+            /// New signatories of the account and their quorum.
+            pub signatory_policy: SignatoryPolicy,
+        }
+    }
+
+    impl RotateSignatory {
+        /// Constructs a new [`RotateSignatory`] handing the account over to a single key.
+        pub fn single(account_id: AccountId, public_key: PublicKey) -> Self {
+            Self {
+                account_id,
+                signatory_policy: SignatoryPolicy::single(public_key),
+            }
+        }
+    }
 }
 
 macro_rules! isi_box {
@@ -1520,7 +1548,7 @@ pub mod prelude {
     pub use super::{
         AssetTransferBox, Burn, BurnBox, ExecuteTrigger, Fail, Grant, GrantBox, InstructionBox,
         Log, Mint, MintBox, NewParameter, Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox,
-        Revoke, RevokeBox, SetKeyValue, SetKeyValueBox, SetParameter, Transfer, TransferBox,
-        Unregister, UnregisterBox, Upgrade,
+        Revoke, RevokeBox, RotateSignatory, SetKeyValue, SetKeyValueBox, SetParameter, Transfer,
+        TransferBox, Unregister, UnregisterBox, Upgrade,
     };
 }
@@ -121,6 +121,7 @@ mod seal {
         Upgrade,
         ExecuteTrigger,
         Log,
+        RotateSignatory,
         Fail,
 
         // Boxed queries
@@ -153,6 +154,7 @@ mod seal {
         FindAllTransactions,
         FindTransactionsByAccountId,
         FindTransactionByHash,
+        FindTransactionProofByHash,
         FindPermissionsByAccountId,
         FindExecutorDataModel,
         FindAllActiveTriggerIds,
@@ -1014,7 +1016,8 @@ mod test {
 // The main problem is lifetimes and conversion cost.
 
 impl IdentifiableBox {
-    fn id_box(&self) -> IdBox {
+    /// Get the id of the wrapped entity.
+    pub fn id_box(&self) -> IdBox {
         match self {
             IdentifiableBox::NewDomain(a) => a.id().clone().into(),
             IdentifiableBox::NewAccount(a) => a.id().clone().into(),
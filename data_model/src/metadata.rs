@@ -422,6 +422,11 @@ impl Metadata {
         }
         map.remove(key)
     }
+
+    /// Retain only the entries under the given `keys`.
+    pub fn retain_keys(&mut self, keys: &[Name]) {
+        self.0.retain(|key, _| keys.contains(key));
+    }
 }
 
 fn check_size_limits(
@@ -0,0 +1,146 @@
+//! Structures and traits related to aggregation of query results.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use iroha_data_model_derive::model;
+use iroha_primitives::numeric::Numeric;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::IdBox;
+
+#[model]
+mod model {
+    use getset::Getters;
+
+    use super::*;
+
+    /// Aggregation of the results of an iterable query.
+    ///
+    /// Aggregation is applied to the results which pass the filter.
+    /// The query then returns one [`AggregateGroup`] per group
+    /// (or a single one if the results aren't grouped), ordered by the group id.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct Aggregation {
+        /// Function computed over the results of each group.
+        pub function: AggregateFunction,
+        /// Key to group the results by, all the results form a single group if not set.
+        pub group_by: Option<GroupBy>,
+    }
+
+    /// Function computed over a group of query results.
+    ///
+    /// [`Sum`](Self::Sum), [`Min`](Self::Min) and [`Max`](Self::Max) only take
+    /// numeric results (quantities of the numeric [`Asset`](crate::asset::Asset)s
+    /// or [`Numeric`] values) into account, other results are skipped.
+    /// Quantities of assets of different definitions are never aggregated together,
+    /// the query fails if a group contains them.
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum AggregateFunction {
+        /// Number of the results.
+        Count,
+        /// Sum of the numeric results, zero if there are none.
+        Sum,
+        /// The smallest of the numeric results.
+        Min,
+        /// The largest of the numeric results.
+        Max,
+    }
+
+    /// Key to group query results by.
+    ///
+    /// Results which don't have the key are skipped.
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum GroupBy {
+        /// Domain of the entity. Assets are grouped by the domain of their owner.
+        Domain,
+        /// Definition of the asset.
+        AssetDefinition,
+        /// Account owning the asset, or the authority of the transaction.
+        Account,
+    }
+
+    /// Result of an [`Aggregation`] computed over a single group.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    pub struct AggregateGroup {
+        /// Id of the group, `None` if the results aren't grouped.
+        pub group: Option<IdBox>,
+        /// Aggregated value, `None` for [`AggregateFunction::Min`] and
+        /// [`AggregateFunction::Max`] of a group without numeric results.
+        pub value: Option<Numeric>,
+    }
+}
+
+impl Aggregation {
+    /// Aggregate all the results with `function`.
+    pub fn new(function: AggregateFunction) -> Self {
+        Self {
+            function,
+            group_by: None,
+        }
+    }
+
+    /// Count the results.
+    pub fn count() -> Self {
+        Self::new(AggregateFunction::Count)
+    }
+
+    /// Sum the numeric results.
+    pub fn sum() -> Self {
+        Self::new(AggregateFunction::Sum)
+    }
+
+    /// Find the smallest of the numeric results.
+    pub fn min() -> Self {
+        Self::new(AggregateFunction::Min)
+    }
+
+    /// Find the largest of the numeric results.
+    pub fn max() -> Self {
+        Self::new(AggregateFunction::Max)
+    }
+
+    /// Compute the aggregation separately for each group of results with the same `key`.
+    #[must_use]
+    pub fn group_by(mut self, key: GroupBy) -> Self {
+        self.group_by = Some(key);
+        self
+    }
+}
+
+impl core::fmt::Display for AggregateGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (&self.group, &self.value) {
+            (Some(group), Some(value)) => write!(f, "{group}: {value}"),
+            (Some(group), None) => write!(f, "{group}: -"),
+            (None, Some(value)) => write!(f, "{value}"),
+            (None, None) => write!(f, "-"),
+        }
+    }
+}
+
+pub mod prelude {
+    //! Prelude: re-export most commonly used traits, structs and macros from this module.
+    pub use super::*;
+}
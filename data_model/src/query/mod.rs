@@ -9,8 +9,13 @@ use alloc::{
     vec,
     vec::Vec,
 };
-use core::{cmp::Ordering, num::NonZeroU32, time::Duration};
+use core::{
+    cmp::Ordering,
+    num::{NonZeroU32, NonZeroU64},
+    time::Duration,
+};
 
+pub use aggregation::Aggregation;
 pub use cursor::ForwardCursor;
 use derive_more::{Constructor, Display};
 use iroha_crypto::{PublicKey, SignatureOf};
@@ -21,6 +26,7 @@ use iroha_version::prelude::*;
 use nonzero_ext::nonzero;
 pub use pagination::Pagination;
 use parity_scale_codec::{Decode, Encode};
+pub use projection::Projection;
 use serde::{Deserialize, Serialize};
 pub use sorting::Sorting;
 
@@ -39,9 +45,11 @@ use crate::{
     IdBox, Identifiable, IdentifiableBox,
 };
 
+pub mod aggregation;
 pub mod cursor;
 pub mod pagination;
 pub mod predicate;
+pub mod projection;
 pub mod sorting;
 
 const FETCH_SIZE: &str = "fetch_size";
@@ -132,7 +140,7 @@ pub trait IterableQuery: Query {
 #[model]
 mod model {
     use getset::Getters;
-    use iroha_crypto::HashOf;
+    use iroha_crypto::{HashOf, MerkleProof};
     use iroha_macro::FromVariant;
     use strum::EnumDiscriminants;
 
@@ -207,6 +215,7 @@ mod model {
         FindRoleByRoleId(FindRoleByRoleId),
         FindRolesByAccountId(FindRolesByAccountId),
         FindAllParameters(FindAllParameters),
+        FindTransactionProofByHash(FindTransactionProofByHash),
     }
 
     /// Sized container for all possible [`Query::Output`]s
@@ -241,6 +250,8 @@ mod model {
             #[skip_try_from]
             Vec<QueryOutputBox>,
         ),
+        TransactionProof(TransactionProof),
+        AggregateGroup(aggregation::AggregateGroup),
     }
 
     /// Output of [`FindAllTransactions`] query
@@ -263,11 +274,41 @@ mod model {
     pub struct TransactionQueryOutput {
         /// The hash of the block to which `tx` belongs to
         pub block_hash: HashOf<SignedBlock>,
+        /// The height of the block to which `tx` belongs to
+        #[getset(skip)]
+        pub block_height: u64,
         /// Transaction
         #[getset(skip)]
         pub transaction: CommittedTransaction,
     }
 
+    /// Output of [`FindTransactionProofByHash`] query
+    #[derive(
+        Debug,
+        Clone,
+        PartialOrd,
+        Ord,
+        PartialEq,
+        Eq,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    pub struct TransactionProof {
+        /// The hash of the block to which `transaction` belongs to
+        pub block_hash: HashOf<SignedBlock>,
+        /// Header of the block to which `transaction` belongs to
+        pub block_header: BlockHeader,
+        /// Transaction
+        pub transaction: CommittedTransaction,
+        /// Path from the transaction to `transactions_hash` of the block header
+        pub audit_path: MerkleProof<SignedTransaction>,
+    }
+
     /// Request type clients (like http clients or wasm) can send to a query endpoint.
     ///
     /// `Q` should be either [`http::SignedQuery`] for client or [`SmartContractQuery`] for wasm smart contract.
@@ -308,6 +349,8 @@ mod model {
         pub pagination: Pagination,
         /// Amount of results to fetch.
         pub fetch_size: FetchSize,
+        /// Fields of the query results to return.
+        pub projection: Projection,
     }
 }
 
@@ -428,6 +471,7 @@ impl_queries! {
     FindAllTransactions => Vec<TransactionQueryOutput>,
     FindTransactionsByAccountId => Vec<TransactionQueryOutput>,
     FindTransactionByHash => TransactionQueryOutput,
+    FindTransactionProofByHash => TransactionProof,
     FindAllBlocks => Vec<SignedBlock>,
     FindAllBlockHeaders => Vec<crate::block::BlockHeader>,
     FindBlockHeaderByHash => crate::block::BlockHeader,
@@ -455,6 +499,8 @@ impl core::fmt::Display for QueryOutputBox {
             QueryOutputBox::Numeric(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::LimitedMetadata(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::ExecutorDataModel(v) => core::fmt::Display::fmt(&v, f),
+            QueryOutputBox::TransactionProof(_) => write!(f, "TransactionProof"),
+            QueryOutputBox::AggregateGroup(v) => core::fmt::Display::fmt(&v, f),
 
             QueryOutputBox::Vec(v) => {
                 // TODO: Remove so we can derive.
@@ -572,6 +618,22 @@ impl AsRef<CommittedTransaction> for TransactionQueryOutput {
     }
 }
 
+impl TransactionProof {
+    /// Check that the transaction is included into the block with the given header.
+    ///
+    /// Only the inclusion is checked, authenticity of `block_header`
+    /// should be established separately (e.g. by checking block signatures).
+    pub fn verify(&self) -> bool {
+        self.block_header
+            .transactions_hash
+            .as_ref()
+            .is_some_and(|root_hash| {
+                self.audit_path
+                    .verify(&self.transaction.as_ref().hash(), root_hash)
+            })
+    }
+}
+
 impl<Q: core::fmt::Display> core::fmt::Display for QueryRequest<Q> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -589,6 +651,7 @@ impl core::fmt::Display for SmartContractQuery {
             .field("sorting", &self.sorting)
             .field("pagination", &self.pagination)
             .field("fetch_size", &self.fetch_size)
+            .field("projection", &self.projection)
             .finish()
     }
 }
@@ -1151,11 +1214,26 @@ pub mod transaction {
             /// Transaction hash.
             pub hash: HashOf<SignedTransaction>,
         }
+
+        /// [`FindTransactionProofByHash`] Iroha Query finds a transaction (if any)
+        /// with corresponding hash value together with the proof of its inclusion into the block
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find proof of transaction with `{hash}` hash")]
+        #[repr(transparent)]
+        // SAFETY: `FindTransactionProofByHash` has no trap representation in `HashOf<SignedTransaction>`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindTransactionProofByHash {
+            /// Transaction hash.
+            pub hash: HashOf<SignedTransaction>,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllTransactions, FindTransactionByHash, FindTransactionsByAccountId};
+        pub use super::{
+            FindAllTransactions, FindTransactionByHash, FindTransactionProofByHash,
+            FindTransactionsByAccountId,
+        };
     }
 }
 
@@ -1219,6 +1297,11 @@ pub mod http {
     use crate::account::AccountId;
 
     declare_versioned!(SignedQuery 1..2, Debug, Clone, iroha_macro::FromVariant, IntoSchema);
+    declare_versioned!(QueryBatch 1..2, Debug, Clone, iroha_macro::FromVariant, IntoSchema);
+
+    /// Response to a [`QueryBatch`], holding the result of each query of the batch in the same order.
+    pub type QueryBatchResponse =
+        Vec<Result<crate::BatchedResponse<QueryOutputBox>, crate::ValidationFail>>;
 
     #[model]
     mod model {
@@ -1248,10 +1331,16 @@ pub mod http {
             pub filter: PredicateBox,
             /// Sorting applied to the result on the server-side.
             pub sorting: Sorting,
+            /// Aggregation applied to the filtered result on the server-side.
+            pub aggregation: Option<Aggregation>,
+            /// Fields of the result to return.
+            pub projection: Projection,
             /// Selects the page of the result set to return.
             pub pagination: Pagination,
             /// Specifies the size of a single batch of results.
             pub fetch_size: FetchSize,
+            /// Height of the block after which the state is queried, latest state if not set.
+            pub at_height: Option<NonZeroU64>,
         }
 
         /// I/O ready structure to send queries.
@@ -1267,6 +1356,17 @@ pub mod http {
         /// End type of a query http clients can send to an endpoint.
         #[derive(Debug, Clone, Decode, Encode)]
         pub struct ClientQueryRequest(pub QueryRequest<SignedQuery>);
+
+        /// Queries sent to a peer in a single request.
+        ///
+        /// All the queries of the batch are evaluated against the same state,
+        /// so their results are consistent with each other.
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        #[version_with_scale(version = 1, versioned_alias = "QueryBatch")]
+        pub struct QueryBatchV1 {
+            /// Queries of the batch. All of them must query the state at the same height.
+            pub queries: Vec<SignedQuery>,
+        }
     }
 
     impl ClientQueryRequest {
@@ -1281,6 +1381,28 @@ pub mod http {
         }
     }
 
+    impl QueryBatch {
+        /// Construct a new batch of `queries`.
+        pub fn new(queries: impl IntoIterator<Item = SignedQuery>) -> Self {
+            QueryBatchV1 {
+                queries: queries.into_iter().collect(),
+            }
+            .into()
+        }
+
+        /// Queries of the batch
+        pub fn queries(&self) -> &[SignedQuery] {
+            let QueryBatch::V1(batch) = self;
+            &batch.queries
+        }
+
+        /// Consume the batch, returning its queries
+        pub fn into_queries(self) -> Vec<SignedQuery> {
+            let QueryBatch::V1(batch) = self;
+            batch.queries
+        }
+    }
+
     mod candidate {
         use parity_scale_codec::Input;
 
@@ -1334,6 +1456,13 @@ pub mod http {
             let SignedQuery::V1(query) = self;
             &query.signature
         }
+        /// Check that the query signature is valid.
+        ///
+        /// Decoded queries are checked on decoding already.
+        pub fn verify_signature(&self) -> bool {
+            let SignedQuery::V1(query) = self;
+            query.signature.verify(&query.payload).is_ok()
+        }
         /// Return query payload
         pub fn query(&self) -> &QueryBox {
             let SignedQuery::V1(query) = self;
@@ -1354,6 +1483,16 @@ pub mod http {
             let SignedQuery::V1(query) = self;
             &query.payload.sorting
         }
+        /// Return query aggregation
+        pub fn aggregation(&self) -> Option<&Aggregation> {
+            let SignedQuery::V1(query) = self;
+            query.payload.aggregation.as_ref()
+        }
+        /// Return query projection
+        pub fn projection(&self) -> &Projection {
+            let SignedQuery::V1(query) = self;
+            &query.payload.projection
+        }
         /// Return query pagination
         pub fn pagination(&self) -> Pagination {
             let SignedQuery::V1(query) = self;
@@ -1364,6 +1503,11 @@ pub mod http {
             let SignedQuery::V1(query) = self;
             query.payload.fetch_size
         }
+        /// Return height of the block after which the state is queried
+        pub fn at_height(&self) -> Option<NonZeroU64> {
+            let SignedQuery::V1(query) = self;
+            query.payload.at_height
+        }
     }
 
     impl ClientQueryBuilder {
@@ -1375,8 +1519,11 @@ pub mod http {
                     authority,
                     filter: PredicateBox::default(),
                     sorting: Sorting::default(),
+                    aggregation: None,
+                    projection: Projection::default(),
                     pagination: Pagination::default(),
                     fetch_size: FetchSize::default(),
+                    at_height: None,
                 },
             }
         }
@@ -1395,6 +1542,20 @@ pub mod http {
             self
         }
 
+        /// Set the aggregation for the query
+        #[inline]
+        pub fn with_aggregation(mut self, aggregation: Aggregation) -> Self {
+            self.payload.aggregation = Some(aggregation);
+            self
+        }
+
+        /// Set the projection for the query
+        #[inline]
+        pub fn with_projection(mut self, projection: Projection) -> Self {
+            self.payload.projection = projection;
+            self
+        }
+
         /// Set the pagination for the query
         #[inline]
         pub fn with_pagination(mut self, pagination: Pagination) -> Self {
@@ -1409,6 +1570,13 @@ pub mod http {
             self
         }
 
+        /// Query the state as it was right after the block at `height` was applied
+        #[inline]
+        pub fn with_at_height(mut self, height: NonZeroU64) -> Self {
+            self.payload.at_height = Some(height);
+            self
+        }
+
         /// Consumes self and returns a signed [`ClientQueryBuilder`].
         ///
         /// # Errors
@@ -1427,7 +1595,7 @@ pub mod http {
     pub mod prelude {
         //! The prelude re-exports most commonly used traits, structs and macros from this crate.
 
-        pub use super::{ClientQueryBuilder, SignedQuery, SignedQueryV1};
+        pub use super::{ClientQueryBuilder, QueryBatch, QueryBatchV1, SignedQuery, SignedQueryV1};
     }
 }
 
@@ -1486,8 +1654,26 @@ pub mod error {
             UnknownCursor,
             /// fetch_size could not be greater than {MAX_FETCH_SIZE:?}
             FetchSizeTooBig,
-            /// Some of the specified parameters (filter/pagination/fetch_size/sorting) are not applicable to singular queries
+            /// Some of the specified parameters (filter/pagination/fetch_size/sorting/aggregation/projection) are not applicable to singular queries
             InvalidSingularParameters,
+            /// Aggregated value is out of the `Numeric` range
+            AggregationOverflow,
+            /// State at height {0} is not served by this peer
+            HeightNotServed(
+                #[skip_from]
+                #[skip_try_from]
+                u64,
+            ),
+            /// Quantities of different asset definitions can't be aggregated together, group the assets by the asset definition
+            AggregationOfDifferentAssets,
+            /// Queries of a batch can't query the state at different heights
+            BatchHeightMismatch,
+            /// Batch contains more queries than the limit of {0}
+            BatchTooLarge(
+                #[skip_from]
+                #[skip_try_from]
+                u64,
+            ),
         }
 
         /// Type assertion error
@@ -1548,6 +1734,6 @@ pub mod prelude {
         account::prelude::*, asset::prelude::*, block::prelude::*, domain::prelude::*,
         executor::prelude::*, peer::prelude::*, permission::prelude::*, predicate::PredicateTrait,
         role::prelude::*, transaction::prelude::*, trigger::prelude::*, FetchSize, QueryBox,
-        QueryId, TransactionQueryOutput,
+        QueryId, TransactionProof, TransactionQueryOutput,
     };
 }
@@ -531,6 +531,7 @@ pub mod test {
 
 pub mod string {
     //! String-related predicates and implementations.
+    pub use self::pattern::{GlobPattern, RegexPattern, MAX_PATTERN_LEN};
     use super::*;
 
     /// Predicate useful for processing [`String`]s and [`Name`]s.
@@ -544,6 +545,23 @@ pub mod string {
         EndsWith(String),
         /// Forward to [`String`] equality.
         Is(String),
+        /// Match the whole string against a glob pattern.
+        ///
+        /// `*` matches any sequence of characters, `?` matches a single character,
+        /// `[...]` matches a character from the set (`[!...]` or `[^...]` from its complement)
+        /// and `\` escapes the following character.
+        /// As for [`Self::Regex`], invalid or too long patterns don't match any string.
+        Glob(GlobPattern),
+        /// Match the whole string against a regular expression.
+        ///
+        /// Supported syntax: literals, `.`, character classes `[...]`/`[^...]`,
+        /// `\d`, `\w`, `\s` (also inside the character classes), grouping `(...)`, alternation `|`
+        /// and quantifiers `*`, `+`, `?`.
+        /// Patterns longer than [`MAX_PATTERN_LEN`] characters or with
+        /// invalid syntax don't match any string.
+        ///
+        /// Matching doesn't backtrack and takes time linear in the length of the input.
+        Regex(RegexPattern),
     }
 
     impl StringPredicate {
@@ -570,6 +588,29 @@ pub mod string {
         pub fn is(predicate: &str) -> Self {
             Self::Is(predicate.to_owned())
         }
+
+        /// Construct the [`Self::Glob`] variant
+        #[inline]
+        pub fn glob(predicate: &str) -> Self {
+            Self::Glob(GlobPattern::new(predicate.to_owned()))
+        }
+
+        /// Construct the [`Self::Regex`] variant
+        #[inline]
+        pub fn regex(predicate: &str) -> Self {
+            Self::Regex(RegexPattern::new(predicate.to_owned()))
+        }
+
+        /// Check that the pattern of [`Self::Glob`] or [`Self::Regex`] is valid.
+        ///
+        /// Other variants are always valid.
+        pub fn is_valid(&self) -> bool {
+            match self {
+                StringPredicate::Glob(pattern) => pattern.is_valid(),
+                StringPredicate::Regex(pattern) => pattern.is_valid(),
+                _ => true,
+            }
+        }
     }
 
     // TODO: Case insensitive variants?
@@ -584,6 +625,8 @@ pub mod string {
                 StringPredicate::StartsWith(content) => input.as_ref().starts_with(content),
                 StringPredicate::EndsWith(content) => input.as_ref().ends_with(content),
                 StringPredicate::Is(content) => *(input.as_ref()) == *content,
+                StringPredicate::Glob(pattern) => pattern.matches(input.as_ref()),
+                StringPredicate::Regex(pattern) => pattern.matches(input.as_ref()),
             }
         }
     }
@@ -607,6 +650,525 @@ pub mod string {
         }
     }
 
+    mod pattern {
+        //! Bounded matching engine backing [`StringPredicate::Glob`] and [`StringPredicate::Regex`].
+        //!
+        //! Patterns are compiled into a Thompson NFA which is simulated over all of its
+        //! states at once, so matching never backtracks and takes
+        //! `O(pattern.len() * input.len())` steps regardless of the pattern.
+        //! Patterns are compiled once, when the predicate is constructed or decoded.
+
+        #[cfg(not(feature = "std"))]
+        use alloc::{boxed::Box, string::String, vec, vec::Vec};
+        use core::{iter::Peekable, str::Chars};
+
+        use iroha_schema::{IntoSchema, MetaMap, TypeId};
+        use parity_scale_codec::{Decode, Encode, Input, Output};
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        /// Maximum length of a glob or regex pattern in characters
+        pub const MAX_PATTERN_LEN: usize = 256;
+
+        macro_rules! compiled_pattern {
+            ($(#[$meta:meta])* $name:ident, $compile:path) => {
+                $(#[$meta])*
+                ///
+                /// Encoded and represented in the schema as the [`String`] of the pattern.
+                #[derive(Debug, Clone)]
+                pub struct $name {
+                    source: String,
+                    /// `None` if the pattern is invalid or too long
+                    program: Option<Program>,
+                }
+
+                impl $name {
+                    /// Compile `source`
+                    pub fn new(source: String) -> Self {
+                        let program = $compile(&source);
+                        Self { source, program }
+                    }
+
+                    /// Source of the pattern
+                    pub fn source(&self) -> &str {
+                        &self.source
+                    }
+
+                    /// Check that the pattern is valid
+                    pub fn is_valid(&self) -> bool {
+                        self.program.is_some()
+                    }
+
+                    /// Check whether the whole `input` matches the pattern.
+                    /// Invalid patterns don't match any input.
+                    pub fn matches(&self, input: &str) -> bool {
+                        self.program
+                            .as_ref()
+                            .is_some_and(|program| program.matches(input))
+                    }
+                }
+
+                impl PartialEq for $name {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.source == other.source
+                    }
+                }
+
+                impl Eq for $name {}
+
+                impl Encode for $name {
+                    fn size_hint(&self) -> usize {
+                        self.source.size_hint()
+                    }
+
+                    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+                        self.source.encode_to(dest);
+                    }
+                }
+
+                impl Decode for $name {
+                    fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+                        String::decode(input).map(Self::new)
+                    }
+                }
+
+                impl Serialize for $name {
+                    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        self.source.serialize(serializer)
+                    }
+                }
+
+                impl<'de> Deserialize<'de> for $name {
+                    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        String::deserialize(deserializer).map(Self::new)
+                    }
+                }
+
+                impl TypeId for $name {
+                    fn id() -> String {
+                        String::id()
+                    }
+                }
+
+                impl IntoSchema for $name {
+                    fn type_name() -> String {
+                        String::type_name()
+                    }
+
+                    fn update_schema_map(metamap: &mut MetaMap) {
+                        String::update_schema_map(metamap);
+                    }
+                }
+            };
+        }
+
+        compiled_pattern! {
+            /// Compiled pattern of [`StringPredicate::Glob`](super::StringPredicate::Glob).
+            GlobPattern, Program::glob
+        }
+
+        compiled_pattern! {
+            /// Compiled pattern of [`StringPredicate::Regex`](super::StringPredicate::Regex).
+            RegexPattern, Program::regex
+        }
+
+        /// Set of characters matched by `[...]`, `\d`, `\w` or `\s`
+        #[derive(Debug, Clone)]
+        struct Class {
+            negated: bool,
+            ranges: Vec<(char, char)>,
+        }
+
+        impl Class {
+            fn escape(c: char) -> Option<Self> {
+                let ranges = match c {
+                    'd' => vec![('0', '9')],
+                    'w' => vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')],
+                    's' => vec![('\t', '\r'), (' ', ' ')],
+                    _ => return None,
+                };
+
+                Some(Self {
+                    negated: false,
+                    ranges,
+                })
+            }
+
+            fn matches(&self, c: char) -> bool {
+                self.ranges
+                    .iter()
+                    .any(|&(start, end)| start <= c && c <= end)
+                    != self.negated
+            }
+        }
+
+        /// Parsed pattern
+        enum Node {
+            Char(char),
+            Any,
+            Class(Class),
+            Concat(Vec<Node>),
+            Alternate(Vec<Node>),
+            /// `*`
+            ZeroOrMore(Box<Node>),
+            /// `+`
+            OneOrMore(Box<Node>),
+            /// `?`
+            ZeroOrOne(Box<Node>),
+        }
+
+        #[derive(Debug, Clone)]
+        enum Inst {
+            Char(char),
+            Any,
+            Class(Class),
+            Split(usize, usize),
+            Jump(usize),
+            Match,
+        }
+
+        /// Compiled pattern
+        #[derive(Debug, Clone)]
+        pub struct Program(Vec<Inst>);
+
+        impl Program {
+            /// Compile glob `pattern`, `None` if it is invalid or too long
+            pub fn glob(pattern: &str) -> Option<Self> {
+                if pattern.chars().count() > MAX_PATTERN_LEN {
+                    return None;
+                }
+
+                let mut chars = pattern.chars().peekable();
+                let mut nodes = Vec::new();
+                while let Some(c) = chars.next() {
+                    nodes.push(match c {
+                        '*' => Node::ZeroOrMore(Box::new(Node::Any)),
+                        '?' => Node::Any,
+                        '[' => parse_class(&mut chars, &['!', '^'], false)?,
+                        '\\' => Node::Char(chars.next()?),
+                        c => Node::Char(c),
+                    });
+                }
+
+                Some(Self::compile(&Node::Concat(nodes)))
+            }
+
+            /// Compile regex `pattern`, `None` if it is invalid or too long
+            pub fn regex(pattern: &str) -> Option<Self> {
+                if pattern.chars().count() > MAX_PATTERN_LEN {
+                    return None;
+                }
+
+                let mut chars = pattern.chars().peekable();
+                let node = parse_alternation(&mut chars)?;
+                if chars.next().is_some() {
+                    // unbalanced `)`
+                    return None;
+                }
+
+                Some(Self::compile(&node))
+            }
+
+            fn compile(node: &Node) -> Self {
+                let mut program = Self(Vec::new());
+                program.emit(node);
+                program.0.push(Inst::Match);
+                program
+            }
+
+            fn emit(&mut self, node: &Node) {
+                match node {
+                    Node::Char(c) => self.0.push(Inst::Char(*c)),
+                    Node::Any => self.0.push(Inst::Any),
+                    Node::Class(class) => self.0.push(Inst::Class(class.clone())),
+                    Node::Concat(nodes) => nodes.iter().for_each(|node| self.emit(node)),
+                    Node::Alternate(nodes) => {
+                        let mut jumps = Vec::new();
+                        let (last, rest) = nodes.split_last().expect("At least two alternatives");
+                        for node in rest {
+                            let split = self.placeholder();
+                            self.emit(node);
+                            jumps.push(self.placeholder());
+                            self.0[split] = Inst::Split(split + 1, self.0.len());
+                        }
+                        self.emit(last);
+                        for jump in jumps {
+                            self.0[jump] = Inst::Jump(self.0.len());
+                        }
+                    }
+                    Node::ZeroOrMore(node) => {
+                        let split = self.placeholder();
+                        self.emit(node);
+                        self.0.push(Inst::Jump(split));
+                        self.0[split] = Inst::Split(split + 1, self.0.len());
+                    }
+                    Node::OneOrMore(node) => {
+                        let start = self.0.len();
+                        self.emit(node);
+                        self.0.push(Inst::Split(start, self.0.len() + 1));
+                    }
+                    Node::ZeroOrOne(node) => {
+                        let split = self.placeholder();
+                        self.emit(node);
+                        self.0[split] = Inst::Split(split + 1, self.0.len());
+                    }
+                }
+            }
+
+            fn placeholder(&mut self) -> usize {
+                self.0.push(Inst::Match);
+                self.0.len() - 1
+            }
+
+            /// Check whether the whole `input` matches the pattern
+            pub fn matches(&self, input: &str) -> bool {
+                let mut current = Threads::new(self.0.len());
+                let mut next = Threads::new(self.0.len());
+                self.add_thread(&mut current, 0);
+
+                for c in input.chars() {
+                    if current.pcs.is_empty() {
+                        return false;
+                    }
+
+                    for &pc in &current.pcs {
+                        let advances = match &self.0[pc] {
+                            Inst::Char(expected) => *expected == c,
+                            Inst::Any => true,
+                            Inst::Class(class) => class.matches(c),
+                            Inst::Split(..) | Inst::Jump(_) | Inst::Match => false,
+                        };
+                        if advances {
+                            self.add_thread(&mut next, pc + 1);
+                        }
+                    }
+
+                    core::mem::swap(&mut current, &mut next);
+                    next.clear();
+                }
+
+                current
+                    .pcs
+                    .iter()
+                    .any(|&pc| matches!(self.0[pc], Inst::Match))
+            }
+
+            /// Add thread at `pc` following all the jumps and splits
+            fn add_thread(&self, threads: &mut Threads, pc: usize) {
+                let mut stack = vec![pc];
+                while let Some(pc) = stack.pop() {
+                    if core::mem::replace(&mut threads.visited[pc], true) {
+                        continue;
+                    }
+                    match &self.0[pc] {
+                        Inst::Jump(to) => stack.push(*to),
+                        Inst::Split(first, second) => {
+                            stack.push(*second);
+                            stack.push(*first);
+                        }
+                        _ => threads.pcs.push(pc),
+                    }
+                }
+            }
+        }
+
+        /// Set of NFA states
+        struct Threads {
+            pcs: Vec<usize>,
+            visited: Vec<bool>,
+        }
+
+        impl Threads {
+            fn new(len: usize) -> Self {
+                Self {
+                    pcs: Vec::with_capacity(len),
+                    visited: vec![false; len],
+                }
+            }
+
+            fn clear(&mut self) {
+                self.pcs.clear();
+                self.visited.fill(false);
+            }
+        }
+
+        fn parse_alternation(chars: &mut Peekable<Chars<'_>>) -> Option<Node> {
+            let mut alternatives = vec![parse_concat(chars)?];
+            while chars.next_if_eq(&'|').is_some() {
+                alternatives.push(parse_concat(chars)?);
+            }
+
+            Some(if alternatives.len() == 1 {
+                alternatives.remove(0)
+            } else {
+                Node::Alternate(alternatives)
+            })
+        }
+
+        fn parse_concat(chars: &mut Peekable<Chars<'_>>) -> Option<Node> {
+            let mut nodes = Vec::new();
+            while let Some(&c) = chars.peek() {
+                if c == '|' || c == ')' {
+                    break;
+                }
+                chars.next();
+
+                let mut node = match c {
+                    '(' => {
+                        let node = parse_alternation(chars)?;
+                        chars.next_if_eq(&')')?;
+                        node
+                    }
+                    '[' => parse_class(chars, &['^'], true)?,
+                    '.' => Node::Any,
+                    '\\' => {
+                        let c = chars.next()?;
+                        Class::escape(c).map_or(Node::Char(c), Node::Class)
+                    }
+                    '*' | '+' | '?' => return None,
+                    c => Node::Char(c),
+                };
+                while let Some(quantifier) = chars.next_if(|&c| matches!(c, '*' | '+' | '?')) {
+                    node = match quantifier {
+                        '*' => Node::ZeroOrMore(Box::new(node)),
+                        '+' => Node::OneOrMore(Box::new(node)),
+                        _ => Node::ZeroOrOne(Box::new(node)),
+                    };
+                }
+                nodes.push(node);
+            }
+
+            Some(Node::Concat(nodes))
+        }
+
+        /// Parse the class after the opening `[`, `negations` are the characters negating the class.
+        ///
+        /// With `class_escapes` `\d`, `\w` and `\s` add their sets to the class,
+        /// otherwise `\` only escapes the following character.
+        fn parse_class(
+            chars: &mut Peekable<Chars<'_>>,
+            negations: &[char],
+            class_escapes: bool,
+        ) -> Option<Node> {
+            let negated = chars.next_if(|c| negations.contains(c)).is_some();
+
+            let mut ranges = Vec::new();
+            // `]` right after the opening bracket is a literal
+            let mut first = true;
+            loop {
+                let start = match chars.next()? {
+                    ']' if !first => break,
+                    '\\' => {
+                        let c = chars.next()?;
+                        if let Some(class) = Class::escape(c).filter(|_| class_escapes) {
+                            ranges.extend(class.ranges);
+                            first = false;
+                            continue;
+                        }
+                        c
+                    }
+                    c => c,
+                };
+                first = false;
+
+                let end = if chars.peek() == Some(&'-') {
+                    chars.next();
+                    match chars.next()? {
+                        ']' => {
+                            // trailing `-` is a literal
+                            ranges.push((start, start));
+                            ranges.push(('-', '-'));
+                            break;
+                        }
+                        '\\' => chars.next()?,
+                        c => c,
+                    }
+                } else {
+                    start
+                };
+                if end < start {
+                    return None;
+                }
+                ranges.push((start, end));
+            }
+
+            Some(Node::Class(Class { negated, ranges }))
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            fn regex(pattern: &str, input: &str) -> bool {
+                Program::regex(pattern)
+                    .expect("Valid pattern")
+                    .matches(input)
+            }
+
+            fn glob(pattern: &str, input: &str) -> bool {
+                Program::glob(pattern)
+                    .expect("Valid pattern")
+                    .matches(input)
+            }
+
+            #[test]
+            fn regex_syntax() {
+                assert!(regex("rose#wonderland", "rose#wonderland"));
+                assert!(!regex("rose", "rose#wonderland"));
+                assert!(regex("ro.e#.*", "rope#garden"));
+                assert!(regex("(rose|tulip)#[a-z_]+", "tulip#looking_glass"));
+                assert!(!regex("(rose|tulip)#[a-z_]+", "daisy#wonderland"));
+                assert!(regex("item\\d+", "item42"));
+                assert!(!regex("item\\d+", "item"));
+                assert!(regex("colou?r", "color"));
+                assert!(regex("[^#]*#\\w+", "rose#wonderland"));
+                assert!(regex("a\\.b", "a.b"));
+                assert!(!regex("a\\.b", "axb"));
+                assert!(regex("", ""));
+                assert!(regex("(|a)b", "b"));
+                assert!(regex("[\\d_]+", "4_2"));
+                assert!(!regex("[\\d_]+", "d"));
+                assert!(regex("[^\\s]+", "rose"));
+                assert!(!regex("[^\\s]+", "ro se"));
+            }
+
+            #[test]
+            fn glob_syntax() {
+                assert!(glob("rose#*", "rose#wonderland"));
+                assert!(!glob("rose#*", "tulip#wonderland"));
+                assert!(glob("*#wonder?and", "rose#wonderland"));
+                assert!(glob("[rt]*#*", "tulip#garden"));
+                assert!(!glob("[!rt]*#*", "tulip#garden"));
+                assert!(glob("a\\*b", "a*b"));
+                assert!(!glob("a\\*b", "axb"));
+                assert!(glob("[a-]", "-"));
+                assert!(glob("*.+", "a.+"));
+                assert!(glob("[\\d]", "d"));
+                assert!(!glob("[\\d]", "4"));
+            }
+
+            #[test]
+            fn invalid_patterns() {
+                for pattern in ["(a", "a)", "*a", "a|+", "[a", "[z-a]", "a\\"] {
+                    assert!(Program::regex(pattern).is_none(), "{pattern}");
+                }
+                assert!(Program::glob("[a").is_none());
+                assert!(Program::glob("a\\").is_none());
+                assert!(Program::regex(&"a".repeat(MAX_PATTERN_LEN + 1)).is_none());
+                assert!(Program::glob(&"a".repeat(MAX_PATTERN_LEN)).is_some());
+            }
+
+            #[test]
+            fn pathological_patterns_are_linear() {
+                // Exponential for backtracking engines
+                let pattern = format!("{}{}", "(a*)*".repeat(20), "b");
+                let input = "a".repeat(10_000);
+                assert!(!regex(&pattern, &input));
+                assert!(regex("(a|aa)+", &input));
+                assert!(!glob(&"*a".repeat(50), &"a".repeat(49)));
+            }
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use iroha_primitives::addr::socket_addr;
@@ -796,6 +1358,20 @@ pub mod string {
                 assert!(pred.applies(""));
                 assert!(pred.applies("asd")); // TODO: is this the correct behaviour that we want
             }
+
+            #[test]
+            fn glob_and_regex() {
+                let id = IdBox::AssetDefinitionId("rose#wonderland".parse().expect("Valid"));
+                assert!(StringPredicate::glob("r*#wonder*").applies(&id));
+                assert!(!StringPredicate::glob("r*#garden").applies(&id));
+                assert!(StringPredicate::regex("(rose|tulip)#\\w+").applies(&id));
+                assert!(!StringPredicate::regex("tulip#.*").applies(&id));
+
+                let invalid = StringPredicate::regex("rose(");
+                assert!(!invalid.is_valid());
+                assert!(!invalid.applies("rose("));
+                assert!(StringPredicate::is("rose(").is_valid());
+            }
         }
     }
 }
@@ -807,7 +1383,9 @@ pub mod numerical {
     use iroha_primitives::numeric::Numeric;
 
     use super::*;
-    use crate::query::QueryOutputBox;
+    use crate::{
+        asset::AssetValue, metadata::MetadataValueBox, query::QueryOutputBox, IdentifiableBox,
+    };
 
     /// A lower-inclusive range predicate.
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
@@ -888,12 +1466,49 @@ pub mod numerical {
     /// [`Self`] only applies to `Values` that are variants of
     /// compatible types. If the [`Range`] variant and the [`Value`]
     /// variant don't match defaults to `false`.
-    #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
     pub enum Range {
         /// Numeric
         Numeric(Interval<Numeric>),
     }
 
+    /// Predicate comparing the numerical value with a constant.
+    ///
+    /// # Type checking
+    ///
+    /// Same as for [`SemiRange`].
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum Comparison {
+        /// Value is less than the constant
+        Less(Numeric),
+        /// Value is less than or equal to the constant
+        LessOrEqual(Numeric),
+        /// Value is greater than the constant
+        Greater(Numeric),
+        /// Value is greater than or equal to the constant
+        GreaterOrEqual(Numeric),
+        /// Value is equal to the constant
+        Equal(Numeric),
+    }
+
+    /// Extract the numerical value the predicates of this module are applied to:
+    /// [`Numeric`] value, numeric metadata value or quantity of the numeric asset.
+    fn numeric_value(input: &QueryOutputBox) -> Option<Numeric> {
+        match input {
+            QueryOutputBox::Numeric(quantity)
+            | QueryOutputBox::LimitedMetadata(MetadataValueBox::Numeric(quantity)) => {
+                Some(*quantity)
+            }
+            QueryOutputBox::Identifiable(IdentifiableBox::Asset(asset)) => match asset.value() {
+                AssetValue::Numeric(quantity) => Some(*quantity),
+                AssetValue::Store(_) => None,
+            },
+            _ => None,
+        }
+    }
+
     /// A trait to mark objects which should be treated as bounded unsigned values.
     pub trait UnsignedMarker {
         /// The maximum attainable value
@@ -997,12 +1612,9 @@ pub mod numerical {
 
         #[inline]
         fn applies(&self, input: &QueryOutputBox) -> Self::EvaluatesTo {
-            match input {
-                QueryOutputBox::Numeric(quantity) => match self {
-                    SemiRange::Numeric(predicate) => predicate.applies(*quantity),
-                },
-                _ => false,
-            }
+            numeric_value(input).map_or(false, |quantity| match self {
+                SemiRange::Numeric(predicate) => predicate.applies(quantity),
+            })
         }
     }
 
@@ -1011,12 +1623,24 @@ pub mod numerical {
 
         #[inline]
         fn applies(&self, input: &QueryOutputBox) -> Self::EvaluatesTo {
-            match input {
-                QueryOutputBox::Numeric(quantity) => match self {
-                    Range::Numeric(predicate) => predicate.applies(*quantity),
-                },
-                _ => false,
-            }
+            numeric_value(input).map_or(false, |quantity| match self {
+                Range::Numeric(predicate) => predicate.applies(quantity),
+            })
+        }
+    }
+
+    impl PredicateTrait<&QueryOutputBox> for Comparison {
+        type EvaluatesTo = bool;
+
+        #[inline]
+        fn applies(&self, input: &QueryOutputBox) -> Self::EvaluatesTo {
+            numeric_value(input).map_or(false, |quantity| match *self {
+                Comparison::Less(value) => quantity < value,
+                Comparison::LessOrEqual(value) => quantity <= value,
+                Comparison::Greater(value) => quantity > value,
+                Comparison::GreaterOrEqual(value) => quantity >= value,
+                Comparison::Equal(value) => quantity == value,
+            })
         }
     }
 
@@ -1077,6 +1701,38 @@ pub mod numerical {
             }
         }
 
+        #[test]
+        fn comparison_semantics_numeric() {
+            let pred = Comparison::Greater(numeric!(10));
+            assert!(pred.applies(&numeric!(10.01).into()));
+            assert!(!pred.applies(&numeric!(10).into()));
+            assert!(Comparison::GreaterOrEqual(numeric!(10)).applies(&numeric!(10).into()));
+            assert!(Comparison::Less(numeric!(10)).applies(&numeric!(9.99).into()));
+            assert!(!Comparison::LessOrEqual(numeric!(10)).applies(&numeric!(10.5).into()));
+            assert!(Comparison::Equal(numeric!(10)).applies(&numeric!(10.00).into()));
+            assert!(!pred.applies(&QueryOutputBox::Vec(Vec::new())));
+        }
+
+        #[test]
+        fn numeric_metadata_and_assets() {
+            use crate::{asset::Asset, metadata::Metadata};
+
+            let pred = Range::Numeric((numeric!(1), numeric!(10)).into());
+            assert!(pred.applies(&MetadataValueBox::from(numeric!(5)).into()));
+            assert!(!pred.applies(&MetadataValueBox::from(numeric!(11)).into()));
+
+            let asset_id: crate::asset::AssetId = format!(
+                "rose##{}@wonderland",
+                iroha_crypto::KeyPair::random().public_key()
+            )
+            .parse()
+            .expect("Valid");
+            let numeric_asset = Asset::new(asset_id.clone(), numeric!(7));
+            assert!(pred.applies(&numeric_asset.into()));
+            let store_asset = Asset::new(asset_id, AssetValue::Store(Metadata::new()));
+            assert!(!pred.applies(&store_asset.into()));
+        }
+
         #[test]
         fn upper_bounds() {
             {
@@ -1109,7 +1765,10 @@ pub mod numerical {
 pub mod value {
     //!  raw predicates applied to `QueryOutputBox`.
     use super::*;
-    use crate::query::QueryOutputBox;
+    use crate::{
+        asset::AssetValue, metadata::MetadataValueBox, query::QueryOutputBox, HasMetadata,
+        IdentifiableBox,
+    };
 
     /// A predicate designed for general processing of `QueryOutputBox`.
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
@@ -1126,6 +1785,13 @@ pub mod value {
         TimeStamp(numerical::SemiInterval<u128>),
         /// Always return true.
         Pass,
+        /// Apply both-ends inclusive range predicate to the numerical value.
+        NumericalRange(numerical::Range),
+        /// Compare the numerical value with a constant.
+        Comparison(numerical::Comparison),
+        /// Apply predicate to the value under the key of the entity's metadata
+        /// (or of the store [`Asset`](crate::asset::Asset) or the metadata value).
+        Metadata(AtKey),
     }
 
     impl PredicateTrait<&QueryOutputBox> for QueryOutputPredicate {
@@ -1167,10 +1833,39 @@ pub mod value {
                     _ => false,
                 },
                 QueryOutputPredicate::Pass => true,
+                QueryOutputPredicate::NumericalRange(pred) => pred.applies(input),
+                QueryOutputPredicate::Comparison(pred) => pred.applies(input),
+                QueryOutputPredicate::Metadata(AtKey {
+                    key,
+                    predicate: pred,
+                }) => metadata_value(input, key)
+                    .map_or(false, |val| pred.applies(&val.clone().into())),
             }
         }
     }
 
+    /// Get the value under `key` of the metadata of `input`
+    fn metadata_value<'input>(
+        input: &'input QueryOutputBox,
+        key: &Name,
+    ) -> Option<&'input MetadataValueBox> {
+        match input {
+            QueryOutputBox::Identifiable(IdentifiableBox::Asset(asset)) => match asset.value() {
+                AssetValue::Store(store) => store.get(key),
+                AssetValue::Numeric(_) => None,
+            },
+            QueryOutputBox::Identifiable(identifiable_box) => {
+                TryInto::<&dyn HasMetadata>::try_into(identifiable_box)
+                    .ok()
+                    .and_then(|has_metadata| has_metadata.metadata().get(key))
+            }
+            QueryOutputBox::LimitedMetadata(MetadataValueBox::LimitedMetadata(metadata)) => {
+                metadata.get(key)
+            }
+            _ => None,
+        }
+    }
+
     impl QueryOutputPredicate {
         /// Construct [`Predicate::Container`] variant.
         #[inline]
@@ -1195,6 +1890,16 @@ pub mod value {
                 predicate: Box::new(pred.into()),
             }))
         }
+
+        /// Construct [`Self::Metadata`] variant.
+        #[inline]
+        #[must_use]
+        pub fn metadata(key: Name, pred: impl Into<QueryOutputPredicate>) -> Self {
+            Self::Metadata(AtKey {
+                key,
+                predicate: Box::new(pred.into()),
+            })
+        }
     }
 
     /// A predicate that targets the particular `index` of a collection.
@@ -1204,6 +1909,13 @@ pub mod value {
         predicate: Box<QueryOutputPredicate>,
     }
 
+    /// A predicate that targets the value under the `key` of the metadata.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct AtKey {
+        key: Name,
+        predicate: Box<QueryOutputPredicate>,
+    }
+
     /// Predicate that targets specific elements or groups; useful for
     /// working with containers. Currently only [`Vec<Value>`] is supported.
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
@@ -1231,7 +1943,7 @@ pub mod value {
         use crate::{
             account::{Account, AccountId},
             domain::{Domain, DomainId},
-            metadata::{Metadata, MetadataValueBox},
+            metadata::{Limits as MetadataLimits, Metadata, MetadataValueBox},
             peer::{Peer, PeerId},
         };
 
@@ -1329,5 +2041,38 @@ pub mod value {
             println!("{idx_pred:?}");
             assert!(!idx_pred.applies(&list));
         }
+
+        #[test]
+        fn metadata_value() {
+            let score: Name = "score".parse().expect("Valid");
+            let mut metadata = Metadata::new();
+            metadata
+                .insert_with_limits(score.clone(), numeric!(42), MetadataLimits::new(10, 100))
+                .expect("Valid");
+            let domain = QueryOutputBox::Identifiable(
+                Domain::new("wonderland".parse().expect("Valid"))
+                    .with_metadata(metadata.clone())
+                    .into(),
+            );
+
+            let pred = QueryOutputPredicate::metadata(
+                score.clone(),
+                QueryOutputPredicate::Comparison(numerical::Comparison::Greater(numeric!(10))),
+            );
+            println!("{pred:?}");
+            assert!(pred.applies(&domain));
+            assert!(pred.applies(&MetadataValueBox::LimitedMetadata(metadata).into()));
+            assert!(!pred.applies(&QueryOutputBox::Identifiable(
+                Domain::new("wonderland".parse().expect("Valid")).into()
+            )));
+
+            let pred = QueryOutputPredicate::metadata(
+                score,
+                QueryOutputPredicate::NumericalRange(numerical::Range::Numeric(
+                    (numeric!(50), numeric!(100)).into(),
+                )),
+            );
+            assert!(!pred.applies(&domain));
+        }
     }
 }
@@ -0,0 +1,49 @@
+//! Structures and traits related to projection of query results.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use iroha_data_model_derive::model;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::name::Name;
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Fields of the results of an iterable query to return.
+    ///
+    /// Projection is applied after filtering, sorting and pagination,
+    /// so these can still use the fields which are omitted from the results.
+    /// Results which aren't entities (e.g. transactions or blocks) are returned as they are.
+    #[derive(
+        Debug, Clone, Default, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum Projection {
+        /// Return the entities as they are.
+        #[default]
+        Full,
+        /// Return only the ids of the entities, as [`IdBox`](crate::IdBox)es.
+        Id,
+        /// Return the entities without their nested collections (assets of accounts,
+        /// accounts and asset definitions of domains) and with only the given keys
+        /// of the metadata. Contents of store assets are treated as their metadata.
+        Summary(Vec<Name>),
+    }
+}
+
+impl Projection {
+    /// Return the entities without nested collections and with only the given `metadata_keys`.
+    pub fn summary(metadata_keys: impl IntoIterator<Item = Name>) -> Self {
+        Self::Summary(metadata_keys.into_iter().collect())
+    }
+}
+
+pub mod prelude {
+    //! Prelude: re-export most commonly used traits, structs and macros from this module.
+    pub use super::*;
+}
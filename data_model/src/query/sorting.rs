@@ -1,12 +1,7 @@
 //! Structures and traits related to sorting.
 
 #[cfg(not(feature = "std"))]
-use alloc::{
-    format,
-    string::{String, ToString as _},
-    vec,
-    vec::Vec,
-};
+use alloc::{format, string::String, vec, vec::Vec};
 
 use iroha_data_model_derive::model;
 use iroha_schema::IntoSchema;
@@ -16,39 +11,105 @@ use serde::{Deserialize, Serialize};
 pub use self::model::*;
 use crate::{name::Name, prelude::*};
 
-const SORT_BY_KEY: &str = "sort_by_metadata_key";
-
 #[model]
 mod model {
     use super::*;
 
     /// Struct for sorting requests
+    ///
+    /// Sorting is stable: results which are equal by all of the keys
+    /// keep the order in which they are returned by the query.
     #[derive(
         Debug, Clone, Default, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
     )]
     pub struct Sorting {
-        /// Sort query result using [`Name`] of the key in [`Asset`]'s metadata.
-        pub sort_by_metadata_key: Option<Name>,
+        /// Keys to sort query result by, from the most significant to the least significant.
+        pub keys: Vec<SortKey>,
+    }
+
+    /// Single key of [`Sorting`]
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct SortKey {
+        /// Field of the query result to compare.
+        pub field: SortField,
+        /// Direction in which values of the field are ordered.
+        pub order: SortOrder,
+    }
+
+    /// Field of the query result which values are compared during sorting.
+    ///
+    /// Results which don't have the field are placed after all the other results.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub enum SortField {
+        /// Id of the entity.
+        Id,
+        /// Quantity of the numeric [`Asset`].
+        AssetQuantity,
+        /// Height of the block, or of the block the transaction belongs to.
+        BlockHeight,
+        /// Creation time of the block or of the transaction.
+        Timestamp,
+        /// Value under the path of keys in the entity's metadata (or in the store [`Asset`]).
+        Metadata(Vec<Name>),
+    }
+
+    /// Direction in which values are ordered
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum SortOrder {
+        /// From the smallest value to the largest one.
+        #[default]
+        Ascending,
+        /// From the largest value to the smallest one.
+        Descending,
     }
 }
 
 impl Sorting {
     /// Creates a sorting by [`Name`] of the key.
     pub fn by_metadata_key(key: Name) -> Self {
+        Self::by(SortKey::ascending(SortField::Metadata(vec![key])))
+    }
+
+    /// Creates a sorting by the given `key`.
+    pub fn by(key: SortKey) -> Self {
+        Self { keys: vec![key] }
+    }
+
+    /// Add `key` to sort results which are equal by all the previous keys.
+    #[must_use]
+    pub fn then_by(mut self, key: SortKey) -> Self {
+        self.keys.push(key);
+        self
+    }
+}
+
+impl SortKey {
+    /// Sort by `field` in ascending order.
+    pub fn ascending(field: SortField) -> Self {
         Self {
-            sort_by_metadata_key: Some(key),
+            field,
+            order: SortOrder::Ascending,
         }
     }
-}
 
-impl Sorting {
-    /// Converts self to iterator of tuples to be used in queries
-    ///
-    /// The length of the output iterator is not constant and has either 0 or 1 value
-    pub fn into_query_parameters(self) -> impl IntoIterator<Item = (&'static str, Name)> + Clone {
-        self.sort_by_metadata_key
-            .map(|key| (SORT_BY_KEY, key))
-            .into_iter()
+    /// Sort by `field` in descending order.
+    pub fn descending(field: SortField) -> Self {
+        Self {
+            field,
+            order: SortOrder::Descending,
+        }
     }
 }
 
@@ -9,7 +9,7 @@ use core::{
 };
 
 use derive_more::{DebugCustom, Display};
-use iroha_crypto::SignatureOf;
+use iroha_crypto::SignaturesOf;
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
@@ -20,6 +20,7 @@ use serde::{Deserialize, Serialize};
 pub use self::model::*;
 use crate::{
     account::AccountId,
+    events::data::DataEvent,
     isi::{Instruction, InstructionBox},
     metadata::UnlimitedMetadata,
     ChainId,
@@ -141,12 +142,15 @@ mod model {
         pub max_wasm_size_bytes: u64,
     }
 
-    /// Transaction that contains a signature
+    /// Transaction that contains signatures
     ///
     /// `Iroha` and its clients use [`Self`] to send transactions over the network.
     /// After a transaction is signed and before it can be processed any further,
     /// the transaction must be accepted by the `Iroha` peer.
-    /// The peer verifies the signature and checks the limits.
+    /// The peer verifies the signatures and checks the limits.
+    ///
+    /// Transactions of accounts controlled by several signatories
+    /// collect signatures of co-signers until the quorum of the account is reached.
     #[version(version = 1, versioned_alias = "SignedTransaction")]
     #[derive(
         Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Serialize, IntoSchema,
@@ -155,8 +159,8 @@ mod model {
     #[cfg_attr(feature = "std", display(fmt = "{}", "self.hash()"))]
     #[ffi_type]
     pub struct SignedTransactionV1 {
-        /// Signature of [`Self::payload`].
-        pub(super) signature: SignatureOf<TransactionPayload>,
+        /// Signatures of [`Self::payload`].
+        pub(super) signatures: SignaturesOf<TransactionPayload>,
         /// Payload of the transaction.
         pub(super) payload: TransactionPayload,
     }
@@ -185,8 +189,36 @@ mod model {
         /// Reason of rejection
         pub error: Option<error::TransactionRejectionReason>,
     }
+
+    /// Effects a transaction would have if it was committed on top of the current state
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        Getters,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct SimulatedTransaction {
+        /// Data events produced by the transaction
+        #[getset(get = "pub")]
+        pub events: Vec<DataEvent>,
+        /// Fuel consumed by the smart contract of the transaction, zero for instructions
+        #[getset(get_copy = "pub")]
+        pub fuel_consumed: u64,
+    }
 }
 
+/// Outcome of a transaction simulation: either the effects of the transaction
+/// or the reason why it would be rejected
+pub type TransactionSimulationResult =
+    Result<SimulatedTransaction, error::TransactionRejectionReason>;
+
 impl TransactionLimits {
     /// Construct [`Self`]
     pub const fn new(max_instruction_number: u64, max_wasm_size_bytes: u64) -> Self {
@@ -291,12 +323,21 @@ impl SignedTransaction {
         &tx.payload.chain_id
     }
 
-    /// Return the transaction signature
+    /// Return the transaction signatures
     #[inline]
     #[allow(private_interfaces)]
-    pub fn signature(&self) -> &SignatureOf<TransactionPayload> {
+    pub fn signatures(&self) -> &SignaturesOf<TransactionPayload> {
+        let SignedTransaction::V1(tx) = self;
+        &tx.signatures
+    }
+
+    /// Check that the transaction is signed and that all of its signatures are valid.
+    ///
+    /// Decoded transactions are checked on decoding already.
+    #[inline]
+    pub fn verify_signatures(&self) -> bool {
         let SignedTransaction::V1(tx) = self;
-        &tx.signature
+        tx.signatures.len() > 0 && tx.signatures.verify(&tx.payload).is_ok()
     }
 
     /// Calculate transaction [`Hash`](`iroha_crypto::HashOf`).
@@ -305,19 +346,46 @@ impl SignedTransaction {
         iroha_crypto::HashOf::new(self)
     }
 
+    /// Calculate [`Hash`](`iroha_crypto::Hash`) of the transaction payload.
+    ///
+    /// Unlike [`Self::hash`] it doesn't depend on the signatures
+    /// and stays the same while co-signers add their signatures.
+    #[inline]
+    pub fn hash_of_payload(&self) -> iroha_crypto::Hash {
+        let SignedTransaction::V1(tx) = self;
+        iroha_crypto::HashOf::new(&tx.payload).into()
+    }
+
     /// Sign transaction with provided key pair.
+    ///
+    /// Signature is added to the signatures which are already present.
+    /// If the transaction is already signed with the same key pair, the existing signature is kept.
     #[must_use]
     pub fn sign(self, key_pair: &iroha_crypto::KeyPair) -> SignedTransaction {
         let SignedTransaction::V1(mut tx) = self;
         let signature = iroha_crypto::SignatureOf::new(key_pair, &tx.payload);
-        tx.signature = signature;
+        tx.signatures.insert(signature);
 
         SignedTransactionV1 {
             payload: tx.payload,
-            signature: tx.signature,
+            signatures: tx.signatures,
         }
         .into()
     }
+
+    /// Add signatures of `other` transaction to the signatures of [`Self`].
+    ///
+    /// Returns `false` and leaves [`Self`] intact if payloads of transactions differ.
+    #[cfg(feature = "transparent_api")]
+    pub fn merge_signatures(&mut self, other: &SignedTransaction) -> bool {
+        let (SignedTransaction::V1(tx), SignedTransaction::V1(other)) = (self, other);
+        if tx.payload != other.payload {
+            return false;
+        }
+
+        tx.signatures.extend(other.signatures.iter().cloned());
+        true
+    }
 }
 
 #[cfg(feature = "transparent_api")]
@@ -348,7 +416,7 @@ mod candidate {
 
     #[derive(Decode, Deserialize)]
     struct SignedTransactionCandidate {
-        signature: SignatureOf<TransactionPayload>,
+        signatures: SignaturesOf<TransactionPayload>,
         payload: TransactionPayload,
     }
 
@@ -367,12 +435,16 @@ mod candidate {
 
             Ok(SignedTransactionV1 {
                 payload: self.payload,
-                signature: self.signature,
+                signatures: self.signatures,
             })
         }
 
         fn validate_signature(&self) -> Result<(), &'static str> {
-            self.signature
+            if self.signatures.len() == 0 {
+                return Err("Transaction doesn't contain any signatures");
+            }
+
+            self.signatures
                 .verify(&self.payload)
                 .map_err(|_| "Transaction contains invalid signatures")
         }
@@ -520,6 +592,32 @@ pub mod error {
             pub reason: String,
         }
 
+        /// Transaction was rejected because its signatures don't satisfy
+        /// the signatory policy of its authority
+        #[derive(
+            Debug,
+            Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[display(fmt = "Failed to check signatories: {reason}")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        // SAFETY: `SignatoryCheckFail` has no trap representation in `String`
+        #[ffi_type(unsafe {robust})]
+        pub struct SignatoryCheckFail {
+            /// Reason why signatures of the transaction are insufficient
+            pub reason: String,
+        }
+
         /// The reason for rejecting transaction which happened because of transaction.
         #[derive(
             Debug,
@@ -548,6 +646,10 @@ pub mod error {
                 #[cfg_attr(feature = "std", source)]
                 crate::query::error::FindError,
             ),
+            /// Signatures don't satisfy the signatory policy of the authority
+            ///
+            /// e.g. combined weight of signatories doesn't reach the quorum
+            SignatoryCheck(#[cfg_attr(feature = "std", source)] SignatoryCheckFail),
             /// Failed to validate transaction limits
             ///
             /// e.g. number of instructions
@@ -583,6 +685,7 @@ pub mod error {
                 NewParameter(_) => "new parameter",
                 Upgrade(_) => "upgrade",
                 Log(_) => "log",
+                RotateSignatory(_) => "rotate signatory",
             };
             write!(
                 f,
@@ -601,10 +704,16 @@ pub mod error {
     #[cfg(feature = "std")]
     impl std::error::Error for WasmExecutionFail {}
 
+    #[cfg(feature = "std")]
+    impl std::error::Error for SignatoryCheckFail {}
+
     pub mod prelude {
         //! The prelude re-exports most commonly used traits, structs and macros from this module.
 
-        pub use super::{InstructionExecutionFail, TransactionRejectionReason, WasmExecutionFail};
+        pub use super::{
+            InstructionExecutionFail, SignatoryCheckFail, TransactionRejectionReason,
+            WasmExecutionFail,
+        };
     }
 }
 
@@ -618,13 +727,26 @@ mod http {
         use super::*;
 
         /// Structure that represents the initial state of a transaction before the transaction receives any signatures.
-        #[derive(Debug, Clone)]
+        ///
+        /// It can be (de-)serialized to be signed elsewhere, e.g. on a machine without network access.
+        #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize)]
+        #[serde(transparent)]
         #[repr(transparent)]
         #[must_use]
         pub struct TransactionBuilder {
             /// [`Transaction`] payload.
             pub(super) payload: TransactionPayload,
         }
+
+        /// Transaction to run against the current state of a peer without submitting it
+        #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize)]
+        pub enum TransactionToSimulate {
+            /// Transaction signed as if it was submitted. Its signatories are checked.
+            Signed(SignedTransaction),
+            /// Transaction which isn't signed. It's simulated on behalf of its authority
+            /// without checking the signatories.
+            Unsigned(TransactionBuilder),
+        }
     }
 
     impl TransactionBuilder {
@@ -743,11 +865,11 @@ mod http {
         /// Sign transaction with provided key pair.
         #[must_use]
         pub fn sign(self, key_pair: &iroha_crypto::KeyPair) -> SignedTransaction {
-            let signature = SignatureOf::new(key_pair, &self.payload);
+            let signatures = SignaturesOf::new(key_pair, &self.payload);
 
             SignedTransactionV1 {
                 payload: self.payload,
-                signature,
+                signatures,
             }
             .into()
         }
@@ -757,9 +879,10 @@ mod http {
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
     #[cfg(feature = "http")]
-    pub use super::http::TransactionBuilder;
+    pub use super::http::{TransactionBuilder, TransactionToSimulate};
     pub use super::{
-        error::prelude::*, CommittedTransaction, Executable, SignedTransaction, WasmSmartContract,
+        error::prelude::*, CommittedTransaction, Executable, SignedTransaction,
+        SimulatedTransaction, TransactionSimulationResult, WasmSmartContract,
     };
 }
 
@@ -43,6 +43,7 @@ pub trait Visit {
         visit_new_parameter(&NewParameter),
         visit_set_parameter(&SetParameter),
         visit_log(&Log),
+        visit_rotate_signatory(&RotateSignatory),
 
         // Visit QueryBox
         visit_find_account_by_id(&FindAccountById),
@@ -80,6 +81,7 @@ pub trait Visit {
         visit_find_roles_by_account_id(&FindRolesByAccountId),
         visit_find_total_asset_quantity_by_asset_definition_id(&FindTotalAssetQuantityByAssetDefinitionId),
         visit_find_transaction_by_hash(&FindTransactionByHash),
+        visit_find_transaction_proof_by_hash(&FindTransactionProofByHash),
         visit_find_transactions_by_account_id(&FindTransactionsByAccountId),
         visit_find_trigger_by_id(&FindTriggerById),
         visit_find_trigger_key_value_by_id_and_key(&FindTriggerKeyValueByIdAndKey),
@@ -205,6 +207,7 @@ pub fn visit_query<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, qu
         visit_find_roles_by_account_id(FindRolesByAccountId),
         visit_find_total_asset_quantity_by_asset_definition_id(FindTotalAssetQuantityByAssetDefinitionId),
         visit_find_transaction_by_hash(FindTransactionByHash),
+        visit_find_transaction_proof_by_hash(FindTransactionProofByHash),
         visit_find_transactions_by_account_id(FindTransactionsByAccountId),
         visit_find_trigger_by_id(FindTriggerById),
         visit_find_trigger_key_value_by_id_and_key(FindTriggerKeyValueByIdAndKey),
@@ -240,6 +243,9 @@ pub fn visit_instruction<V: Visit + ?Sized>(
             visitor.visit_execute_trigger(authority, variant_value)
         }
         InstructionBox::Log(variant_value) => visitor.visit_log(authority, variant_value),
+        InstructionBox::RotateSignatory(variant_value) => {
+            visitor.visit_rotate_signatory(authority, variant_value)
+        }
         InstructionBox::Burn(variant_value) => visitor.visit_burn(authority, variant_value),
         InstructionBox::Fail(variant_value) => visitor.visit_fail(authority, variant_value),
         InstructionBox::Grant(variant_value) => visitor.visit_grant(authority, variant_value),
@@ -429,6 +435,7 @@ leaf_visitors! {
     visit_execute_trigger(&ExecuteTrigger),
     visit_fail(&Fail),
     visit_log(&Log),
+    visit_rotate_signatory(&RotateSignatory),
 
     // Query visitors
     visit_find_account_by_id(&FindAccountById),
@@ -466,6 +473,7 @@ leaf_visitors! {
     visit_find_roles_by_account_id(&FindRolesByAccountId),
     visit_find_total_asset_quantity_by_asset_definition_id(&FindTotalAssetQuantityByAssetDefinitionId),
     visit_find_transaction_by_hash(&FindTransactionByHash),
+    visit_find_transaction_proof_by_hash(&FindTransactionProofByHash),
     visit_find_transactions_by_account_id(&FindTransactionsByAccountId),
     visit_find_trigger_by_id(&FindTriggerById),
     visit_find_trigger_key_value_by_id_and_key(&FindTriggerKeyValueByIdAndKey),
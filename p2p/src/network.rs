@@ -3,6 +3,7 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
     net::ToSocketAddrs,
+    sync::Arc,
     time::Duration,
 };
 
@@ -15,7 +16,7 @@ use iroha_primitives::addr::SocketAddr;
 use parity_scale_codec::Encode as _;
 use tokio::{
     net::{TcpListener, TcpStream},
-    sync::{mpsc, watch},
+    sync::{mpsc, oneshot, watch},
 };
 
 use crate::{
@@ -24,9 +25,10 @@ use crate::{
     peer::{
         handles::{connected_from, connecting, PeerHandle},
         message::*,
-        Connection, ConnectionId,
+        Connection, ConnectionDirection, ConnectionId, ConnectionStats,
     },
-    unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, Post, UpdateTopology,
+    unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, PeerInfo, Post,
+    UpdateTopology,
 };
 
 /// [`NetworkBase`] actor handle.
@@ -44,6 +46,8 @@ pub struct NetworkBaseHandle<T: Pload, K: Kex, E: Enc> {
     update_topology_sender: mpsc::UnboundedSender<UpdateTopology>,
     /// Sender of [`NetworkMessage`] message
     network_message_sender: unbounded_with_len::Sender<NetworkMessage<T>>,
+    /// Sender of requests for [`PeerInfo`] of the connected peers
+    peers_info_sender: mpsc::UnboundedSender<oneshot::Sender<Vec<PeerInfo>>>,
     /// Key exchange used by network
     _key_exchange: core::marker::PhantomData<K>,
     /// Encryptor used by the network
@@ -57,6 +61,7 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
             online_peers_receiver: self.online_peers_receiver.clone(),
             update_topology_sender: self.update_topology_sender.clone(),
             network_message_sender: self.network_message_sender.clone(),
+            peers_info_sender: self.peers_info_sender.clone(),
             _key_exchange: core::marker::PhantomData::<K>,
             _encryptor: core::marker::PhantomData::<E>,
         }
@@ -85,6 +90,7 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
         let (update_topology_sender, update_topology_receiver) = mpsc::unbounded_channel();
         let (network_message_sender, network_message_receiver) =
             unbounded_with_len::unbounded_channel();
+        let (peers_info_sender, peers_info_receiver) = mpsc::unbounded_channel();
         let (peer_message_sender, peer_message_receiver) = mpsc::channel(1);
         let (service_message_sender, service_message_receiver) = mpsc::channel(1);
         let network = NetworkBase {
@@ -104,6 +110,8 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             service_message_sender,
             current_conn_id: 0,
             current_topology: HashMap::new(),
+            dropped_messages: HashMap::new(),
+            peers_info_receiver,
             idle_timeout,
             _key_exchange: core::marker::PhantomData::<K>,
             _encryptor: core::marker::PhantomData::<E>,
@@ -114,6 +122,7 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             online_peers_receiver,
             update_topology_sender,
             network_message_sender,
+            peers_info_sender,
             _key_exchange: core::marker::PhantomData,
             _encryptor: core::marker::PhantomData,
         })
@@ -154,6 +163,17 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
         f(&self.online_peers_receiver.borrow())
     }
 
+    /// Diagnostics of the connections with the currently connected peers.
+    pub async fn peers_info(&self) -> Vec<PeerInfo> {
+        let (sender, receiver) = oneshot::channel();
+        self.peers_info_sender
+            .send(sender)
+            .expect("NetworkBase must accept messages until there is at least one handle to it");
+        receiver
+            .await
+            .expect("NetworkBase must respond to messages until there is at least one handle to it")
+    }
+
     /// Wait for update of [`OnlinePeers`].
     pub async fn wait_online_peers_update<P>(
         &mut self,
@@ -202,6 +222,10 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     /// Current topology
     /// Bool determines who is responsible for initiating connection
     current_topology: HashMap<PeerId, bool>,
+    /// Number of messages which weren't delivered to the peers of the current topology
+    dropped_messages: HashMap<PublicKey, u64>,
+    /// Receiver of requests for [`PeerInfo`] of the connected peers
+    peers_info_receiver: mpsc::UnboundedReceiver<oneshot::Sender<Vec<PeerInfo>>>,
     /// Duration after which terminate connection with idle peer
     idle_timeout: Duration,
     /// Key exchange used by network
@@ -232,6 +256,10 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 _ = update_topology_interval.tick() => {
                     self.update_topology()
                 }
+                // Diagnostics are requested by operators, so their rate is low
+                Some(reply) = self.peers_info_receiver.recv() => {
+                    let _ = reply.send(self.peers_info());
+                }
                 // Every peer produce small amount of service messages so this shouldn't starve other tasks
                 Some(service_message) = self.service_message_receiver.recv() => {
                     match service_message {
@@ -305,7 +333,9 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 let is_active = self_public_key_hash > peer_public_key_hash;
                 (peer_id, is_active)
             })
-            .collect();
+            .collect::<HashMap<_, _>>();
+        self.dropped_messages
+            .retain(|public_key, _| topology.contains_key(public_key));
         self.current_topology = topology;
         self.update_topology()
     }
@@ -384,6 +414,8 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             ready_peer_handle,
             peer_message_sender,
             disambiguator,
+            direction,
+            stats,
         }: Connected<T>,
     ) {
         self.connecting_peers.remove(&connection_id);
@@ -414,6 +446,8 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             conn_id: connection_id,
             p2p_addr: peer_id.address.clone(),
             disambiguator,
+            direction,
+            stats,
         };
         let _ = peer_message_sender.send(self.peer_message_sender.clone());
         self.peers.insert(peer_id.public_key().clone(), ref_peer);
@@ -441,18 +475,23 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                     iroha_logger::error!(peer=%peer_id, "Failed to send message to peer");
                     self.peers.remove(&peer_id.public_key);
                     Self::remove_online_peer(&self.online_peers_sender, &peer_id);
+                    self.message_dropped(&peer_id.public_key);
                 }
             }
             None if peer_id.public_key() == self.key_pair.public_key() => {
                 #[cfg(debug_assertions)]
                 iroha_logger::trace!("Not sending message to myself")
             }
-            _ => iroha_logger::warn!(peer=%peer_id, "Peer not found. Message not sent."),
+            _ => {
+                iroha_logger::warn!(peer=%peer_id, "Peer not found. Message not sent.");
+                self.message_dropped(&peer_id.public_key);
+            }
         }
     }
 
     fn broadcast(&mut self, Broadcast { data }: Broadcast<T>) {
         iroha_logger::trace!("Broadcast message");
+        let mut failed = Vec::new();
         let Self {
             peers,
             online_peers_sender,
@@ -463,11 +502,47 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 let peer_id = PeerId::new(ref_peer.p2p_addr.clone(), public_key.clone());
                 iroha_logger::error!(peer=%peer_id, "Failed to send message to peer");
                 Self::remove_online_peer(online_peers_sender, &peer_id);
+                failed.push(public_key.clone());
                 false
             } else {
                 true
             }
         });
+
+        let unreachable = self
+            .current_topology
+            .keys()
+            .map(PeerId::public_key)
+            .filter(|public_key| {
+                !self.peers.contains_key(*public_key) && *public_key != self.key_pair.public_key()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for public_key in failed.iter().chain(&unreachable) {
+            self.message_dropped(public_key);
+        }
+    }
+
+    /// Count a message which wasn't delivered to the peer with `public_key`
+    fn message_dropped(&mut self, public_key: &PublicKey) {
+        if self.current_topology.contains_key(public_key) {
+            *self.dropped_messages.entry(public_key.clone()).or_default() += 1;
+        }
+    }
+
+    fn peers_info(&self) -> Vec<PeerInfo> {
+        self.peers
+            .iter()
+            .map(|(public_key, peer)| PeerInfo {
+                peer_id: PeerId::new(peer.p2p_addr.clone(), public_key.clone()),
+                direction: peer.direction,
+                uptime: peer.stats.uptime(),
+                since_last_message: peer.stats.since_last_message(),
+                bytes_sent: peer.stats.bytes_sent(),
+                bytes_received: peer.stats.bytes_received(),
+                dropped_messages: self.dropped_messages.get(public_key).copied().unwrap_or(0),
+            })
+            .collect()
     }
 
     async fn peer_message(&mut self, PeerMessage(peer_id, msg): PeerMessage<T>) {
@@ -544,6 +619,25 @@ pub mod message {
         pub data: T,
     }
 
+    /// Diagnostics of the connection with a peer
+    #[derive(Clone, Debug)]
+    pub struct PeerInfo {
+        /// Id of the peer
+        pub peer_id: PeerId,
+        /// Which side has established the connection
+        pub direction: ConnectionDirection,
+        /// Time since the connection was established
+        pub uptime: Duration,
+        /// Time since the latest message was received from the peer
+        pub since_last_message: Option<Duration>,
+        /// Bytes sent to the peer over the connection
+        pub bytes_sent: u64,
+        /// Bytes received from the peer over the connection
+        pub bytes_received: u64,
+        /// Messages to the peer which weren't delivered, counted across its connections
+        pub dropped_messages: u64,
+    }
+
     /// Message send to network by other actors.
     pub(crate) enum NetworkMessage<T> {
         Post(Post<T>),
@@ -574,4 +668,8 @@ struct RefPeer<T: Pload> {
     /// To solve this situation disambiguator value is used.
     /// It's equal for both peers and when peer receive connection for peer already present in peers set it just select connection with higher value.
     disambiguator: u64,
+    /// Which side has established the connection
+    direction: ConnectionDirection,
+    /// Traffic of the connection
+    stats: Arc<ConnectionStats>,
 }
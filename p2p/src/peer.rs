@@ -1,5 +1,10 @@
 //! Tokio actor Peer
 
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
 use bytes::{Buf, BufMut, BytesMut};
 use iroha_data_model::prelude::PeerId;
 use message::*;
@@ -119,6 +124,7 @@ mod run {
         }: RunPeerArgs<T, P>,
     ) {
         let conn_id = peer.connection_id();
+        let direction = peer.direction();
         let mut peer_id = None;
 
         iroha_logger::trace!("Peer created");
@@ -158,6 +164,7 @@ mod run {
             let (post_sender, mut post_receiver) = unbounded_with_len::unbounded_channel();
             let (peer_message_sender, peer_message_receiver) = oneshot::channel();
             let ready_peer_handle = handles::PeerHandle { post_sender };
+            let stats = Arc::new(ConnectionStats::new());
             if service_message_sender
                 .send(ServiceMessage::Connected(Connected {
                     connection_id,
@@ -165,6 +172,8 @@ mod run {
                     ready_peer_handle,
                     peer_message_sender,
                     disambiguator,
+                    direction,
+                    stats: Arc::clone(&stats),
                 }))
                 .await
                 .is_err()
@@ -184,8 +193,9 @@ mod run {
 
             iroha_logger::trace!("Peer connected");
 
-            let mut message_reader = MessageReader::new(read, cryptographer.clone());
-            let mut message_sender = MessageSender::new(write, cryptographer);
+            let mut message_reader =
+                MessageReader::new(read, cryptographer.clone(), Arc::clone(&stats));
+            let mut message_sender = MessageSender::new(write, cryptographer, stats);
 
             let mut idle_interval = tokio::time::interval_at(Instant::now() + idle_timeout, idle_timeout);
             let mut ping_interval = tokio::time::interval_at(Instant::now() + idle_timeout / 2, idle_timeout / 2);
@@ -284,18 +294,28 @@ mod run {
     /// Trait for peer stages that might be used as starting point for peer's [`run`] function.
     pub(super) trait Entrypoint<K: Kex, E: Enc>: Handshake<K, E> + Send + 'static {
         fn connection_id(&self) -> ConnectionId;
+
+        fn direction(&self) -> ConnectionDirection;
     }
 
     impl<K: Kex, E: Enc> Entrypoint<K, E> for Connecting {
         fn connection_id(&self) -> ConnectionId {
             self.connection_id
         }
+
+        fn direction(&self) -> ConnectionDirection {
+            ConnectionDirection::Outgoing
+        }
     }
 
     impl<K: Kex, E: Enc> Entrypoint<K, E> for ConnectedFrom {
         fn connection_id(&self) -> ConnectionId {
             self.connection.id
         }
+
+        fn direction(&self) -> ConnectionDirection {
+            ConnectionDirection::Incoming
+        }
     }
 
     /// Cancellation-safe way to read messages from tcp stream
@@ -303,15 +323,21 @@ mod run {
         read: OwnedReadHalf,
         buffer: bytes::BytesMut,
         cryptographer: Cryptographer<E>,
+        stats: Arc<ConnectionStats>,
     }
 
     impl<E: Enc> MessageReader<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(read: OwnedReadHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(
+            read: OwnedReadHalf,
+            cryptographer: Cryptographer<E>,
+            stats: Arc<ConnectionStats>,
+        ) -> Self {
             Self {
                 read,
                 cryptographer,
+                stats,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: BytesMut::with_capacity(1024),
             }
@@ -361,6 +387,7 @@ mod run {
             let decoded = DecodeAll::decode_all(&mut decrypted.as_slice())?;
 
             self.buffer.advance(size + Self::U32_SIZE);
+            self.stats.record_received(size + Self::U32_SIZE);
 
             Ok(Some(decoded))
         }
@@ -370,15 +397,21 @@ mod run {
         write: OwnedWriteHalf,
         cryptographer: Cryptographer<E>,
         buffer: BytesMut,
+        stats: Arc<ConnectionStats>,
     }
 
     impl<E: Enc> MessageSender<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(write: OwnedWriteHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(
+            write: OwnedWriteHalf,
+            cryptographer: Cryptographer<E>,
+            stats: Arc<ConnectionStats>,
+        ) -> Self {
             Self {
                 write,
                 cryptographer,
+                stats,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: BytesMut::with_capacity(1024),
             }
@@ -408,6 +441,7 @@ mod run {
             self.write.write_all(&self.buffer[..]).await?;
             self.write.flush().await?;
             self.buffer.advance(encrypted_size + Self::U32_SIZE);
+            self.stats.record_sent(encrypted_size + Self::U32_SIZE);
             assert!(
                 !self.buffer.has_remaining(),
                 "Buffer must be empty at this point"
@@ -748,6 +782,10 @@ pub mod message {
         pub peer_message_sender: oneshot::Sender<mpsc::Sender<PeerMessage<T>>>,
         /// Disambiguator of connection (equal for both peers)
         pub disambiguator: u64,
+        /// Which side has established the connection
+        pub direction: ConnectionDirection,
+        /// Traffic of the connection
+        pub stats: Arc<ConnectionStats>,
     }
 
     /// Messages received from Peer
@@ -841,6 +879,71 @@ impl Connection {
     }
 }
 
+/// Side which has established the connection with a peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionDirection {
+    /// The peer has connected to us
+    Incoming,
+    /// We have connected to the peer
+    Outgoing,
+}
+
+/// Traffic of a [`Connection`] after the handshake, updated by the peer actor
+#[derive(Debug)]
+pub struct ConnectionStats {
+    connected_at: std::time::Instant,
+    /// Milliseconds since `connected_at`, [`u64::MAX`] if nothing was received yet
+    last_received_ms: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+}
+
+impl ConnectionStats {
+    fn new() -> Self {
+        Self {
+            connected_at: std::time::Instant::now(),
+            last_received_ms: AtomicU64::new(u64::MAX),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+        }
+    }
+
+    fn record_sent(&self, bytes: usize) {
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn record_received(&self, bytes: usize) {
+        self.bytes_received
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+        let since_connected = u64::try_from(self.uptime().as_millis()).unwrap_or(u64::MAX - 1);
+        self.last_received_ms
+            .store(since_connected, Ordering::Relaxed);
+    }
+
+    /// Time since the connection was established
+    pub fn uptime(&self) -> Duration {
+        self.connected_at.elapsed()
+    }
+
+    /// Time since the latest message was received from the peer
+    pub fn since_last_message(&self) -> Option<Duration> {
+        match self.last_received_ms.load(Ordering::Relaxed) {
+            u64::MAX => None,
+            ms => Some(self.uptime().saturating_sub(Duration::from_millis(ms))),
+        }
+    }
+
+    /// Bytes sent to the peer, including the framing of messages
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent.load(Ordering::Relaxed)
+    }
+
+    /// Bytes received from the peer, including the framing of messages
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+}
+
 mod garbage {
     //! Module with functions to read and write garbage.
     // TODO: why do we need this?
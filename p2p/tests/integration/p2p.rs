@@ -191,7 +191,7 @@ async fn two_networks() {
     info!("Posting message...");
     network1.post(Post {
         data: TestMessage("Some data to send to peer".to_owned()),
-        peer_id: peer2,
+        peer_id: peer2.clone(),
     });
 
     tokio::time::timeout(delay, &mut messages2)
@@ -209,6 +209,22 @@ async fn two_networks() {
     let connected_peers2 = network2.online_peers(HashSet::len);
     assert_eq!(connected_peers2, 1);
 
+    let peers_info1 = network1.peers_info().await;
+    let [info1] = peers_info1.as_slice() else {
+        panic!("Network 1 should be connected to a single peer");
+    };
+    let peers_info2 = network2.peers_info().await;
+    let [info2] = peers_info2.as_slice() else {
+        panic!("Network 2 should be connected to a single peer");
+    };
+    assert_eq!(info1.peer_id, peer2);
+    assert_eq!(info2.peer_id, peer1);
+    assert_ne!(info1.direction, info2.direction);
+    assert!(info1.bytes_sent > 0);
+    assert_eq!(info1.bytes_sent, info2.bytes_received);
+    assert!(info2.since_last_message.is_some());
+    assert_eq!(info1.dropped_messages, 0);
+
     // Connecting to the same peer from network1
     network1.update_topology(UpdateTopology(topology1));
     tokio::time::sleep(delay).await;
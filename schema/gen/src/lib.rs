@@ -1,9 +1,12 @@
 //! Iroha schema generation support library. Contains the
 //! `build_schemas` `fn`, which is the function which decides which
 //! types are included in the schema.
-use iroha_crypto::MerkleTree;
+use iroha_crypto::{MerkleTree, SparseMerkleTree};
 use iroha_data_model::{
-    block::stream::{BlockMessage, BlockSubscriptionRequest},
+    block::{
+        stream::{BlockMessage, BlockSubscriptionRequest},
+        StateEntry,
+    },
     query::QueryOutputBox,
     BatchedResponse,
 };
@@ -39,10 +42,13 @@ pub fn build_schemas() -> MetaMap {
     schemas! {
         // Transaction
         SignedTransaction,
+        Result<SimulatedTransaction, TransactionRejectionReason>,
 
         // Query + response
         SignedQuery,
         BatchedResponse<QueryOutputBox>,
+        QueryBatch,
+        Vec<Result<BatchedResponse<QueryOutputBox>, ValidationFail>>,
 
         // Event stream
         EventMessage,
@@ -54,6 +60,8 @@ pub fn build_schemas() -> MetaMap {
 
         // Never referenced, but present in type signature. Like `PhantomData<X>`
         MerkleTree<SignedTransaction>,
+        SparseMerkleTree<StateEntry>,
+        StateEntry,
     }
 }
 
@@ -63,9 +71,14 @@ types!(
     AccountEventFilter,
     AccountEventSet,
     AccountId,
+    AccountPermission,
     AccountPermissionChanged,
+    AccountRole,
     AccountRoleChanged,
     Action,
+    AggregateFunction,
+    AggregateGroup,
+    Aggregation,
     Algorithm,
     Asset,
     AssetChanged,
@@ -84,14 +97,17 @@ types!(
     AssetValue,
     AssetValueType,
     AtIndex,
+    AtKey,
     BTreeMap<AccountId, Account>,
     BTreeMap<AssetDefinitionId, AssetDefinition>,
     BTreeMap<AssetDefinitionId, Numeric>,
     BTreeMap<AssetId, Asset>,
+    BTreeMap<PublicKey, u8>,
     BTreeMap<Name, MetadataValueBox>,
     BTreeSet<Permission>,
     BTreeSet<PermissionId>,
     BTreeSet<SignatureWrapperOf<BlockPayload>>,
+    BTreeSet<SignatureWrapperOf<TransactionPayload>>,
     BatchedResponse<QueryOutputBox>,
     BatchedResponseV1<QueryOutputBox>,
     BlockEvent,
@@ -110,6 +126,7 @@ types!(
     BurnBox,
     ChainId,
     CommittedTransaction,
+    Comparison,
     ConfigurationEvent,
     ConfigurationEventFilter,
     ConfigurationEventSet,
@@ -127,6 +144,7 @@ types!(
     DomainOwnerChanged,
     Duration,
     EventBox,
+    EventCursor,
     EventMessage,
     EventSubscriptionRequest,
     Executable,
@@ -179,6 +197,7 @@ types!(
     FindRolesByAccountId,
     FindTotalAssetQuantityByAssetDefinitionId,
     FindTransactionByHash,
+    FindTransactionProofByHash,
     FindTransactionsByAccountId,
     FindTriggerById,
     FindTriggerKeyValueByIdAndKey,
@@ -188,10 +207,12 @@ types!(
     Grant<Permission, Role>,
     Grant<RoleId, Account>,
     GrantBox,
+    GroupBy,
     Hash,
     HashOf<MerkleTree<SignedTransaction>>,
     HashOf<SignedBlock>,
     HashOf<SignedTransaction>,
+    HashOf<SparseMerkleTree<StateEntry>>,
     IdBox,
     IdentifiableBox,
     InstructionBox,
@@ -199,6 +220,7 @@ types!(
     InstructionExecutionError,
     InstructionExecutionFail,
     InstructionType,
+    Interval<Numeric>,
     InvalidParameterError,
     IpfsPath,
     Ipv4Addr,
@@ -207,6 +229,7 @@ types!(
     Level,
     Log,
     MathError,
+    MerkleProof<SignedTransaction>,
     MerkleTree<SignedTransaction>,
     Metadata,
     MetadataChanged<AccountId>,
@@ -237,22 +260,28 @@ types!(
     Option<u32>,
     Option<u64>,
     Option<AccountId>,
+    Option<Aggregation>,
     Option<AssetDefinitionId>,
     Option<AssetId>,
     Option<BlockStatus>,
     Option<DomainId>,
     Option<Duration>,
+    Option<EventCursor>,
+    Option<GroupBy>,
     Option<HashOf<MerkleTree<SignedTransaction>>>,
     Option<HashOf<SignedBlock>>,
     Option<HashOf<SignedTransaction>>,
+    Option<HashOf<SparseMerkleTree<StateEntry>>>,
+    Option<IdBox>,
     Option<IpfsPath>,
-    Option<Name>,
     Option<NonZeroU32>,
     Option<NonZeroU64>,
+    Option<Numeric>,
     Option<Option<u64>>,
     Option<ParameterId>,
     Option<PeerId>,
     Option<RoleId>,
+    Option<SignatoryPolicy>,
     Option<String>,
     Option<TimeInterval>,
     Option<TransactionRejectionReason>,
@@ -274,11 +303,15 @@ types!(
     PipelineEventBox,
     PipelineEventFilterBox,
     PredicateBox,
+    Projection,
     PublicKey,
+    QueryBatch,
+    QueryBatchV1,
     QueryBox,
     QueryExecutionFail,
     QueryOutputBox,
     QueryOutputPredicate,
+    Range,
     Register<Account>,
     Register<Asset>,
     Register<AssetDefinition>,
@@ -295,6 +328,8 @@ types!(
     RemoveKeyValueBox,
     Repeats,
     RepetitionError,
+    Result<BatchedResponse<QueryOutputBox>, ValidationFail>,
+    Result<SimulatedTransaction, TransactionRejectionReason>,
     Revoke<Permission, Account>,
     Revoke<Permission, Role>,
     Revoke<RoleId, Account>,
@@ -304,6 +339,7 @@ types!(
     RoleEventFilter,
     RoleEventSet,
     RoleId,
+    RotateSignatory,
     SemiInterval<Numeric>,
     SemiInterval<u128>,
     SemiRange,
@@ -314,24 +350,34 @@ types!(
     SetKeyValue<Trigger>,
     SetKeyValueBox,
     SetParameter,
+    SignatoryCheckFail,
+    SignatoryPolicy,
     Signature,
     SignatureOf<BlockPayload>,
     SignatureOf<ClientQueryPayload>,
     SignatureOf<TransactionPayload>,
     SignatureWrapperOf<BlockPayload>,
+    SignatureWrapperOf<TransactionPayload>,
     SignaturesOf<BlockPayload>,
+    SignaturesOf<TransactionPayload>,
     SignedBlock,
     SignedBlockV1,
     SignedQuery,
     SignedQueryV1,
     SignedTransaction,
     SignedTransactionV1,
+    SimulatedTransaction,
     SizeError,
     SocketAddr,
     SocketAddrHost,
     SocketAddrV4,
     SocketAddrV6,
+    SortField,
+    SortKey,
+    SortOrder,
     Sorting,
+    SparseMerkleTree<StateEntry>,
+    StateEntry,
     String,
     StringPredicate,
     JsonString,
@@ -344,6 +390,7 @@ types!(
     TransactionLimitError,
     TransactionLimits,
     TransactionPayload,
+    TransactionProof,
     TransactionQueryOutput,
     TransactionRejectionReason,
     TransactionStatus,
@@ -376,13 +423,19 @@ types!(
     Upgrade,
     ValidationFail,
     Vec<CommittedTransaction>,
+    Vec<DataEvent>,
     Vec<EventBox>,
     Vec<EventFilterBox>,
     Vec<InstructionBox>,
     Vec<MetadataValueBox>,
+    Vec<Name>,
+    Vec<Option<HashOf<SignedTransaction>>>,
     Vec<PeerId>,
     Vec<PredicateBox>,
     Vec<QueryOutputBox>,
+    Vec<Result<BatchedResponse<QueryOutputBox>, ValidationFail>>,
+    Vec<SignedQuery>,
+    Vec<SortKey>,
     Vec<u8>,
     WasmExecutionFail,
     WasmSmartContract,
@@ -413,7 +466,8 @@ pub mod complete_data_model {
         block::{
             error::BlockRejectionReason,
             stream::{BlockMessage, BlockSubscriptionRequest},
-            BlockHeader, BlockPayload, SignedBlock, SignedBlockV1,
+            AccountPermission, AccountRole, BlockHeader, BlockPayload, SignedBlock, SignedBlockV1,
+            StateEntry,
         },
         domain::NewDomain,
         events::pipeline::{BlockEventFilter, TransactionEventFilter},
@@ -430,13 +484,16 @@ pub mod complete_data_model {
         parameter::ParameterValueBox,
         prelude::*,
         query::{
+            aggregation::{AggregateFunction, AggregateGroup, Aggregation, GroupBy},
             error::{FindError, QueryExecutionFail},
             predicate::{
-                numerical::{SemiInterval, SemiRange},
+                numerical::{Comparison, Interval, Range, SemiInterval, SemiRange},
                 string::StringPredicate,
-                value::{AtIndex, Container, QueryOutputPredicate},
+                value::{AtIndex, AtKey, Container, QueryOutputPredicate},
                 GenericPredicateBox, NonTrivial, PredicateBox,
             },
+            projection::Projection,
+            sorting::{SortField, SortKey, SortOrder},
             ForwardCursor, Pagination, QueryOutputBox, Sorting,
         },
         transaction::{
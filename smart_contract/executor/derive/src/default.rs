@@ -159,6 +159,7 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_new_parameter(operation: &NewParameter)",
         "fn visit_upgrade(operation: &Upgrade)",
         "fn visit_log(operation: &Log)",
+        "fn visit_rotate_signatory(operation: &RotateSignatory)",
         "fn visit_fail(operation: &Fail)",
     ]
     .into_iter()
@@ -6,8 +6,8 @@ pub mod permissions;
 use alloc::format;
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
-    visit_unregister_account,
+    visit_register_account, visit_remove_account_key_value, visit_rotate_signatory,
+    visit_set_account_key_value, visit_unregister_account,
 };
 pub use asset::{
     visit_burn_asset_numeric, visit_mint_asset_numeric, visit_register_asset,
@@ -94,6 +94,9 @@ pub fn visit_instruction<V: Validate + Visit + ?Sized>(
         InstructionBox::Log(isi) => {
             executor.visit_log(authority, isi);
         }
+        InstructionBox::RotateSignatory(isi) => {
+            executor.visit_rotate_signatory(authority, isi);
+        }
         InstructionBox::ExecuteTrigger(isi) => {
             executor.visit_execute_trigger(authority, isi);
         }
@@ -537,6 +540,23 @@ pub mod account {
         );
     }
 
+    pub fn visit_rotate_signatory<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RotateSignatory,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        if account_id == authority {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't rotate signatories of another account");
+    }
+
     fn is_token_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
             return false;
@@ -12,8 +12,8 @@ use data_model::{
     isi::Instruction,
     prelude::*,
     query::{
-        cursor::ForwardCursor, predicate::PredicateBox, sorting::Sorting, Pagination, Query,
-        QueryOutputBox,
+        cursor::ForwardCursor, predicate::PredicateBox, sorting::Sorting, Pagination, Projection,
+        Query, QueryOutputBox,
     },
     BatchedResponse,
 };
@@ -162,6 +162,7 @@ pub struct SmartContractQuery<'a, Q> {
     sorting: Sorting,
     pagination: Pagination,
     fetch_size: FetchSize,
+    projection: Projection,
 }
 
 impl<Q: Query> Encode for SmartContractQuery<'_, Q> {
@@ -172,6 +173,7 @@ impl<Q: Query> Encode for SmartContractQuery<'_, Q> {
         self.sorting.encode_to(&mut output);
         self.pagination.encode_to(&mut output);
         self.fetch_size.encode_to(&mut output);
+        self.projection.encode_to(&mut output);
         output
     }
 }
@@ -205,6 +207,9 @@ pub trait ExecuteIterableQueryOnHost: Sized {
 
     /// Set fetch size for a query. Default is [`DEFAULT_FETCH_SIZE`]
     fn fetch_size(&self, fetch_size: FetchSize) -> SmartContractQuery<Self>;
+
+    /// Select the fields of the query results to return
+    fn project(&self, projection: Projection) -> SmartContractQuery<Self>;
 }
 
 impl<Q> ExecuteQueryOnHost for Q
@@ -222,6 +227,7 @@ where
             sorting: Sorting::default(),
             pagination: Pagination::default(),
             fetch_size: FetchSize::default(),
+            projection: Projection::default(),
         }
         .execute()
     }
@@ -243,6 +249,7 @@ where
             sorting: Sorting::default(),
             pagination: Pagination::default(),
             fetch_size: FetchSize::default(),
+            projection: Projection::default(),
         }
     }
 
@@ -254,6 +261,7 @@ where
             sorting,
             pagination: Pagination::default(),
             fetch_size: FetchSize::default(),
+            projection: Projection::default(),
         }
     }
 
@@ -265,6 +273,7 @@ where
             sorting: Sorting::default(),
             pagination,
             fetch_size: FetchSize::default(),
+            projection: Projection::default(),
         }
     }
 
@@ -276,6 +285,19 @@ where
             sorting: Sorting::default(),
             pagination: Pagination::default(),
             fetch_size,
+            projection: Projection::default(),
+        }
+    }
+
+    #[must_use]
+    fn project(&self, projection: Projection) -> SmartContractQuery<Self> {
+        SmartContractQuery {
+            query: self,
+            filter: PredicateBox::default(),
+            sorting: Sorting::default(),
+            pagination: Pagination::default(),
+            fetch_size: FetchSize::default(),
+            projection,
         }
     }
 }
@@ -349,6 +371,13 @@ where
         self.fetch_size = fetch_size;
         self
     }
+
+    /// Select the fields of the query results to return
+    #[must_use]
+    pub fn project(mut self, projection: Projection) -> Self {
+        self.projection = projection;
+        self
+    }
 }
 
 /// Cursor over query results implementing [`IntoIterator`].
@@ -541,6 +570,8 @@ mod tests {
         pub pagination: Pagination,
         /// Amount of results to fetch.
         pub fetch_size: FetchSize,
+        /// Fields of the query results to return.
+        pub projection: Projection,
     }
 
     #[derive(Decode)]
@@ -548,7 +579,16 @@ mod tests {
     struct SmartContractQueryRequest(pub iroha_data_model::query::QueryRequest<SmartContractQuery>);
 
     impl SmartContractQueryRequest {
-        fn unwrap_query(self) -> (QueryBox, PredicateBox, Sorting, Pagination, FetchSize) {
+        fn unwrap_query(
+            self,
+        ) -> (
+            QueryBox,
+            PredicateBox,
+            Sorting,
+            Pagination,
+            FetchSize,
+            Projection,
+        ) {
             match self.0 {
                 iroha_data_model::query::QueryRequest::Query(query) => (
                     query.query,
@@ -556,6 +596,7 @@ mod tests {
                     query.sorting,
                     query.pagination,
                     query.fetch_size,
+                    query.projection,
                 ),
                 iroha_data_model::query::QueryRequest::Cursor(_) => {
                     panic!("Expected query, got cursor")
@@ -651,6 +692,7 @@ mod tests {
             fetch_size: FetchSize {
                 fetch_size: Some(NonZeroU32::new(56_785_678).unwrap()),
             },
+            projection: Projection::summary([Name::from_str("metadata_key").unwrap()]),
         };
         let smart_contract_query_rq = super::QueryRequest::Query(smart_contract_query.clone());
         let encoded_rq = smart_contract_query_rq.encode();
@@ -675,6 +717,7 @@ mod tests {
                 assert_eq!(q.sorting(), &smart_contract_query.sorting);
                 assert_eq!(q.pagination(), &smart_contract_query.pagination);
                 assert_eq!(q.fetch_size(), &smart_contract_query.fetch_size);
+                assert_eq!(q.projection(), &smart_contract_query.projection);
             }
             q => panic!(
                 "Unexpected query request value decoded for data model query: {:?}",
@@ -2,6 +2,7 @@
 
 use std::{ops::Deref, time::Duration};
 
+use iroha_data_model::peer::PeerId;
 use parity_scale_codec::{Compact, Decode, Encode};
 use prometheus::{
     core::{AtomicU64, GenericGauge, GenericGaugeVec},
@@ -70,6 +71,46 @@ pub struct Status {
     pub queue_size: u64,
 }
 
+/// Response body for GET peers request
+#[derive(Clone, Debug, Deserialize, Serialize, Encode, Decode)]
+pub struct PeersStatus {
+    /// Role of the reporting peer in the current consensus topology, e.g. `Leader` or `ObservingPeer`
+    pub role: String,
+    /// Currently connected peers excluding the reporting peer
+    pub peers: Vec<PeerStatus>,
+}
+
+/// Connection diagnostics of a peer in [`PeersStatus`]
+#[derive(Clone, Debug, Deserialize, Serialize, Encode, Decode)]
+pub struct PeerStatus {
+    /// Id of the peer, including its address
+    pub id: PeerId,
+    /// Which side has established the connection
+    pub direction: ConnectionDirection,
+    /// Time since the connection was established
+    pub uptime: Uptime,
+    /// Time since the latest message was received from the peer
+    pub since_last_message: Option<Uptime>,
+    /// Bytes sent to the peer over the connection
+    #[codec(compact)]
+    pub bytes_sent: u64,
+    /// Bytes received from the peer over the connection
+    #[codec(compact)]
+    pub bytes_received: u64,
+    /// Messages to the peer which weren't delivered, counted across its connections
+    #[codec(compact)]
+    pub dropped_messages: u64,
+}
+
+/// Side which has established the connection with a peer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, Encode, Decode)]
+pub enum ConnectionDirection {
+    /// The peer has connected to the reporting peer
+    Incoming,
+    /// The reporting peer has connected to the peer
+    Outgoing,
+}
+
 impl<T: Deref<Target = Metrics>> From<&T> for Status {
     fn from(value: &T) -> Self {
         let val: &Metrics = value;
@@ -112,6 +153,8 @@ pub struct Metrics {
     pub queue_size: GenericGauge<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: DroppedMessagesCounter,
+    /// Number of Torii requests rejected due to rate limits
+    pub rate_limited_requests: IntCounterVec,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
@@ -162,6 +205,14 @@ impl Default for Metrics {
             .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let rate_limited_requests = IntCounterVec::new(
+            Opts::new(
+                "rate_limited_requests",
+                "Torii requests rejected due to rate limits",
+            ),
+            &["endpoint", "limit"],
+        )
+        .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -186,7 +237,8 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
-            dropped_messages
+            dropped_messages,
+            rate_limited_requests
         );
 
         Self {
@@ -202,6 +254,7 @@ impl Default for Metrics {
             view_changes,
             queue_size,
             dropped_messages,
+            rate_limited_requests,
             registry,
         }
     }
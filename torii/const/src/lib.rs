@@ -8,12 +8,20 @@ pub mod uri {
         iroha_primitives::addr::socket_addr!(127.0.0.1:8080);
     /// Query URI is used to handle incoming Query requests.
     pub const QUERY: &str = "query";
+    /// Query batch URI is used to handle several queries evaluated against the same state.
+    pub const QUERY_BATCH: &str = "query_batch";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "transaction";
+    /// Transaction simulation URI is used to run transactions against the current state without submitting them.
+    pub const TRANSACTION_SIMULATION: &str = "simulate_transaction";
+    /// URI for listing multisig transactions which are waiting for co-signers.
+    pub const PENDING_TRANSACTIONS: &str = "pending_transactions";
     /// Block URI is used to handle incoming Block requests.
     pub const CONSENSUS: &str = "consensus";
     /// Health URI is used to handle incoming Healthcheck requests.
     pub const HEALTH: &str = "health";
+    /// Readiness URI reports whether the peer is synchronized and able to serve requests.
+    pub const READY: &str = "ready";
     /// The URI used for block synchronization.
     pub const BLOCK_SYNC: &str = "block/sync";
     /// The web socket uri used to subscribe to block and transactions statuses.
@@ -24,6 +32,8 @@ pub mod uri {
     pub const CONFIGURATION: &str = "configuration";
     /// URI to report status for administration
     pub const STATUS: &str = "status";
+    /// URI to report the connected peers and their connection diagnostics
+    pub const PEERS: &str = "peers";
     ///  Metrics URI is used to export metrics according to [Prometheus
     ///  Guidance](https://prometheus.io/docs/instrumenting/writing_exporters/).
     pub const METRICS: &str = "metrics";
@@ -34,3 +44,79 @@ pub mod uri {
     /// URI for getting cpu profile
     pub const PROFILE: &str = "debug/pprof/profile";
 }
+
+pub mod compression {
+    //! Compression of the Torii responses.
+    //!
+    //! HTTP responses are compressed with `zstd` or `gzip` as negotiated through the
+    //! `Accept-Encoding` header. Messages of the block and event websockets are compressed
+    //! if the client sends [`WS_COMPRESSION_HEADER`] with [`DEFLATE`] and the peer confirms
+    //! it with the same header in the handshake response.
+    //!
+    //! Every message is compressed independently, the same way as by the `permessage-deflate`
+    //! extension (RFC 7692) without context takeover: raw deflate ended with a sync flush
+    //! whose [`SYNC_FLUSH_TAIL`] is stripped. The websocket implementation used by Torii
+    //! doesn't support the reserved frame bits the extension relies on, so it can't be
+    //! negotiated through `Sec-WebSocket-Extensions` without breaking the clients offering it.
+    //!
+    //! # Framing
+    //!
+    //! Clients which don't send [`WS_COMPRESSION_HEADER`] receive the messages uncompressed.
+    //! To receive them compressed, a client:
+    //!
+    //! 1. Sends `x-iroha-ws-compression: deflate` in the handshake request.
+    //!    Other values and lists which don't contain `deflate` are ignored.
+    //! 2. Checks that the handshake response has the same header with `deflate`.
+    //!    Without it the peer doesn't support the compression and sends messages as is.
+    //! 3. Decodes every binary message by appending [`SYNC_FLUSH_TAIL`] to its payload and
+    //!    inflating the result as raw deflate (RFC 1951, no zlib or gzip header).
+    //!    The result is the SCALE encoded message, the same as sent without compression.
+    //!
+    //! Messages sent by the client are never compressed, and the frames don't have `RSV1` set.
+
+    /// Header negotiating the compression of the messages sent by the peer over a websocket
+    pub const WS_COMPRESSION_HEADER: &str = "x-iroha-ws-compression";
+    /// Per-message deflate applied to every binary message
+    pub const DEFLATE: &str = "deflate";
+    /// Bytes ending every sync flushed deflate stream, which aren't sent over the websocket
+    pub const SYNC_FLUSH_TAIL: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+}
+
+pub mod admin {
+    //! Authentication of the requests to the administrative endpoints.
+    //!
+    //! If the peer is configured with admin public keys, requests to `configuration`,
+    //! `status`, `metrics` and the profiling endpoints must be signed by one of them.
+    //! The signature covers the [`payload`] of the request and is only accepted
+    //! within [`TIMESTAMP_TOLERANCE_MS`] of the peer clock. The peer accepts
+    //! every nonce only once, and the signed host keeps the request
+    //! from being replayed to other peers.
+
+    /// Header with the multihash of the public key which signed the request
+    pub const PUBLIC_KEY_HEADER: &str = "x-iroha-admin-public-key";
+    /// Header with the time of signing, in milliseconds since the Unix epoch
+    pub const TIMESTAMP_HEADER: &str = "x-iroha-admin-timestamp";
+    /// Header with the random nonce unique to the request
+    pub const NONCE_HEADER: &str = "x-iroha-admin-nonce";
+    /// Header with the hex encoded signature of the request [`payload`]
+    pub const SIGNATURE_HEADER: &str = "x-iroha-admin-signature";
+    /// Maximum difference between the signing time and the peer clock
+    pub const TIMESTAMP_TOLERANCE_MS: u64 = 30_000;
+    /// Maximum length of the nonce
+    pub const MAX_NONCE_LEN: usize = 64;
+
+    /// Payload of the request which is signed:
+    /// method, `Host` header, path, timestamp and nonce followed by the body
+    pub fn payload(
+        method: &str,
+        host: &str,
+        path: &str,
+        timestamp_ms: u64,
+        nonce: &str,
+        body: &[u8],
+    ) -> Vec<u8> {
+        let mut payload = format!("{method} {host} {path} {timestamp_ms} {nonce}\n").into_bytes();
+        payload.extend_from_slice(body);
+        payload
+    }
+}
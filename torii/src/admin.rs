@@ -0,0 +1,389 @@
+//! Authentication of the requests to the administrative endpoints.
+//!
+//! See [`iroha_torii_const::admin`] for the description of the scheme.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use iroha_crypto::{PublicKey, Signature};
+use iroha_torii_const::admin::{
+    payload, MAX_NONCE_LEN, NONCE_HEADER, PUBLIC_KEY_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+    TIMESTAMP_TOLERANCE_MS,
+};
+use serde::de::DeserializeOwned;
+use warp::{
+    http::{header::HOST, Method, StatusCode},
+    hyper::body::Bytes,
+    path::FullPath,
+    reply::{self, Response},
+    Filter, Rejection, Reply,
+};
+
+/// Administrator who made the request
+#[derive(Debug, Clone)]
+pub struct Admin(
+    /// `None` if the admin keys aren't configured and the endpoints are open
+    Option<PublicKey>,
+);
+
+impl fmt::Display for Admin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(public_key) => write!(f, "{public_key}"),
+            None => write!(f, "anonymous"),
+        }
+    }
+}
+
+/// Rejection of a request which failed the admin authentication
+#[derive(Debug, Clone, Copy)]
+pub struct Unauthorized(&'static str);
+
+impl warp::reject::Reject for Unauthorized {}
+
+impl Reply for Unauthorized {
+    fn into_response(self) -> Response {
+        reply::with_status(self.0, StatusCode::UNAUTHORIZED).into_response()
+    }
+}
+
+/// Recover from the rejections caused by the admin authentication
+pub fn recover(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    if let Some(unauthorized) = rejection.find::<Unauthorized>() {
+        return Ok(unauthorized.into_response());
+    }
+    if rejection.find::<InvalidJson>().is_some() {
+        return Ok(
+            reply::with_status("Request body isn't valid JSON", StatusCode::BAD_REQUEST)
+                .into_response(),
+        );
+    }
+    Err(rejection)
+}
+
+/// Admin keys of the peer together with the nonces of the recently authenticated requests
+pub struct Authenticator {
+    admin_keys: Vec<PublicKey>,
+    seen_nonces: Mutex<SeenNonces>,
+}
+
+impl Authenticator {
+    /// Construct [`Self`] accepting the requests signed by any of the `admin_keys`
+    pub fn new(admin_keys: Vec<PublicKey>) -> Self {
+        if admin_keys.is_empty() {
+            iroha_logger::warn!(
+                "Admin public keys aren't configured, administrative endpoints are open to anyone"
+            );
+        }
+
+        Self {
+            admin_keys,
+            seen_nonces: Mutex::default(),
+        }
+    }
+
+    fn verify(
+        &self,
+        request: &Request<'_>,
+        credentials: Credentials,
+        now: Duration,
+    ) -> Result<Admin, Unauthorized> {
+        if self.admin_keys.is_empty() {
+            return Ok(Admin(None));
+        }
+
+        let Credentials {
+            public_key: Some(public_key),
+            timestamp: Some(timestamp),
+            nonce: Some(nonce),
+            signature: Some(signature),
+        } = credentials
+        else {
+            return Err(Unauthorized("Request isn't signed by an admin"));
+        };
+
+        let public_key: PublicKey = public_key
+            .parse()
+            .map_err(|_| Unauthorized("Invalid admin public key"))?;
+        if !self.admin_keys.contains(&public_key) {
+            return Err(Unauthorized("Public key doesn't belong to an admin"));
+        }
+
+        let timestamp_ms: u64 = timestamp
+            .parse()
+            .map_err(|_| Unauthorized("Invalid admin signature timestamp"))?;
+        let now_ms = u64::try_from(now.as_millis()).unwrap_or(u64::MAX);
+        if now_ms.abs_diff(timestamp_ms) > TIMESTAMP_TOLERANCE_MS {
+            return Err(Unauthorized(
+                "Admin signature timestamp is out of tolerance",
+            ));
+        }
+
+        if nonce.is_empty() || nonce.len() > MAX_NONCE_LEN {
+            return Err(Unauthorized("Invalid admin signature nonce"));
+        }
+
+        let payload = payload(
+            request.method.as_str(),
+            request.host,
+            request.path.trim_start_matches('/'),
+            timestamp_ms,
+            &nonce,
+            request.body,
+        );
+        Signature::from_hex(public_key.clone(), signature)
+            .map_err(|_| Unauthorized("Invalid admin signature"))?
+            .verify(&payload)
+            .map_err(|_| Unauthorized("Invalid admin signature"))?;
+
+        // Only the nonces of the valid signatures are remembered, so they can't be flooded
+        if !self
+            .seen_nonces
+            .lock()
+            .expect("Lock shouldn't be poisoned")
+            .insert(nonce, now_ms)
+        {
+            return Err(Unauthorized("Admin signature nonce was already used"));
+        }
+
+        Ok(Admin(Some(public_key)))
+    }
+}
+
+/// Nonces of the authenticated requests which are still within the timestamp tolerance
+#[derive(Default)]
+struct SeenNonces {
+    nonces: HashSet<String>,
+    /// Nonces in the order they were seen in, along with the time they were seen at
+    order: VecDeque<(u64, String)>,
+}
+
+impl SeenNonces {
+    /// Remember the `nonce` seen at `now_ms`, `false` if it was already seen
+    fn insert(&mut self, nonce: String, now_ms: u64) -> bool {
+        // Timestamp of a request is at most the tolerance away from the time it is seen at,
+        // so it can be replayed within twice the tolerance after that
+        while let Some((seen_at_ms, _)) = self.order.front() {
+            if now_ms.saturating_sub(*seen_at_ms) <= 2 * TIMESTAMP_TOLERANCE_MS {
+                break;
+            }
+            if let Some((_, expired)) = self.order.pop_front() {
+                self.nonces.remove(&expired);
+            }
+        }
+
+        if !self.nonces.insert(nonce.clone()) {
+            return false;
+        }
+        self.order.push_back((now_ms, nonce));
+        true
+    }
+}
+
+/// Filter for warp which authenticates the admin making the request
+pub fn authenticate(
+    authenticator: Arc<Authenticator>,
+) -> impl Filter<Extract = (Admin,), Error = Rejection> + Clone {
+    authenticate_with_body(authenticator).map(|admin, _body| admin)
+}
+
+/// Filter for warp which authenticates the admin making the request and decodes its JSON body
+pub fn authenticate_json<T: DeserializeOwned + Send>(
+    authenticator: Arc<Authenticator>,
+) -> impl Filter<Extract = (Admin, T), Error = Rejection> + Clone {
+    authenticate_with_body(authenticator)
+        .and_then(|admin, body: Bytes| async move {
+            serde_json::from_slice(&body)
+                .map(|value| (admin, value))
+                .map_err(|_| warp::reject::custom(InvalidJson))
+        })
+        .untuple_one()
+}
+
+/// Rejection of a request body which isn't valid JSON
+#[derive(Debug)]
+struct InvalidJson;
+
+impl warp::reject::Reject for InvalidJson {}
+
+/// Maximum length of the body of an administrative request
+const MAX_CONTENT_LENGTH: u64 = 1024 * 1024;
+
+fn authenticate_with_body(
+    authenticator: Arc<Authenticator>,
+) -> impl Filter<Extract = (Admin, Bytes), Error = Rejection> + Clone {
+    warp::method()
+        .and(warp::path::full())
+        .and(warp::header::optional::<String>(HOST.as_str()))
+        .and(credentials())
+        .and(body())
+        .and_then(
+            move |method: Method,
+                  path: FullPath,
+                  host: Option<String>,
+                  credentials: Credentials,
+                  body: Bytes| {
+                let authenticator = Arc::clone(&authenticator);
+                async move {
+                    let request = Request {
+                        method: &method,
+                        host: host.as_deref().unwrap_or_default(),
+                        path: path.as_str(),
+                        body: &body,
+                    };
+                    let now = SystemTime::now()
+                        .duration_since(SystemTime::UNIX_EPOCH)
+                        .unwrap_or_default();
+                    authenticator
+                        .verify(&request, credentials, now)
+                        .map(|admin| (admin, body))
+                        .map_err(warp::reject::custom)
+                }
+            },
+        )
+        .untuple_one()
+}
+
+/// Body of the request, limited by [`MAX_CONTENT_LENGTH`] and empty for `GET` requests
+fn body() -> impl Filter<Extract = (Bytes,), Error = Rejection> + Clone {
+    warp::get()
+        .map(Bytes::new)
+        .or(warp::body::content_length_limit(MAX_CONTENT_LENGTH).and(warp::body::bytes()))
+        .unify()
+}
+
+fn credentials() -> impl Filter<Extract = (Credentials,), Error = Rejection> + Clone {
+    warp::header::optional::<String>(PUBLIC_KEY_HEADER)
+        .and(warp::header::optional::<String>(TIMESTAMP_HEADER))
+        .and(warp::header::optional::<String>(NONCE_HEADER))
+        .and(warp::header::optional::<String>(SIGNATURE_HEADER))
+        .map(|public_key, timestamp, nonce, signature| Credentials {
+            public_key,
+            timestamp,
+            nonce,
+            signature,
+        })
+}
+
+/// Parts of the request which are covered by the admin signature
+struct Request<'request> {
+    method: &'request Method,
+    host: &'request str,
+    path: &'request str,
+    body: &'request [u8],
+}
+
+/// Values of the admin authentication headers
+struct Credentials {
+    public_key: Option<String>,
+    timestamp: Option<String>,
+    nonce: Option<String>,
+    signature: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write as _;
+
+    use iroha_crypto::KeyPair;
+
+    use super::*;
+
+    const NOW: Duration = Duration::from_secs(1_700_000_000);
+    const HOST: &str = "127.0.0.1:8080";
+
+    fn sign(key_pair: &KeyPair, timestamp: Duration, nonce: &str, body: &[u8]) -> Credentials {
+        let timestamp_ms = u64::try_from(timestamp.as_millis()).unwrap();
+        let payload = payload("POST", HOST, "configuration", timestamp_ms, nonce, body);
+        let signature = Signature::new(key_pair, &payload);
+
+        Credentials {
+            public_key: Some(key_pair.public_key().to_string()),
+            timestamp: Some(timestamp_ms.to_string()),
+            nonce: Some(nonce.to_owned()),
+            signature: Some(
+                signature
+                    .payload()
+                    .iter()
+                    .fold(String::new(), |mut hex, byte| {
+                        write!(hex, "{byte:02x}").expect("Writing to a string doesn't fail");
+                        hex
+                    }),
+            ),
+        }
+    }
+
+    fn verify_post(
+        authenticator: &Authenticator,
+        host: &str,
+        credentials: Credentials,
+        now: Duration,
+    ) -> Result<Admin, Unauthorized> {
+        let request = Request {
+            method: &Method::POST,
+            host,
+            path: "/configuration",
+            body: b"{}",
+        };
+        authenticator.verify(&request, credentials, now)
+    }
+
+    #[test]
+    fn open_without_admin_keys() {
+        let credentials = Credentials {
+            public_key: None,
+            timestamp: None,
+            nonce: None,
+            signature: None,
+        };
+
+        let authenticator = Authenticator::new(Vec::new());
+        assert!(verify_post(&authenticator, HOST, credentials, NOW)
+            .unwrap()
+            .0
+            .is_none());
+    }
+
+    #[test]
+    fn signed_by_admin() {
+        let admin = KeyPair::random();
+        let authenticator = Authenticator::new(vec![admin.public_key().clone()]);
+        let verify = |credentials| verify_post(&authenticator, HOST, credentials, NOW);
+
+        let authenticated = verify(sign(&admin, NOW, "1", b"{}")).unwrap();
+        assert_eq!(authenticated.0.as_ref(), Some(admin.public_key()));
+
+        verify(sign(&admin, NOW, "2", b"{\"tampered\":true}"))
+            .expect_err("Body isn't the signed one");
+        verify(sign(&admin, NOW - Duration::from_secs(60), "3", b"{}"))
+            .expect_err("Signature is too old");
+        verify(sign(&KeyPair::random(), NOW, "4", b"{}")).expect_err("Key isn't an admin one");
+        verify(sign(&admin, NOW, "", b"{}")).expect_err("Nonce is empty");
+    }
+
+    #[test]
+    fn replayed_request_is_rejected() {
+        let admin = KeyPair::random();
+        let authenticator = Authenticator::new(vec![admin.public_key().clone()]);
+
+        verify_post(&authenticator, HOST, sign(&admin, NOW, "1", b"{}"), NOW).unwrap();
+        verify_post(&authenticator, HOST, sign(&admin, NOW, "1", b"{}"), NOW)
+            .expect_err("Nonce was already used");
+        verify_post(
+            &authenticator,
+            "127.0.0.1:8081",
+            sign(&admin, NOW, "2", b"{}"),
+            NOW,
+        )
+        .expect_err("Request was signed for another peer");
+
+        // Nonces are forgotten once the requests carrying them would be too old anyway
+        let later = NOW + Duration::from_millis(2 * TIMESTAMP_TOLERANCE_MS + 1);
+        verify_post(&authenticator, HOST, sign(&admin, later, "3", b"{}"), later).unwrap();
+        assert_eq!(authenticator.seen_nonces.lock().unwrap().order.len(), 1);
+    }
+}
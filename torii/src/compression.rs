@@ -0,0 +1,202 @@
+//! Compression of the HTTP responses with the content coding negotiated
+//! through the `Accept-Encoding` header of the request.
+
+use std::io::Write as _;
+
+use flate2::{write::GzEncoder, Compression};
+use tokio::task;
+use warp::{
+    http::{
+        header::{HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, VARY},
+        StatusCode,
+    },
+    hyper::body::{self, Body},
+    reply::Response,
+    Filter, Rejection, Reply,
+};
+
+/// Bodies smaller than this are sent uncompressed as compression doesn't pay off for them
+const MIN_COMPRESSED_SIZE: usize = 1024;
+/// `zstd` compression level which favours speed over ratio
+const ZSTD_LEVEL: i32 = 3;
+
+/// Content coding of the compressed responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Zstd,
+    Gzip,
+}
+
+impl Encoding {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Zstd => "zstd",
+            Self::Gzip => "gzip",
+        }
+    }
+
+    /// Picks the supported coding with the highest quality value in the `Accept-Encoding`
+    /// header, preferring `zstd` on a tie. Codings with zero quality are never picked.
+    fn negotiate(accept_encoding: &str) -> Option<Self> {
+        let mut wildcard = None;
+        let mut zstd = None;
+        let mut gzip = None;
+        for item in accept_encoding.split(',') {
+            let mut params = item.split(';');
+            let coding = params.next().unwrap_or_default().trim();
+            let quality = match params.find_map(|param| param.trim().strip_prefix("q=")) {
+                Some(quality) => match parse_quality(quality) {
+                    Some(quality) => quality,
+                    None => continue,
+                },
+                None => 1000,
+            };
+            match coding.to_ascii_lowercase().as_str() {
+                "*" => wildcard = Some(quality),
+                "zstd" => zstd = Some(quality),
+                "gzip" | "x-gzip" => gzip = Some(quality),
+                _ => {}
+            }
+        }
+
+        let zstd = zstd.or(wildcard).unwrap_or(0);
+        let gzip = gzip.or(wildcard).unwrap_or(0);
+        if zstd == 0 && gzip == 0 {
+            None
+        } else if zstd >= gzip {
+            Some(Self::Zstd)
+        } else {
+            Some(Self::Gzip)
+        }
+    }
+
+    fn encode(self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Zstd => {
+                zstd::encode_all(bytes, ZSTD_LEVEL).expect("Compression in memory can't fail")
+            }
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+                encoder
+                    .write_all(bytes)
+                    .expect("Writing into a `Vec` can't fail");
+                encoder.finish().expect("Writing into a `Vec` can't fail")
+            }
+        }
+    }
+}
+
+/// Parses the quality value (`0` to `1` with up to three decimals) into thousandths
+fn parse_quality(value: &str) -> Option<u16> {
+    let value = value.trim();
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let integer: u16 = match integer {
+        "0" => 0,
+        "1" => 1000,
+        _ => return None,
+    };
+    let fraction: u16 = format!("{fraction:0<3}").parse().ok()?;
+    let quality = integer + fraction;
+
+    (quality <= 1000).then_some(quality)
+}
+
+/// Compresses the replies of the `filter` with the coding accepted by the client
+pub fn compressed<F, R>(filter: F) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send + Sync + 'static,
+    R: Reply + Send,
+{
+    warp::header::optional::<String>(ACCEPT_ENCODING.as_str())
+        .and(filter)
+        .and_then(|accept_encoding: Option<String>, reply: R| async move {
+            Ok::<_, Rejection>(compress(accept_encoding.as_deref(), reply.into_response()).await)
+        })
+}
+
+async fn compress(accept_encoding: Option<&str>, response: Response) -> Response {
+    let Some(encoding) = accept_encoding.and_then(Encoding::negotiate) else {
+        return response;
+    };
+    if matches!(
+        response.status(),
+        StatusCode::SWITCHING_PROTOCOLS | StatusCode::NO_CONTENT | StatusCode::NOT_MODIFIED
+    ) || response.headers().contains_key(CONTENT_ENCODING)
+    {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match body::to_bytes(body).await {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            iroha_logger::warn!(%error, "Failed to read the response body");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    parts
+        .headers
+        .append(VARY, HeaderValue::from_static("accept-encoding"));
+    if bytes.len() < MIN_COMPRESSED_SIZE {
+        return Response::from_parts(parts, Body::from(bytes));
+    }
+
+    // Compression of large bodies takes a while, so it's kept off the async executor
+    let compressed = match task::spawn_blocking(move || encoding.encode(&bytes)).await {
+        Ok(compressed) => compressed,
+        Err(error) => {
+            iroha_logger::warn!(%error, "Failed to compress the response body");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    parts
+        .headers
+        .insert(CONTENT_ENCODING, HeaderValue::from_static(encoding.name()));
+    parts.headers.remove(CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(compressed))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read as _;
+
+    use super::*;
+
+    #[test]
+    fn negotiates_highest_quality_coding() {
+        assert_eq!(Encoding::negotiate("gzip"), Some(Encoding::Gzip));
+        assert_eq!(Encoding::negotiate("gzip, zstd"), Some(Encoding::Zstd));
+        assert_eq!(
+            Encoding::negotiate("zstd;q=0.5, gzip;q=0.8"),
+            Some(Encoding::Gzip)
+        );
+        assert_eq!(Encoding::negotiate("*"), Some(Encoding::Zstd));
+        assert_eq!(Encoding::negotiate("*, zstd;q=0"), Some(Encoding::Gzip));
+    }
+
+    #[test]
+    fn rejects_unsupported_or_refused_codings() {
+        assert_eq!(Encoding::negotiate(""), None);
+        assert_eq!(Encoding::negotiate("br, identity"), None);
+        assert_eq!(Encoding::negotiate("gzip;q=0, zstd;q=0.000"), None);
+        assert_eq!(Encoding::negotiate("gzip;q=2"), None);
+    }
+
+    #[test]
+    fn encoded_bytes_are_decodable() {
+        let bytes = b"iroha".repeat(1000);
+
+        let compressed = Encoding::Zstd.encode(&bytes);
+        assert_eq!(zstd::decode_all(compressed.as_slice()).unwrap(), bytes);
+
+        let compressed = Encoding::Gzip.encode(&bytes);
+        let mut decompressed = Vec::new();
+        flate2::read::GzDecoder::new(compressed.as_slice())
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, bytes);
+    }
+}
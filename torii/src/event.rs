@@ -1,12 +1,14 @@
 //! Iroha is a quite dynamic system so many events can happen.
 //! This module contains descriptions of such an events and
 //! utility Iroha Special Instructions to work with them.
+use std::num::NonZeroU64;
+
 use futures::TryStreamExt;
 use iroha_data_model::events::prelude::*;
 use iroha_macro::error::ErrorTryFromEnum;
-use warp::ws::WebSocket;
+use warp::ws::{Message, WebSocket};
 
-use crate::stream::{self, Sink, Stream};
+use crate::stream::{self, Compression, Sink, Stream};
 
 /// Type of Stream error
 pub type StreamError = stream::Error<<WebSocket as Stream<EventSubscriptionRequest>>::Err>;
@@ -44,32 +46,49 @@ pub type Result<T> = core::result::Result<T, Error>;
 #[derive(Debug)]
 pub struct Consumer {
     stream: WebSocket,
+    compression: Compression,
     filters: Vec<EventFilterBox>,
+    from: Option<EventCursor>,
 }
 
 impl Consumer {
-    /// Constructs [`Consumer`], which consumes `Event`s and forwards it through the `stream`.
+    /// Constructs [`Consumer`], which consumes `Event`s and forwards it through the `stream`
+    /// applying the negotiated `compression`.
     ///
     /// # Errors
     /// Can fail due to timeout or without message at websocket or during decoding request
     #[iroha_futures::telemetry_future]
-    pub async fn new(mut stream: WebSocket) -> Result<Self> {
-        let EventSubscriptionRequest(filters) = stream.recv().await?;
-        Ok(Consumer { stream, filters })
+    pub async fn new(mut stream: WebSocket, compression: Compression) -> Result<Self> {
+        let EventSubscriptionRequest { filters, from } = stream.recv().await?;
+        Ok(Consumer {
+            stream,
+            compression,
+            filters,
+            from,
+        })
+    }
+
+    /// Cursor of the first event requested by the subscriber, if the replay was requested
+    pub fn from(&self) -> Option<EventCursor> {
+        self.from
     }
 
-    /// Forwards the `event` over the `stream` if it matches the `filter`.
+    /// Forwards the `event` over the `stream` if it matches the `filter`
+    /// and isn't positioned before the first requested event.
     ///
     /// # Errors
     /// Can fail due to timeout or sending event. Also receiving might fail
     #[iroha_futures::telemetry_future]
-    pub async fn consume(&mut self, event: EventBox) -> Result<()> {
+    pub async fn consume(&mut self, event: EventBox, cursor: Option<EventCursor>) -> Result<()> {
+        if cursor.is_some_and(|cursor| self.from.is_some_and(|from| cursor < from)) {
+            return Ok(());
+        }
         if !self.filters.iter().any(|filter| filter.matches(&event)) {
             return Ok(());
         }
 
         self.stream
-            .send(EventMessage(event))
+            .send_compressed(EventMessage { event, cursor }, self.compression)
             .await
             .map_err(Into::into)
     }
@@ -95,4 +114,105 @@ impl Consumer {
     pub async fn close_stream(self) -> Result<()> {
         self.stream.close().await.map_err(Into::into)
     }
+
+    /// Close stream with the policy violation code telling the subscriber
+    /// why the subscription can't be served
+    ///
+    /// # Errors
+    /// Throws up [`WebSocket::close()`] errors
+    pub async fn reject(mut self, reason: String) -> Result<()> {
+        futures::SinkExt::send(&mut self.stream, Message::close_with(1008_u16, reason)).await?;
+        self.stream.close().await.map_err(Into::into)
+    }
+}
+
+/// Position of a live event in the blockchain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LivePosition {
+    /// Event doesn't belong to a committed block
+    Unbound,
+    /// Event belongs to a block and has the given cursor
+    At(EventCursor),
+    /// Event belongs to a block whose first events were emitted before the subscription
+    Unknown,
+}
+
+/// Numbering of the live events which matches the numbering of the events replayed from [`Kura`](iroha_core::kura::Kura).
+///
+/// Events of a block are emitted in the order: transaction events, block approval,
+/// block commit and the events of the block application ending with [`BlockStatus::Applied`].
+#[derive(Debug, Default)]
+pub struct LiveCursor {
+    /// Set once the end of a block is observed, so that the next block is observed from its start
+    synced: bool,
+    /// Height of the block the latest events belong to
+    height: Option<NonZeroU64>,
+    /// Index of the next event of the block
+    next_index: u64,
+    /// Whether the block at `height` was already approved
+    approved: bool,
+}
+
+impl LiveCursor {
+    /// Position of the live `event`. Must be called for every emitted event in the order of emission.
+    pub fn position(&mut self, event: &EventBox) -> LivePosition {
+        let (height, approval) = match event {
+            EventBox::Pipeline(PipelineEventBox::Transaction(event)) => {
+                match event.block_height().and_then(NonZeroU64::new) {
+                    Some(height) => (Some(height), false),
+                    None => return LivePosition::Unbound,
+                }
+            }
+            EventBox::Pipeline(PipelineEventBox::Block(event)) => {
+                let height =
+                    NonZeroU64::new(event.header().height()).expect("Block height can't be zero");
+                match event.status() {
+                    BlockStatus::Rejected(_) => return LivePosition::Unbound,
+                    BlockStatus::Approved => (Some(height), true),
+                    BlockStatus::Committed => return self.end_of_block(height, false),
+                    BlockStatus::Applied => return self.end_of_block(height, true),
+                }
+            }
+            _ => (None, false),
+        };
+
+        if let Some(height) = height {
+            // Transactions of a block are reported before its approval, so the events
+            // after an approval at the same height belong to a block proposed after a view change
+            if self.synced && (self.height != Some(height) || self.approved) {
+                self.height = Some(height);
+                self.next_index = 0;
+                self.approved = false;
+            }
+            if approval && self.height == Some(height) {
+                self.approved = true;
+            }
+        }
+
+        self.next()
+    }
+
+    fn end_of_block(&mut self, height: NonZeroU64, applied: bool) -> LivePosition {
+        let position = if self.synced && self.height == Some(height) {
+            self.next()
+        } else {
+            self.height = None;
+            LivePosition::Unknown
+        };
+        if applied {
+            self.synced = true;
+        }
+
+        position
+    }
+
+    fn next(&mut self) -> LivePosition {
+        let Some(height) = self.height.filter(|_| self.synced) else {
+            return LivePosition::Unknown;
+        };
+
+        let index = self.next_index;
+        self.next_index += 1;
+        LivePosition::At(EventCursor { height, index })
+    }
 }
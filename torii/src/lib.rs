@@ -2,21 +2,22 @@
 //!
 //! Crate provides the following features that are not enabled by default:
 //!
-//! - `telemetry`: enables Status, Metrics, and API Version endpoints
+//! - `telemetry`: enables Status, Peers, Metrics, and API Version endpoints
 //! - `schema`: enables Data Model Schema endpoint
 
 use std::{
     convert::Infallible,
     fmt::{Debug, Write as _},
     net::ToSocketAddrs,
+    num::NonZeroUsize,
     sync::Arc,
 };
 
+use eyre::WrapErr as _;
 use futures::{stream::FuturesUnordered, StreamExt};
-use iroha_config::parameters::actual::Torii as Config;
-#[cfg(feature = "telemetry")]
-use iroha_core::metrics::MetricsReporter;
+use iroha_config::parameters::actual::{Torii as Config, ToriiTls};
 use iroha_core::{
+    block_sync::BlockSyncStatus,
     kiso::{Error as KisoError, KisoHandle},
     kura::Kura,
     prelude::*,
@@ -25,10 +26,17 @@ use iroha_core::{
     state::State,
     EventsSender,
 };
-use iroha_data_model::ChainId;
+#[cfg(feature = "telemetry")]
+use iroha_core::{metrics::MetricsReporter, sumeragi::SumeragiHandle};
+use iroha_data_model::{peer::PeerId, ChainId};
 use iroha_primitives::addr::SocketAddr;
-use iroha_torii_const::uri;
-use tokio::{sync::Notify, task};
+use iroha_torii_const::{compression::WS_COMPRESSION_HEADER, uri};
+use limits::{Endpoint, RateLimits};
+use tokio::{
+    sync::{Notify, Semaphore},
+    task,
+};
+use transaction_status::ExpiredTransactions;
 use utils::*;
 use warp::{
     http::StatusCode,
@@ -39,9 +47,14 @@ use warp::{
 
 #[macro_use]
 pub(crate) mod utils;
+mod admin;
+mod compression;
 mod event;
+mod limits;
 mod routing;
+mod simulation;
 mod stream;
+mod transaction_status;
 
 /// Main network handler and the only entrypoint of the Iroha.
 pub struct Torii {
@@ -49,14 +62,27 @@ pub struct Torii {
     kiso: KisoHandle,
     queue: Arc<Queue>,
     events: EventsSender,
+    expired_transactions: Arc<ExpiredTransactions>,
     notify_shutdown: Arc<Notify>,
     query_service: LiveQueryStoreHandle,
     kura: Arc<Kura>,
     transaction_max_content_length: u64,
     address: SocketAddr,
+    tls: Option<ToriiTls>,
+    query_batch_max_size: NonZeroUsize,
+    ready_max_blocks_behind: u64,
+    rate_limits: Arc<RateLimits>,
+    event_replays: Arc<Semaphore>,
+    historical_queries: Arc<Semaphore>,
+    simulator: Arc<simulation::Simulator>,
+    admin: Arc<admin::Authenticator>,
     state: Arc<State>,
+    peer_id: Arc<PeerId>,
+    block_sync_status: BlockSyncStatus,
     #[cfg(feature = "telemetry")]
     metrics_reporter: MetricsReporter,
+    #[cfg(feature = "telemetry")]
+    sumeragi: SumeragiHandle,
 }
 
 impl Torii {
@@ -72,21 +98,47 @@ impl Torii {
         query_service: LiveQueryStoreHandle,
         kura: Arc<Kura>,
         state: Arc<State>,
+        peer_id: PeerId,
+        block_sync_status: BlockSyncStatus,
         #[cfg(feature = "telemetry")] metrics_reporter: MetricsReporter,
+        #[cfg(feature = "telemetry")] sumeragi: SumeragiHandle,
     ) -> Self {
+        let rate_limits = Arc::new(RateLimits::new(
+            config.rate_limit,
+            #[cfg(feature = "telemetry")]
+            metrics_reporter.clone(),
+        ));
+
         Self {
             chain_id: Arc::new(chain_id),
             kiso,
             queue,
             events,
+            expired_transactions: Arc::default(),
             notify_shutdown,
             query_service,
             kura,
             state,
+            peer_id: Arc::new(peer_id),
+            block_sync_status,
             #[cfg(feature = "telemetry")]
             metrics_reporter,
+            #[cfg(feature = "telemetry")]
+            sumeragi,
             address: config.address.into_value(),
+            tls: config.tls,
+            rate_limits,
+            event_replays: Arc::new(Semaphore::new(
+                routing::subscription::MAX_CONCURRENT_REPLAYS,
+            )),
+            historical_queries: Arc::new(Semaphore::new(
+                routing::MAX_CONCURRENT_HISTORICAL_QUERIES,
+            )),
+            simulator: Arc::default(),
+            admin: Arc::new(admin::Authenticator::new(config.admin_public_keys)),
             transaction_max_content_length: config.max_content_len_bytes,
+            query_batch_max_size: config.query_batch_max_size,
+            ready_max_blocks_behind: config.ready_max_blocks_behind,
         }
     }
 
@@ -97,22 +149,69 @@ impl Torii {
             .and(warp::path(uri::HEALTH))
             .and_then(|| async { Ok::<_, Infallible>(routing::handle_health()) });
 
+        let ready_route = warp::get()
+            .and(warp::path(uri::READY))
+            .and(add_state!(
+                self.state,
+                self.kura,
+                self.peer_id,
+                self.block_sync_status,
+                self.ready_max_blocks_behind
+            ))
+            .and_then(
+                |state: Arc<State>,
+                 kura: Arc<Kura>,
+                 peer_id: Arc<PeerId>,
+                 block_sync_status: BlockSyncStatus,
+                 max_blocks_behind: u64| async move {
+                    Ok::<_, Infallible>(routing::handle_ready(
+                        &state,
+                        &kura,
+                        &peer_id,
+                        &block_sync_status,
+                        max_blocks_behind,
+                    ))
+                },
+            );
+
         let get_router = warp::get().and(
             warp::path(uri::CONFIGURATION)
+                .and(self.admin())
                 .and(add_state!(self.kiso))
-                .and_then(|kiso| async move {
+                .and_then(|_admin, kiso| async move {
                     Ok::<_, Infallible>(WarpResult(routing::handle_get_configuration(kiso).await))
-                }),
+                })
+                .or(warp::path(uri::PENDING_TRANSACTIONS)
+                    .and(add_state!(self.queue, self.state))
+                    .and(warp::query::<routing::PendingTransactionsParams>())
+                    .and_then(|queue, state, params| async move {
+                        Ok::<_, Infallible>(
+                            routing::handle_pending_transactions(queue, state, params).await,
+                        )
+                    }))
+                .or(endpoint5(
+                    routing::handle_transaction_status,
+                    warp::path(uri::TRANSACTION)
+                        .and(add_state!(
+                            self.queue,
+                            self.state,
+                            self.expired_transactions
+                        ))
+                        .and(accept())
+                        .and(warp::path::param())
+                        .and(warp::path::end()),
+                )),
         );
 
         #[cfg(feature = "telemetry")]
         let get_router = get_router
             .or(warp::path(uri::STATUS)
+                .and(self.admin())
                 .and(add_state!(self.metrics_reporter.clone()))
                 .and(warp::header::optional(warp::http::header::ACCEPT.as_str()))
                 .and(warp::path::tail())
                 .and_then(
-                    |metrics_reporter, accept: Option<String>, tail| async move {
+                    |_admin, metrics_reporter, accept: Option<String>, tail| async move {
                         Ok::<_, Infallible>(crate::utils::WarpResult(routing::handle_status(
                             &metrics_reporter,
                             accept.as_ref(),
@@ -120,9 +219,22 @@ impl Torii {
                         )))
                     },
                 ))
+            .or(warp::path(uri::PEERS)
+                .and(self.admin())
+                .and(add_state!(self.metrics_reporter.clone(), self.sumeragi))
+                .and(warp::header::optional(warp::http::header::ACCEPT.as_str()))
+                .and_then(
+                    |_admin, metrics_reporter, sumeragi, accept: Option<String>| async move {
+                        Ok::<_, Infallible>(
+                            routing::handle_peers(&metrics_reporter, &sumeragi, accept.as_ref())
+                                .await,
+                        )
+                    },
+                ))
             .or(warp::path(uri::METRICS)
+                .and(self.admin())
                 .and(add_state!(self.metrics_reporter))
-                .and_then(|metrics_reporter| async move {
+                .and_then(|_admin, metrics_reporter| async move {
                     Ok::<_, Infallible>(crate::utils::WarpResult(routing::handle_metrics(
                         &metrics_reporter,
                     )))
@@ -150,8 +262,9 @@ impl Torii {
 
             let profiling_lock = std::sync::Arc::new(tokio::sync::Mutex::new(()));
             get_router.or(profile_router_path
+                .and(self.admin())
                 .and(warp::query::<routing::profiling::ProfileParams>())
-                .and_then(move |params| {
+                .and_then(move |_admin, params| {
                     let profiling_lock = Arc::clone(&profiling_lock);
                     async move {
                         Ok::<_, Infallible>(
@@ -163,41 +276,86 @@ impl Torii {
 
         let post_router = warp::post()
             .and(
-                endpoint4(
+                endpoint5(
                     routing::handle_transaction,
                     warp::path(uri::TRANSACTION)
+                        .and(self.limit_ip(Endpoint::Transaction))
                         .and(add_state!(self.chain_id, self.queue, self.state.clone()))
+                        .and(accept())
                         .and(warp::body::content_length_limit(
                             self.transaction_max_content_length,
                         ))
-                        .and(body::versioned()),
+                        .and(self.limit_account(Endpoint::Transaction, body::versioned())),
                 )
-                .or(endpoint3(
+                .or(endpoint5(
+                    routing::handle_transaction_simulation,
+                    warp::path(uri::TRANSACTION_SIMULATION)
+                        .and(self.limit_ip(Endpoint::Transaction))
+                        .and(add_state!(
+                            self.chain_id,
+                            self.state.clone(),
+                            self.simulator
+                        ))
+                        .and(accept())
+                        .and(warp::body::content_length_limit(
+                            self.transaction_max_content_length,
+                        ))
+                        .and(self.limit_account(Endpoint::Transaction, body::unversioned())),
+                ))
+                .or(endpoint5(
                     routing::handle_queries,
                     warp::path(uri::QUERY)
-                        .and(add_state!(self.query_service, self.state.clone(),))
-                        .and(routing::client_query_request()),
+                        .and(self.limit_ip(Endpoint::Query))
+                        .and(add_state!(
+                            self.query_service,
+                            self.state.clone(),
+                            self.historical_queries,
+                        ))
+                        .and(accept())
+                        .and(self.limit_account(Endpoint::Query, routing::client_query_request())),
+                ))
+                .or(endpoint6(
+                    routing::handle_query_batch,
+                    warp::path(uri::QUERY_BATCH)
+                        .and(self.limit_ip(Endpoint::Query))
+                        .and(add_state!(
+                            self.query_service,
+                            self.state.clone(),
+                            self.historical_queries,
+                            self.query_batch_max_size,
+                        ))
+                        .and(accept())
+                        .and(self.limit_account(Endpoint::Query, body::versioned())),
                 ))
-                .or(endpoint2(
+                .or(endpoint3(
                     routing::handle_post_configuration,
                     warp::path(uri::CONFIGURATION)
                         .and(add_state!(self.kiso))
-                        .and(warp::body::json()),
+                        .and(admin::authenticate_json(Arc::clone(&self.admin))),
                 )),
             )
             .recover(|rejection| async move { body::recover_versioned(rejection) });
 
         let events_ws_router = warp::path(uri::SUBSCRIPTION)
-            .and(add_state!(self.events))
+            .and(self.limit_ip(Endpoint::Subscription))
+            .and(add_state!(self.events, self.state, self.event_replays))
+            .and(ws_compression())
             .and(warp::ws())
-            .map(|events, ws: Ws| {
-                ws.on_upgrade(|this_ws| async move {
-                    if let Err(error) =
-                        routing::subscription::handle_subscription(events, this_ws).await
+            .map(|events, state, event_replays, compression, ws: Ws| {
+                let reply = ws.on_upgrade(move |this_ws| async move {
+                    if let Err(error) = routing::subscription::handle_subscription(
+                        events,
+                        state,
+                        event_replays,
+                        this_ws,
+                        compression,
+                    )
+                    .await
                     {
                         iroha_logger::error!(%error, "Failure during subscription");
                     }
-                })
+                });
+                confirm_ws_compression(reply, compression)
             });
 
         // `warp` panics if there is `/` in the string given to the `warp::path` filter
@@ -210,32 +368,67 @@ impl Torii {
             });
 
         let blocks_ws_router = block_ws_router_path
+            .and(self.limit_ip(Endpoint::Subscription))
             .and(add_state!(self.kura))
+            .and(ws_compression())
             .and(warp::ws())
-            .map(|sumeragi: Arc<_>, ws: Ws| {
-                ws.on_upgrade(|this_ws| async move {
-                    if let Err(error) = routing::handle_blocks_stream(sumeragi, this_ws).await {
+            .map(|kura: Arc<_>, compression, ws: Ws| {
+                let reply = ws.on_upgrade(move |this_ws| async move {
+                    if let Err(error) =
+                        routing::handle_blocks_stream(kura, this_ws, compression).await
+                    {
                         iroha_logger::error!(%error, "Failed to subscribe to blocks stream");
                     }
-                })
+                });
+                confirm_ws_compression(reply, compression)
             });
 
         let ws_router = events_ws_router.or(blocks_ws_router);
 
         warp::any()
             .and(
-                // we want to avoid logging for the "health" endpoint.
-                // we have to place it **first** so that warp's trace will
+                // we want to avoid logging for the "health" and "ready" endpoints.
+                // we have to place them **first** so that warp's trace will
                 // not log 404 if it doesn't find "/health" which might be placed
                 // **after** `.with(trace)`
-                health_route,
+                health_route.or(ready_route),
             )
             .or(ws_router
-                .or(get_router)
-                .or(post_router)
+                .or(compression::compressed(get_router.or(post_router)))
+                .recover(|rejection| async move { limits::recover(rejection) })
+                .recover(|rejection| async move { admin::recover(rejection) })
                 .with(warp::trace::request()))
     }
 
+    /// Filter which authenticates the admin making the request
+    fn admin(
+        &self,
+    ) -> impl warp::Filter<Extract = (admin::Admin,), Error = warp::Rejection> + Clone {
+        admin::authenticate(Arc::clone(&self.admin))
+    }
+
+    /// Filter which applies the per-IP rate limit of the `endpoint`
+    fn limit_ip(
+        &self,
+        endpoint: Endpoint,
+    ) -> impl warp::Filter<Extract = (), Error = warp::Rejection> + Clone {
+        limits::limit_ip(Arc::clone(&self.rate_limits), endpoint)
+    }
+
+    /// Filter which applies the per-account rate limit of the `endpoint` to the `request`
+    fn limit_account<T: limits::Signed + Send>(
+        &self,
+        endpoint: Endpoint,
+        request: impl warp::Filter<Extract = (T,), Error = warp::Rejection> + Clone,
+    ) -> impl warp::Filter<Extract = (T,), Error = warp::Rejection> + Clone {
+        limits::limit_account(
+            Arc::clone(&self.rate_limits),
+            Arc::clone(&self.state),
+            endpoint,
+            request,
+        )
+    }
+
     /// Start main API endpoints.
     ///
     /// # Errors
@@ -243,6 +436,8 @@ impl Torii {
     fn start_api(self: Arc<Self>) -> eyre::Result<Vec<task::JoinHandle<()>>> {
         let torii_address = &self.address;
 
+        let tls = self.tls.as_ref().map(TlsFiles::read).transpose()?;
+
         let handles = torii_address
             .to_socket_addrs()?
             .map(|addr| {
@@ -253,8 +448,18 @@ impl Torii {
                 // FIXME: warp panics if fails to bind!
                 //        handle this properly, report address origin after Axum
                 //        migration: https://github.com/hyperledger/iroha/issues/3776
-                let (_, serve_fut) =
-                    warp::serve(api_router).bind_with_graceful_shutdown(addr, signal_fut);
+                let Some(tls) = &tls else {
+                    let (_, serve_fut) =
+                        warp::serve(api_router).bind_with_graceful_shutdown(addr, signal_fut);
+                    return task::spawn(serve_fut);
+                };
+
+                let server = warp::serve(api_router).tls().cert(&tls.cert).key(&tls.key);
+                let server = match &tls.client_ca {
+                    Some(client_ca) => server.client_auth_required(client_ca),
+                    None => server,
+                };
+                let (_, serve_fut) = server.bind_with_graceful_shutdown(addr, signal_fut);
 
                 task::spawn(serve_fut)
             })
@@ -274,6 +479,10 @@ impl Torii {
 
         handles.extend(Arc::clone(&torii).start_api()?);
 
+        let expired_transactions = Arc::clone(&torii.expired_transactions);
+        let events = torii.events.subscribe();
+        task::spawn(async move { expired_transactions.track(events).await });
+
         handles
             .into_iter()
             .collect::<FuturesUnordered<_>>()
@@ -290,6 +499,48 @@ impl Torii {
     }
 }
 
+/// Filter which negotiates the compression of the websocket messages requested by the client
+fn ws_compression(
+) -> impl warp::Filter<Extract = (stream::Compression,), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>(WS_COMPRESSION_HEADER)
+        .map(|requested: Option<String>| stream::Compression::negotiate(requested.as_deref()))
+}
+
+/// Confirms the negotiated `compression` in the websocket handshake `reply`
+fn confirm_ws_compression(reply: impl Reply, compression: stream::Compression) -> Response {
+    let mut response = reply.into_response();
+    if let Some(value) = compression.header_value() {
+        response.headers_mut().insert(
+            WS_COMPRESSION_HEADER,
+            warp::http::HeaderValue::from_static(value),
+        );
+    }
+    response
+}
+
+/// Contents of the files configured in [`ToriiTls`]
+struct TlsFiles {
+    cert: Vec<u8>,
+    key: Vec<u8>,
+    client_ca: Option<Vec<u8>>,
+}
+
+impl TlsFiles {
+    fn read(config: &ToriiTls) -> eyre::Result<Self> {
+        let read = |file: &iroha_config::base::WithOrigin<std::path::PathBuf>| {
+            let path = file.resolve_relative_path();
+            std::fs::read(&path)
+                .wrap_err_with(|| format!("Failed to read TLS file `{}`", path.display()))
+        };
+
+        Ok(Self {
+            cert: read(&config.cert_file)?,
+            key: read(&config.key_file)?,
+            client_ca: config.client_ca_file.as_ref().map(read).transpose()?,
+        })
+    }
+}
+
 /// Torii errors.
 #[derive(Debug, thiserror::Error, displaydoc::Display)]
 pub enum Error {
@@ -314,6 +565,8 @@ pub enum Error {
     ConfigurationFailure(#[from] KisoError),
     /// Failed to find status segment by provided path
     StatusSegmentNotFound(#[source] eyre::Report),
+    /// Transaction isn't known to the peer
+    UnknownTransaction,
 }
 
 impl Reply for Error {
@@ -328,6 +581,25 @@ impl Reply for Error {
     }
 }
 
+impl Reply for Encoded<Error> {
+    fn into_response(self) -> Response {
+        match self {
+            Self(Encoding::Json, Error::Query(err)) => {
+                reply::with_status(reply::json(&err), Error::query_status_code(&err))
+                    .into_response()
+            }
+            // Other errors don't have a structured representation,
+            // so the JSON reply is an object with the error message
+            Self(Encoding::Json, err) => reply::with_status(
+                reply::json(&serde_json::json!({ "error": err.to_string() })),
+                err.status_code(),
+            )
+            .into_response(),
+            Self(Encoding::Scale, err) => err.into_response(),
+        }
+    }
+}
+
 impl Error {
     fn status_code(&self) -> StatusCode {
         use Error::*;
@@ -335,7 +607,7 @@ impl Error {
         match self {
             Query(e) => Self::query_status_code(e),
             AcceptTransaction(_) => StatusCode::BAD_REQUEST,
-            Config(_) | StatusSegmentNotFound(_) => StatusCode::NOT_FOUND,
+            Config(_) | StatusSegmentNotFound(_) | UnknownTransaction => StatusCode::NOT_FOUND,
             PushIntoQueue(err) => match **err {
                 queue::Error::Full => StatusCode::INTERNAL_SERVER_ERROR,
                 queue::Error::SignatoryInconsistent => StatusCode::UNAUTHORIZED,
@@ -360,11 +632,16 @@ impl Error {
             QueryFailed(query_error)
             | InstructionFailed(InstructionExecutionError::Query(query_error)) => match query_error
             {
-                Conversion(_) | UnknownCursor | FetchSizeTooBig | InvalidSingularParameters => {
-                    StatusCode::BAD_REQUEST
-                }
+                Conversion(_)
+                | UnknownCursor
+                | FetchSizeTooBig
+                | InvalidSingularParameters
+                | AggregationOverflow
+                | AggregationOfDifferentAssets
+                | BatchHeightMismatch => StatusCode::BAD_REQUEST,
+                BatchTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
                 Signature(_) => StatusCode::UNAUTHORIZED,
-                Find(_) => StatusCode::NOT_FOUND,
+                Find(_) | HeightNotServed(_) => StatusCode::NOT_FOUND,
             },
             TooComplex => StatusCode::UNPROCESSABLE_ENTITY,
             InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
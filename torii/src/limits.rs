@@ -0,0 +1,448 @@
+//! Per-IP and per-account rate limiting of the Torii endpoints.
+//!
+//! Every source IP address and every signing account gets a token bucket
+//! which is refilled continuously. Requests which find their bucket empty
+//! are rejected with `429 Too Many Requests` and a `Retry-After` hint.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    mem,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use iroha_config::parameters::actual::{TokenBucket, ToriiRateLimit};
+#[cfg(feature = "telemetry")]
+use iroha_core::metrics::MetricsReporter;
+use iroha_core::state::{State, StateReadOnly, WorldReadOnly};
+use iroha_crypto::PublicKey;
+use iroha_data_model::{
+    account::AccountId,
+    prelude::TransactionToSimulate,
+    query::{
+        http::{ClientQueryRequest, QueryBatch},
+        QueryRequest,
+    },
+    transaction::SignedTransaction,
+};
+use warp::{
+    http::{header::RETRY_AFTER, StatusCode},
+    reply::{self, Response},
+    Filter, Rejection, Reply,
+};
+
+/// Number of buckets a limiter keeps at most, the least recently used ones are forgotten first
+const MAX_TRACKED_BUCKETS: usize = 65_536;
+
+/// Torii endpoints which are subject to rate limiting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint {
+    /// Transaction submission and simulation
+    Transaction,
+    /// Query execution
+    Query,
+    /// Event and block stream subscriptions
+    Subscription,
+}
+
+impl Endpoint {
+    #[cfg(feature = "telemetry")]
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Transaction => "transaction",
+            Self::Query => "query",
+            Self::Subscription => "subscription",
+        }
+    }
+}
+
+/// Rejection of a request which exceeded one of the rate limits
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimited {
+    retry_after: Duration,
+}
+
+impl warp::reject::Reject for RateLimited {}
+
+impl Reply for RateLimited {
+    fn into_response(self) -> Response {
+        // `Retry-After` is in whole seconds, so round up not to invite a retry too early
+        let retry_after =
+            self.retry_after.as_secs() + u64::from(self.retry_after.subsec_nanos() > 0);
+        reply::with_header(
+            reply::with_status("Rate limit exceeded", StatusCode::TOO_MANY_REQUESTS),
+            RETRY_AFTER,
+            retry_after.max(1),
+        )
+        .into_response()
+    }
+}
+
+/// Recover from the rejection caused by [`RateLimited`]
+pub fn recover(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    rejection
+        .find::<RateLimited>()
+        .copied()
+        .map(Reply::into_response)
+        .ok_or(rejection)
+}
+
+/// Requests which are signed on behalf of accounts
+pub trait Signed {
+    /// Accounts on behalf of which the request is made,
+    /// each with the keys which signed the request on its behalf.
+    ///
+    /// Signatures are verified, a request or a query of a batch
+    /// with an invalid signature has no signatories.
+    fn signatories(&self) -> Vec<(&AccountId, Vec<&PublicKey>)>;
+}
+
+impl Signed for SignedTransaction {
+    fn signatories(&self) -> Vec<(&AccountId, Vec<&PublicKey>)> {
+        if !self.verify_signatures() {
+            return Vec::new();
+        }
+        let keys = self
+            .signatures()
+            .iter()
+            .map(|signature| signature.public_key())
+            .collect();
+        vec![(self.authority(), keys)]
+    }
+}
+
+impl Signed for TransactionToSimulate {
+    fn signatories(&self) -> Vec<(&AccountId, Vec<&PublicKey>)> {
+        match self {
+            Self::Signed(transaction) => transaction.signatories(),
+            // Unsigned transactions are limited per IP only
+            Self::Unsigned(_) => Vec::new(),
+        }
+    }
+}
+
+impl Signed for ClientQueryRequest {
+    fn signatories(&self) -> Vec<(&AccountId, Vec<&PublicKey>)> {
+        match &self.0 {
+            QueryRequest::Query(query) if query.verify_signature() => {
+                vec![(query.authority(), vec![query.signature().public_key()])]
+            }
+            // Unverified queries are limited per IP only, continuations were already limited
+            QueryRequest::Query(_) | QueryRequest::Cursor(_) => Vec::new(),
+        }
+    }
+}
+
+impl Signed for QueryBatch {
+    fn signatories(&self) -> Vec<(&AccountId, Vec<&PublicKey>)> {
+        self.queries()
+            .iter()
+            .filter(|query| query.verify_signature())
+            .map(|query| (query.authority(), vec![query.signature().public_key()]))
+            .collect()
+    }
+}
+
+/// Authorities of the `request` whose signatures are valid
+/// and made with the keys of their accounts in the `world`.
+///
+/// Requests with invalid signatures or signed with foreign keys are rejected later on,
+/// so the buckets of the accounts they claim to come from are left untouched
+/// and such requests are limited per IP only.
+fn verified_authorities<'request>(
+    request: &'request impl Signed,
+    world: &impl WorldReadOnly,
+) -> Vec<&'request AccountId> {
+    let mut authorities: Vec<_> = request
+        .signatories()
+        .into_iter()
+        .filter(|(authority, keys)| {
+            let is_signatory = |key: &&PublicKey| {
+                world.account(authority).map_or_else(
+                    // Account might not exist, fall back to the signatory from its id
+                    |_| authority.signatory_matches(key),
+                    |account| account.signatory_weight(key).is_some(),
+                )
+            };
+            !keys.is_empty() && keys.iter().all(is_signatory)
+        })
+        .map(|(authority, _)| authority)
+        .collect();
+    authorities.sort_unstable();
+    authorities.dedup();
+    authorities
+}
+
+/// Rate limits of the Torii endpoints
+pub struct RateLimits {
+    per_ip: Option<RateLimiter<IpAddr>>,
+    per_account: Option<RateLimiter<AccountId>>,
+    #[cfg(feature = "telemetry")]
+    metrics_reporter: MetricsReporter,
+}
+
+impl RateLimits {
+    /// Construct [`Self`] from the configuration
+    pub fn new(
+        config: ToriiRateLimit,
+        #[cfg(feature = "telemetry")] metrics_reporter: MetricsReporter,
+    ) -> Self {
+        Self {
+            per_ip: config.per_ip.map(RateLimiter::new),
+            per_account: config.per_account.map(RateLimiter::new),
+            #[cfg(feature = "telemetry")]
+            metrics_reporter,
+        }
+    }
+
+    fn check_ip(&self, endpoint: Endpoint, ip: IpAddr) -> Result<(), RateLimited> {
+        let Some(limiter) = &self.per_ip else {
+            return Ok(());
+        };
+        limiter
+            .take(ip, Instant::now())
+            .inspect_err(|_| self.report(endpoint, "ip"))
+    }
+
+    fn check_account(&self, endpoint: Endpoint, account: &AccountId) -> Result<(), RateLimited> {
+        let Some(limiter) = &self.per_account else {
+            return Ok(());
+        };
+        limiter
+            .take(account.clone(), Instant::now())
+            .inspect_err(|_| self.report(endpoint, "account"))
+    }
+
+    #[cfg(feature = "telemetry")]
+    fn report(&self, endpoint: Endpoint, limit: &str) {
+        self.metrics_reporter
+            .metrics()
+            .rate_limited_requests
+            .with_label_values(&[endpoint.as_str(), limit])
+            .inc();
+    }
+
+    #[cfg(not(feature = "telemetry"))]
+    #[allow(clippy::unused_self)]
+    fn report(&self, _endpoint: Endpoint, _limit: &str) {}
+}
+
+/// Filter for warp which rejects requests from IP addresses exceeding their rate limit
+pub fn limit_ip(
+    limits: Arc<RateLimits>,
+    endpoint: Endpoint,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::addr::remote()
+        .and_then(move |addr: Option<SocketAddr>| {
+            let limits = Arc::clone(&limits);
+            async move {
+                addr.map_or(Ok(()), |addr| {
+                    limits
+                        .check_ip(endpoint, addr.ip())
+                        .map_err(warp::reject::custom)
+                })
+            }
+        })
+        .untuple_one()
+}
+
+/// Filter for warp which rejects requests extracted by `request`
+/// if any of their verified authorities exceeds its rate limit
+pub fn limit_account<T: Signed + Send>(
+    limits: Arc<RateLimits>,
+    state: Arc<State>,
+    endpoint: Endpoint,
+    request: impl Filter<Extract = (T,), Error = Rejection> + Clone,
+) -> impl Filter<Extract = (T,), Error = Rejection> + Clone {
+    request.and_then(move |request: T| {
+        let checked = verified_authorities(&request, state.view().world())
+            .into_iter()
+            .try_for_each(|authority| limits.check_account(endpoint, authority));
+        let result = checked.map(|()| request).map_err(warp::reject::custom);
+        async move { result }
+    })
+}
+
+/// Token buckets of the keys of a single kind
+struct RateLimiter<K> {
+    config: TokenBucket,
+    buckets: Mutex<Generations<K>>,
+}
+
+/// Buckets split into two generations to forget the least recently used ones in `O(1)`.
+///
+/// Buckets are looked up in the current generation first and are moved there
+/// from the previous one on use. Once the current generation is full it becomes
+/// the previous one, dropping the buckets which weren't used since the last rotation.
+struct Generations<K> {
+    current: HashMap<K, Bucket>,
+    previous: HashMap<K, Bucket>,
+}
+
+impl<K> Default for Generations<K> {
+    fn default() -> Self {
+        Self {
+            current: HashMap::new(),
+            previous: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> Generations<K> {
+    /// Bucket of `key` in the current generation, `new` one if `key` isn't tracked
+    fn bucket(&mut self, key: K, new: Bucket) -> &mut Bucket {
+        let bucket = if self.current.contains_key(&key) {
+            new
+        } else {
+            let bucket = self.previous.remove(&key).unwrap_or(new);
+            if self.current.len() >= MAX_TRACKED_BUCKETS / 2 {
+                self.previous = mem::take(&mut self.current);
+            }
+            bucket
+        };
+        self.current.entry(key).or_insert(bucket)
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.current.len() + self.previous.len()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl<K: Hash + Eq> RateLimiter<K> {
+    fn new(config: TokenBucket) -> Self {
+        Self {
+            config,
+            buckets: Mutex::default(),
+        }
+    }
+
+    fn rate(&self) -> f64 {
+        f64::from(self.config.rate.get())
+    }
+
+    fn burst(&self) -> f64 {
+        f64::from(self.config.burst.get())
+    }
+
+    /// Tokens in the `bucket` after refilling it up to `now`
+    fn refilled(&self, bucket: Bucket, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        elapsed
+            .as_secs_f64()
+            .mul_add(self.rate(), bucket.tokens)
+            .min(self.burst())
+    }
+
+    /// Take a token from the bucket of `key`, or tell how long to wait for the next one
+    fn take(&self, key: K, now: Instant) -> Result<(), RateLimited> {
+        let mut buckets = self.buckets.lock().expect("Lock shouldn't be poisoned");
+
+        let bucket = buckets.bucket(
+            key,
+            Bucket {
+                tokens: self.burst(),
+                refilled_at: now,
+            },
+        );
+        bucket.tokens = self.refilled(*bucket, now);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(RateLimited {
+                retry_after: Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate()),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(rate: u32, burst: u32) -> RateLimiter<u8> {
+        RateLimiter::new(TokenBucket {
+            rate: rate.try_into().unwrap(),
+            burst: burst.try_into().unwrap(),
+        })
+    }
+
+    #[test]
+    fn burst_is_allowed_then_limited() {
+        let limiter = limiter(1, 3);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            limiter.take(0, now).expect("Within burst");
+        }
+        let rejection = limiter.take(0, now).expect_err("Burst is exhausted");
+        assert_eq!(rejection.retry_after, Duration::from_secs(1));
+
+        // Other keys have their own buckets
+        limiter.take(1, now).expect("Separate bucket");
+    }
+
+    #[test]
+    fn bucket_is_refilled_at_rate() {
+        let limiter = limiter(2, 2);
+        let now = Instant::now();
+
+        limiter.take(0, now).unwrap();
+        limiter.take(0, now).unwrap();
+        limiter.take(0, now).unwrap_err();
+
+        let later = now + Duration::from_millis(500);
+        limiter.take(0, later).expect("One token is refilled");
+        limiter.take(0, later).unwrap_err();
+
+        // Refill doesn't exceed the burst
+        let much_later = later + Duration::from_secs(60);
+        limiter.take(0, much_later).unwrap();
+        limiter.take(0, much_later).unwrap();
+        limiter.take(0, much_later).unwrap_err();
+    }
+
+    #[test]
+    fn least_recently_used_buckets_are_forgotten() {
+        let limiter = RateLimiter::new(TokenBucket {
+            rate: 1.try_into().unwrap(),
+            burst: 1.try_into().unwrap(),
+        });
+        let now = Instant::now();
+
+        limiter.take(0_usize, now).unwrap();
+        for key in 1..=MAX_TRACKED_BUCKETS {
+            limiter.take(key, now).unwrap();
+            if key % 1_000 == 0 {
+                limiter.take(0, now).expect_err("Bucket in use is kept");
+            }
+        }
+        assert!(limiter.buckets.lock().unwrap().len() <= MAX_TRACKED_BUCKETS);
+        limiter.take(0, now).expect_err("Bucket in use is kept");
+        limiter
+            .take(1, now)
+            .expect("Bucket wasn't used for too long");
+    }
+
+    #[test]
+    fn retry_after_is_rounded_up() {
+        let response = RateLimited {
+            retry_after: Duration::from_millis(1500),
+        }
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "2");
+    }
+}
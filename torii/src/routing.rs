@@ -5,26 +5,40 @@
 // FIXME: This can't be fixed, because one trait in `warp` is private.
 #![allow(opaque_hidden_inferred_bound)]
 
+use std::num::{NonZeroU64, NonZeroUsize};
+
 #[cfg(feature = "telemetry")]
 use eyre::{eyre, WrapErr};
 use futures::TryStreamExt;
 use iroha_config::client_api::ConfigDTO;
-use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest};
+use iroha_core::{
+    query::store::LiveQueryStoreHandle,
+    smartcontracts::query::ValidQueryRequest,
+    state::{HistoryError, StateReadOnly, StateView},
+};
+use iroha_crypto::HashOf;
 use iroha_data_model::{
     block::{
         stream::{BlockMessage, BlockSubscriptionRequest},
         SignedBlock,
     },
     prelude::*,
-    query::{cursor::ForwardCursor, http, QueryOutputBox, QueryRequest},
+    query::{cursor::ForwardCursor, error::QueryExecutionFail, http, QueryOutputBox, QueryRequest},
     BatchedResponse,
 };
 #[cfg(feature = "telemetry")]
-use iroha_telemetry::metrics::Status;
-use tokio::task;
+use iroha_telemetry::metrics::{PeersStatus, Status};
+use tokio::{
+    sync::{Semaphore, SemaphorePermit},
+    task,
+};
 
 use super::*;
-use crate::stream::{Sink, Stream};
+use crate::{
+    admin::Admin,
+    simulation::Simulator,
+    stream::{Compression, Sink, Stream},
+};
 
 /// Filter for warp which extracts [`http::ClientQueryRequest`]
 pub fn client_query_request(
@@ -49,12 +63,13 @@ pub async fn handle_transaction(
     chain_id: Arc<ChainId>,
     queue: Arc<Queue>,
     state: Arc<State>,
+    encoding: Encoding,
     transaction: SignedTransaction,
-) -> Result<Empty> {
+) -> Result<Empty, Encoded<Error>> {
     let state_view = state.view();
     let transaction_limits = state_view.config.transaction_limits;
     let transaction = AcceptedTransaction::accept(transaction, &chain_id, &transaction_limits)
-        .map_err(Error::AcceptTransaction)?;
+        .map_err(|error| Encoded(encoding, Error::AcceptTransaction(error)))?;
     queue
         .push(transaction, &state_view)
         .map_err(|queue::Failure { tx, err }| {
@@ -65,36 +80,247 @@ pub async fn handle_transaction(
 
             Box::new(err)
         })
-        .map_err(Error::PushIntoQueue)
+        .map_err(|error| Encoded(encoding, Error::PushIntoQueue(error)))
         .map(|()| Empty)
 }
 
+/// Run the transaction against a copy of the current state without submitting it.
+///
+/// The transaction is validated the same way as submitted ones, signatories included
+/// unless the transaction isn't signed, and the changes made by it are discarded.
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_simulation(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    simulator: Arc<Simulator>,
+    encoding: Encoding,
+    transaction: TransactionToSimulate,
+) -> Result<Encoded<TransactionSimulationResult>, Encoded<Error>> {
+    simulator
+        .simulate(&chain_id, state, transaction)
+        .await
+        .map(|simulation| Encoded(encoding, simulation))
+        .map_err(|error| Encoded(encoding, error))
+}
+
+/// Report the status of the transaction with the given `hash`.
+///
+/// Committed transactions are looked up in the blockchain, the pending ones in the queue,
+/// and the ones which were dropped from the queue are known from the recent pipeline events.
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_status(
+    queue: Arc<Queue>,
+    state: Arc<State>,
+    expired_transactions: Arc<ExpiredTransactions>,
+    encoding: Encoding,
+    hash: Hash,
+) -> Result<Encoded<TransactionEvent>, Encoded<Error>> {
+    let hash = HashOf::from_untyped_unchecked(hash);
+    let state_view = state.view();
+
+    let committed = state_view.block_with_tx(&hash).and_then(|block| {
+        let block_height = block.header().height();
+        block
+            .transactions()
+            .find(|tx| tx.as_ref().hash() == hash)
+            .map(|tx| (block_height, tx.error().clone()))
+    });
+    let (block_height, status) = if let Some((block_height, error)) = committed {
+        let status = error.map_or(TransactionStatus::Approved, |error| {
+            TransactionStatus::Rejected(Box::new(error))
+        });
+        (Some(block_height), status)
+    } else if let Some(status) = queue.transaction_status(&hash) {
+        (None, status)
+    } else if expired_transactions.contains(&hash) {
+        (None, TransactionStatus::Expired)
+    } else {
+        return Err(Encoded(encoding, Error::UnknownTransaction));
+    };
+
+    Ok(Encoded(
+        encoding,
+        TransactionEvent {
+            hash,
+            block_height,
+            status,
+        },
+    ))
+}
+
+/// Query params used to list multisig transactions waiting for co-signers
+#[derive(serde::Deserialize)]
+pub struct PendingTransactionsParams {
+    /// Authority of the transactions
+    account: AccountId,
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_pending_transactions(
+    queue: Arc<Queue>,
+    state: Arc<State>,
+    PendingTransactionsParams { account }: PendingTransactionsParams,
+) -> Scale<Vec<SignedTransaction>> {
+    let state_view = state.view();
+    let transactions = queue
+        .multisig_transactions(&account, &state_view)
+        .map(Into::into)
+        .collect();
+
+    Scale(transactions)
+}
+
+/// Maximum number of queries reconstructing the state at a past height at once
+pub const MAX_CONCURRENT_HISTORICAL_QUERIES: usize = 4;
+
+/// Wait for a permit from `historical_queries` if the state at `at_height` has to be reconstructed
+async fn historical_query_permit(
+    historical_queries: &Semaphore,
+    at_height: Option<NonZeroU64>,
+) -> Option<SemaphorePermit<'_>> {
+    match at_height {
+        Some(_) => Some(
+            historical_queries
+                .acquire()
+                .await
+                .expect("Historical query semaphore is never closed"),
+        ),
+        None => None,
+    }
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
     state: Arc<State>,
+    historical_queries: Arc<Semaphore>,
+    encoding: Encoding,
     query_request: http::ClientQueryRequest,
-) -> Result<Scale<BatchedResponse<QueryOutputBox>>> {
-    let handle = task::spawn_blocking(move || {
-        let state_view = state.view();
-        match query_request.0 {
-            QueryRequest::Query(signed_query) => {
-                let valid_query = ValidQueryRequest::validate(signed_query, &state_view)?;
-                let query_output = valid_query.execute_and_process(&state_view)?;
-                live_query_store
-                    .handle_query_output(query_output)
-                    .map_err(ValidationFail::from)
+) -> Result<Encoded<BatchedResponse<QueryOutputBox>>, Encoded<Error>> {
+    let at_height = match &query_request.0 {
+        QueryRequest::Query(signed_query) => signed_query.at_height(),
+        QueryRequest::Cursor(_) => None,
+    };
+    let _permit = historical_query_permit(&historical_queries, at_height).await;
+    let handle = task::spawn_blocking(move || match query_request.0 {
+        QueryRequest::Query(signed_query) => {
+            let at_height = signed_query.at_height();
+            let state_view = state.view();
+            // Permissions are always checked against the latest state
+            let valid_query = ValidQueryRequest::validate(signed_query, &state_view)?;
+            match at_height {
+                Some(height) if height.get() != state_view.height() => state
+                    .at_height(height, |historical_state| {
+                        process_query(&live_query_store, &valid_query, historical_state)
+                    })
+                    .map_err(|error| history_error(height, &error))?,
+                _ => process_query(&live_query_store, &valid_query, &state_view),
             }
-            QueryRequest::Cursor(cursor) => live_query_store
-                .handle_query_cursor(cursor)
-                .map_err(ValidationFail::from),
         }
+        QueryRequest::Cursor(cursor) => live_query_store
+            .handle_query_cursor(cursor)
+            .map_err(ValidationFail::from),
     });
     handle
         .await
         .expect("Failed to join query handling task")
-        .map(Scale)
-        .map_err(Into::into)
+        .map(|response| Encoded(encoding, response))
+        .map_err(|error| Encoded(encoding, error.into()))
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_query_batch(
+    live_query_store: LiveQueryStoreHandle,
+    state: Arc<State>,
+    historical_queries: Arc<Semaphore>,
+    max_size: NonZeroUsize,
+    encoding: Encoding,
+    batch: http::QueryBatch,
+) -> Result<Encoded<http::QueryBatchResponse>, Encoded<Error>> {
+    if batch.queries().len() > max_size.get() {
+        let max_size = u64::try_from(max_size.get()).expect("`usize` should fit into `u64`");
+        return Err(Encoded(
+            encoding,
+            ValidationFail::QueryFailed(QueryExecutionFail::BatchTooLarge(max_size)).into(),
+        ));
+    }
+
+    let _permit = historical_query_permit(
+        &historical_queries,
+        batch.queries().first().and_then(SignedQuery::at_height),
+    )
+    .await;
+    let handle = task::spawn_blocking(move || {
+        let queries = batch.into_queries();
+        let at_height = queries.first().and_then(SignedQuery::at_height);
+        if queries.iter().any(|query| query.at_height() != at_height) {
+            return Err(ValidationFail::QueryFailed(
+                QueryExecutionFail::BatchHeightMismatch,
+            ));
+        }
+
+        // A single state is used for the whole batch to keep the results consistent
+        let state_view = state.view();
+        match at_height {
+            Some(height) if height.get() != state_view.height() => state
+                .at_height(height, |historical_state| {
+                    process_query_batch(&live_query_store, queries, &state_view, historical_state)
+                })
+                .map_err(|error| history_error(height, &error)),
+            _ => Ok(process_query_batch(
+                &live_query_store,
+                queries,
+                &state_view,
+                &state_view,
+            )),
+        }
+    });
+    handle
+        .await
+        .expect("Failed to join query batch handling task")
+        .map(|responses| Encoded(encoding, responses))
+        .map_err(|error| Encoded(encoding, error.into()))
+}
+
+/// Execute `query` against `state`, storing the results which don't fit into the first batch
+fn process_query(
+    live_query_store: &LiveQueryStoreHandle,
+    query: &ValidQueryRequest,
+    state: &impl StateReadOnly,
+) -> Result<BatchedResponse<QueryOutputBox>, ValidationFail> {
+    let query_output = query.execute_and_process(state)?;
+    live_query_store
+        .handle_query_output(query_output)
+        .map_err(ValidationFail::from)
+}
+
+/// Execute `queries` against `state` checking their permissions against `latest_state`
+fn process_query_batch(
+    live_query_store: &LiveQueryStoreHandle,
+    queries: Vec<SignedQuery>,
+    latest_state: &StateView<'_>,
+    state: &impl StateReadOnly,
+) -> http::QueryBatchResponse {
+    queries
+        .into_iter()
+        .map(|query| {
+            let valid_query = ValidQueryRequest::validate(query, latest_state)?;
+            process_query(live_query_store, &valid_query, state)
+        })
+        .collect()
+}
+
+/// Report a failure to reconstruct the state at `height` to the client
+fn history_error(height: NonZeroU64, error: &HistoryError) -> ValidationFail {
+    match error {
+        HistoryError::NoBlock(_) | HistoryError::NotKept(_) => {
+            ValidationFail::QueryFailed(QueryExecutionFail::HeightNotServed(height.get()))
+        }
+        HistoryError::Replay { .. } => {
+            iroha_logger::error!(%error, %height, "Failed to reconstruct the state");
+            ValidationFail::InternalError(error.to_string())
+        }
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -107,6 +333,61 @@ pub fn handle_health() -> Json {
     reply::json(&Health::Healthy)
 }
 
+/// Report of the checks which decide whether the peer is ready to serve requests
+#[derive(Debug, serde::Serialize)]
+pub struct Readiness {
+    genesis_committed: bool,
+    height: u64,
+    highest_known_height: u64,
+    blocks_behind: u64,
+    max_blocks_behind: u64,
+    /// Reported for information only: observer peers serve requests without being in the topology
+    in_topology: bool,
+    kura_writable: bool,
+}
+
+impl Readiness {
+    fn is_ready(&self) -> bool {
+        self.genesis_committed && self.blocks_behind <= self.max_blocks_behind && self.kura_writable
+    }
+}
+
+/// Report the [`Readiness`], responding with `503 Service Unavailable` if the peer isn't ready.
+///
+/// The peer is ready while it lags at most `max_blocks_behind` blocks behind the highest known one.
+pub fn handle_ready(
+    state: &State,
+    kura: &Kura,
+    peer_id: &PeerId,
+    block_sync_status: &BlockSyncStatus,
+    max_blocks_behind: u64,
+) -> Response {
+    let (height, in_topology) = {
+        let state_view = state.view();
+        (
+            state_view.height(),
+            state_view.world().peers_ids().contains(peer_id),
+        )
+    };
+    let highest_known_height = block_sync_status.highest_known_height().max(height);
+    let readiness = Readiness {
+        genesis_committed: height > 0,
+        height,
+        highest_known_height,
+        blocks_behind: highest_known_height - height,
+        max_blocks_behind,
+        in_topology,
+        kura_writable: kura.is_writable(),
+    };
+
+    let status = if readiness.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    reply::with_status(reply::json(&readiness), status).into_response()
+}
+
 #[iroha_futures::telemetry_future]
 #[cfg(feature = "schema")]
 pub async fn handle_schema() -> Json {
@@ -120,13 +401,29 @@ pub async fn handle_get_configuration(kiso: KisoHandle) -> Result<Json> {
 }
 
 #[iroha_futures::telemetry_future]
-pub async fn handle_post_configuration(kiso: KisoHandle, value: ConfigDTO) -> Result<impl Reply> {
+pub async fn handle_post_configuration(
+    kiso: KisoHandle,
+    admin: Admin,
+    value: ConfigDTO,
+) -> Result<impl Reply> {
+    let previous = kiso.get_dto().await?;
     kiso.update_with_dto(value).await?;
+    iroha_logger::info!(
+        target: "audit",
+        %admin,
+        ?previous,
+        updated = ?value,
+        "Configuration updated"
+    );
     Ok(reply::with_status(reply::reply(), StatusCode::ACCEPTED))
 }
 
 #[iroha_futures::telemetry_future]
-pub async fn handle_blocks_stream(kura: Arc<Kura>, mut stream: WebSocket) -> eyre::Result<()> {
+pub async fn handle_blocks_stream(
+    kura: Arc<Kura>,
+    mut stream: WebSocket,
+    compression: Compression,
+) -> eyre::Result<()> {
     let BlockSubscriptionRequest(mut from_height) = stream.recv().await?;
 
     let mut interval = tokio::time::interval(std::time::Duration::from_millis(10));
@@ -156,7 +453,7 @@ pub async fn handle_blocks_stream(kura: Arc<Kura>, mut stream: WebSocket) -> eyr
                 if let Some(block) = kura.get_block_by_height(from_height.get()) {
                     stream
                         // TODO: to avoid clone `BlockMessage` could be split into sending and receiving parts
-                        .send(BlockMessage(SignedBlock::clone(&block)))
+                        .send_compressed(BlockMessage(SignedBlock::clone(&block)), compression)
                         .await?;
                     from_height = from_height.checked_add(1).expect("Maximum block height is achieved.");
                 }
@@ -171,6 +468,8 @@ pub async fn handle_blocks_stream(kura: Arc<Kura>, mut stream: WebSocket) -> eyr
 pub mod subscription {
     //! Contains the `handle_subscription` functions and used for general routing.
 
+    use tokio::sync::broadcast::error::RecvError;
+
     use super::*;
     use crate::event;
 
@@ -180,9 +479,11 @@ pub mod subscription {
         /// Event consumption resulted in an error
         Consumer(#[from] Box<event::Error>),
         /// Event reception error
-        Event(#[from] tokio::sync::broadcast::error::RecvError),
+        Event(#[from] RecvError),
         /// `WebSocket` error
         WebSocket(#[from] warp::Error),
+        /// Failed to replay the events of the committed blocks
+        Replay(#[from] HistoryError),
         /// A `Close` message is received. Not strictly an Error
         CloseMessage,
     }
@@ -202,29 +503,63 @@ pub mod subscription {
 
     type Result<T> = core::result::Result<T, Error>;
 
+    /// Maximum number of subscriptions replaying the events of the committed blocks at once
+    pub const MAX_CONCURRENT_REPLAYS: usize = 4;
+
     /// Handle subscription request
     ///
     /// Subscribes `stream` for `events` filtered by filter that is
-    /// received through the `stream`
+    /// received through the `stream`. If the request has a starting cursor,
+    /// events of the committed blocks are replayed from `state` first,
+    /// at most [`MAX_CONCURRENT_REPLAYS`] subscriptions at once as limited by `replays`.
+    /// Subscriptions starting deeper than the history kept by the peer are rejected.
     ///
     /// There should be a [`warp::filters::ws::Message::close()`]
     /// message to end subscription
     #[iroha_futures::telemetry_future]
-    pub async fn handle_subscription(events: EventsSender, stream: WebSocket) -> eyre::Result<()> {
-        let mut consumer = event::Consumer::new(stream).await?;
-
-        match subscribe_forever(events, &mut consumer).await {
+    pub async fn handle_subscription(
+        events: EventsSender,
+        state: Arc<State>,
+        replays: Arc<Semaphore>,
+        stream: WebSocket,
+        compression: Compression,
+    ) -> eyre::Result<()> {
+        let mut consumer = event::Consumer::new(stream, compression).await?;
+
+        match subscribe_forever(events, state, &replays, &mut consumer).await {
             Ok(()) | Err(Error::CloseMessage) => consumer.close_stream().await.map_err(Into::into),
+            Err(Error::Replay(error @ HistoryError::NotKept(_))) => {
+                iroha_logger::debug!(%error, "Rejecting event subscription");
+                consumer.reject(error.to_string()).await.map_err(Into::into)
+            }
             Err(err) => Err(err.into()),
         }
     }
 
     /// Make endless `consumer` subscription for `events`
     ///
+    /// If the subscriber falls behind `events`, the missed events of the committed blocks
+    /// are replayed from `state` starting after the last sent one. Missed events which
+    /// don't belong to a committed block can't be recovered.
+    ///
     /// Ideally should return `Result<!>` cause it either runs forever
     /// either returns `Err` variant
-    async fn subscribe_forever(events: EventsSender, consumer: &mut event::Consumer) -> Result<()> {
+    async fn subscribe_forever(
+        events: EventsSender,
+        state: Arc<State>,
+        replays: &Semaphore,
+        consumer: &mut event::Consumer,
+    ) -> Result<()> {
+        // Subscribe before the replay so that the events emitted meanwhile aren't lost
         let mut events = events.subscribe();
+        let subscribed_at = EventCursor::start_of(next_height(&state));
+        let mut live_cursor = event::LiveCursor::default();
+        let mut from = consumer.from();
+        let mut replayed_height = None;
+        let mut last_sent = None;
+        if let Some(from) = from {
+            replayed_height = replay(Arc::clone(&state), replays, from, None, consumer).await?;
+        }
 
         loop {
             tokio::select! {
@@ -237,15 +572,130 @@ pub mod subscription {
                 }
                 // This branch catches and sends events
                 event = events.recv() => {
-                    let event = event?;
+                    let event = match event {
+                        Ok(event) => event,
+                        Err(RecvError::Lagged(skipped)) => {
+                            // Live events can't be numbered after a gap, so they are
+                            // treated as if the subscription started from `resume`
+                            let resume = [
+                                from,
+                                last_sent.map(EventCursor::next),
+                                replayed_height.and_then(|height| {
+                                    height.checked_add(1).map(EventCursor::start_of)
+                                }),
+                            ]
+                            .into_iter()
+                            .flatten()
+                            .fold(subscribed_at, Ord::max);
+                            iroha_logger::debug!(
+                                skipped,
+                                ?resume,
+                                "Subscriber lagged behind, replaying missed events"
+                            );
+                            live_cursor = event::LiveCursor::default();
+                            from = Some(resume);
+                            replayed_height =
+                                replay(Arc::clone(&state), replays, resume, None, consumer)
+                                    .await?
+                                    .or(replayed_height);
+                            continue;
+                        }
+                        Err(error @ RecvError::Closed) => return Err(error.into()),
+                    };
                     iroha_logger::trace!(?event);
-                    consumer.consume(event).await?;
+                    match live_cursor.position(&event) {
+                        event::LivePosition::Unbound => consumer.consume(event, None).await?,
+                        event::LivePosition::At(cursor) => {
+                            if replayed_height.map_or(true, |height| cursor.height() > height) {
+                                consumer.consume(event, Some(cursor)).await?;
+                                last_sent = Some(cursor);
+                            }
+                        }
+                        event::LivePosition::Unknown => {
+                            let Some(from) = from else {
+                                consumer.consume(event, None).await?;
+                                continue;
+                            };
+                            // Events of the block which started before the subscription
+                            // are replayed once the block is applied
+                            let Some(height) = applied_block_height(&event) else {
+                                continue;
+                            };
+                            if replayed_height.map_or(true, |replayed| height > replayed) {
+                                let from = from.max(EventCursor::start_of(height));
+                                replayed_height = replay(
+                                    Arc::clone(&state),
+                                    replays,
+                                    from,
+                                    Some(height),
+                                    consumer,
+                                )
+                                .await?
+                                .or(replayed_height);
+                            }
+                        }
+                    }
                 }
                 // Else branch to prevent panic
                 else => ()
             }
         }
     }
+
+    /// Send the events of the committed blocks starting from `from` up to the block
+    /// at `to_height` (or the latest block) to the `consumer`.
+    ///
+    /// Events positioned before `from` are skipped.
+    /// Waits for a permit from `replays` first.
+    /// Returns the height of the latest replayed block.
+    async fn replay(
+        state: Arc<State>,
+        replays: &Semaphore,
+        from: EventCursor,
+        to_height: Option<NonZeroU64>,
+        consumer: &mut event::Consumer,
+    ) -> Result<Option<NonZeroU64>> {
+        let _permit = replays
+            .acquire()
+            .await
+            .expect("Replay semaphore is never closed");
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+        let handle = task::spawn_blocking(move || {
+            state.replay_events(from.height(), |height, events| {
+                sender.blocking_send((height, events)).is_ok()
+                    && to_height.map_or(true, |to_height| height < to_height)
+            })
+        });
+
+        let mut replayed_height = None;
+        while let Some((height, events)) = receiver.recv().await {
+            for (index, event) in (0..).zip(events) {
+                let cursor = EventCursor { height, index };
+                if cursor >= from {
+                    consumer.consume(event, Some(cursor)).await?;
+                }
+            }
+            replayed_height = Some(height);
+        }
+        handle.await.expect("Failed to join event replay task")?;
+
+        Ok(replayed_height)
+    }
+
+    /// Height of the block following the latest committed one
+    fn next_height(state: &State) -> NonZeroU64 {
+        NonZeroU64::new(state.view().height() + 1).expect("Height can't be zero")
+    }
+
+    /// Height of the block if `event` reports its application
+    fn applied_block_height(event: &EventBox) -> Option<NonZeroU64> {
+        let EventBox::Pipeline(PipelineEventBox::Block(event)) = event else {
+            return None;
+        };
+        (event.status() == &BlockStatus::Applied)
+            .then(|| NonZeroU64::new(event.header().height()))
+            .flatten()
+    }
 }
 
 #[iroha_futures::telemetry_future]
@@ -318,6 +768,25 @@ pub fn handle_status(
     }
 }
 
+#[iroha_futures::telemetry_future]
+#[cfg(feature = "telemetry")]
+pub async fn handle_peers(
+    metrics_reporter: &MetricsReporter,
+    sumeragi: &SumeragiHandle,
+    accept: Option<impl AsRef<str> + Send>,
+) -> Response {
+    let status = PeersStatus {
+        role: sumeragi.role().to_string(),
+        peers: metrics_reporter.peers().await,
+    };
+
+    if accept.is_some_and(|x| x.as_ref() == PARITY_SCALE_MIME_TYPE) {
+        Scale(status).into_response()
+    } else {
+        reply::json(&status).into_response()
+    }
+}
+
 #[cfg(feature = "profiling")]
 pub mod profiling {
     use std::num::{NonZeroU16, NonZeroU64};
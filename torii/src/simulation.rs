@@ -0,0 +1,106 @@
+//! Simulation of transactions against a copy of the state which is shared by the simulations.
+
+use std::sync::{Arc, Mutex};
+
+use iroha_core::{
+    prelude::*,
+    state::{State, StateReadOnly},
+};
+use iroha_crypto::{HashOf, KeyPair};
+use iroha_data_model::{
+    block::SignedBlock,
+    transaction::{prelude::*, TransactionSimulationResult},
+    ChainId,
+};
+use tokio::{sync::Semaphore, task};
+
+use crate::Error;
+
+/// Maximum number of transactions simulated at once
+const MAX_CONCURRENT_SIMULATIONS: usize = 4;
+
+/// Runs simulations against a copy of the state made once per committed block,
+/// so that simulations neither copy the whole state each nor block the execution of blocks.
+pub struct Simulator {
+    simulations: Semaphore,
+    copy: Mutex<Option<StateCopy>>,
+}
+
+/// Copy of the state at the block with the given hash
+struct StateCopy {
+    block_hash: Option<HashOf<SignedBlock>>,
+    state: Arc<State>,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self {
+            simulations: Semaphore::new(MAX_CONCURRENT_SIMULATIONS),
+            copy: Mutex::default(),
+        }
+    }
+}
+
+impl Simulator {
+    /// Run the `transaction` against the latest committed state.
+    ///
+    /// Unsigned transactions are signed with a throwaway key pair, as the executor
+    /// only validates signed transactions, and their signatories aren't checked.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't accepted, e.g. because it exceeds the transaction limits
+    pub async fn simulate(
+        self: Arc<Self>,
+        chain_id: &ChainId,
+        state: Arc<State>,
+        transaction: TransactionToSimulate,
+    ) -> Result<TransactionSimulationResult, Error> {
+        let (transaction, check_signatories) = match transaction {
+            TransactionToSimulate::Signed(transaction) => (transaction, true),
+            TransactionToSimulate::Unsigned(transaction) => {
+                (transaction.sign(&KeyPair::random()), false)
+            }
+        };
+        let transaction_limits = state.view().config.transaction_limits;
+        let transaction = AcceptedTransaction::accept(transaction, chain_id, &transaction_limits)
+            .map_err(Error::AcceptTransaction)?;
+
+        let _permit = self
+            .simulations
+            .acquire()
+            .await
+            .expect("Semaphore is never closed");
+        let simulator = Arc::clone(&self);
+        let simulation = task::spawn_blocking(move || {
+            let copy = simulator.state_copy(&state);
+            let mut state_block = copy.block();
+            state_block.transaction_executor().simulate(
+                transaction,
+                check_signatories,
+                &mut state_block,
+            )
+        })
+        .await
+        .expect("Transaction simulation shouldn't panic");
+
+        Ok(simulation)
+    }
+
+    /// Copy of the `state` at its latest block, made once the previous copy is outdated
+    fn state_copy(&self, state: &State) -> Arc<State> {
+        let latest_block_hash = state.view().latest_block_hash();
+        let mut copy = self.copy.lock().expect("Lock shouldn't be poisoned");
+        match &*copy {
+            Some(copy) if copy.block_hash == latest_block_hash => Arc::clone(&copy.state),
+            _ => {
+                // The copy keeps the simulations from blocking the execution of the blocks
+                let state = Arc::new(state.detached_copy());
+                *copy = Some(StateCopy {
+                    block_hash: state.view().latest_block_hash(),
+                    state: Arc::clone(&state),
+                });
+                state
+            }
+        }
+    }
+}
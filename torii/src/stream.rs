@@ -3,8 +3,11 @@
 //! of encoding/decoding as well as timeouts
 
 use core::{result::Result, time::Duration};
+use std::{io::Write as _, mem};
 
+use flate2::{write::DeflateEncoder, Compression as Level};
 use futures::{SinkExt, StreamExt};
+use iroha_torii_const::compression::{DEFLATE, SYNC_FLUSH_TAIL};
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
 
@@ -12,6 +15,8 @@ use parity_scale_codec::DecodeAll;
 const TIMEOUT: Duration = Duration::from_millis(10_000);
 #[cfg(not(test))]
 const TIMEOUT: Duration = Duration::from_millis(1000);
+/// Size above which messages are compressed on a blocking thread not to stall the async executor
+const BLOCKING_COMPRESSION_SIZE: usize = 64 * 1024;
 
 /// Error type with generic for actual Stream/Sink error type
 #[derive(Debug, displaydoc::Display, thiserror::Error)]
@@ -53,6 +58,67 @@ pub trait StreamMessage {
     fn is_close(&self) -> bool;
 }
 
+/// Compression applied to the binary messages sent over the stream
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// Messages are sent as is
+    #[default]
+    None,
+    /// Every message is compressed with per-message deflate,
+    /// see [`iroha_torii_const::compression`]
+    Deflate,
+}
+
+impl Compression {
+    /// Compression requested by the client in the [`WS_COMPRESSION_HEADER`](iroha_torii_const::compression::WS_COMPRESSION_HEADER).
+    /// Unknown values fall back to [`Compression::None`].
+    pub fn negotiate(requested: Option<&str>) -> Self {
+        match requested {
+            Some(requested) if requested.split(',').any(|value| value.trim() == DEFLATE) => {
+                Self::Deflate
+            }
+            _ => Self::None,
+        }
+    }
+
+    /// Value of the [`WS_COMPRESSION_HEADER`](iroha_torii_const::compression::WS_COMPRESSION_HEADER)
+    /// to confirm the compression in the handshake response
+    pub fn header_value(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Deflate => Some(DEFLATE),
+        }
+    }
+
+    /// Compress `bytes`, offloading large messages to a blocking thread
+    async fn apply_offloaded(self, bytes: Vec<u8>) -> Vec<u8> {
+        if self == Self::None || bytes.len() <= BLOCKING_COMPRESSION_SIZE {
+            return self.apply(bytes);
+        }
+        tokio::task::spawn_blocking(move || self.apply(bytes))
+            .await
+            .expect("Compression shouldn't panic")
+    }
+
+    fn apply(self, bytes: Vec<u8>) -> Vec<u8> {
+        match self {
+            Self::None => bytes,
+            Self::Deflate => {
+                let mut encoder = DeflateEncoder::new(Vec::new(), Level::fast());
+                encoder
+                    .write_all(&bytes)
+                    .expect("Writing into a `Vec` can't fail");
+                encoder.flush().expect("Writing into a `Vec` can't fail");
+                let mut deflated = mem::take(encoder.get_mut());
+                if deflated.ends_with(&SYNC_FLUSH_TAIL) {
+                    deflated.truncate(deflated.len() - SYNC_FLUSH_TAIL.len());
+                }
+                deflated
+            }
+        }
+    }
+}
+
 /// Trait for writing custom messages into stream
 #[async_trait::async_trait]
 pub trait Sink<S>: SinkExt<Self::Message, Error = Self::Err> + Unpin
@@ -65,11 +131,16 @@ where
     /// Message type used by the underlying sink
     type Message: StreamMessage + Send;
 
-    /// Encoded message and sends it to the stream
-    async fn send(&mut self, message: S) -> Result<(), Error<Self::Err>> {
+    /// Encodes message, applies the `compression` to it and sends it to the stream
+    async fn send_compressed(
+        &mut self,
+        message: S,
+        compression: Compression,
+    ) -> Result<(), Error<Self::Err>> {
+        let bytes = compression.apply_offloaded(message.encode()).await;
         tokio::time::timeout(
             TIMEOUT,
-            <Self as SinkExt<Self::Message>>::send(self, Self::Message::binary(message.encode())),
+            <Self as SinkExt<Self::Message>>::send(self, Self::Message::binary(bytes)),
         )
         .await
         .map_err(|_err| Error::SendTimeout)?
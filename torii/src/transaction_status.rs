@@ -0,0 +1,101 @@
+//! Tracking of the pipeline events which are needed to report the status
+//! of transactions which are neither in the queue nor in the blockchain.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Mutex,
+};
+
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    events::{
+        pipeline::{PipelineEventBox, TransactionEvent, TransactionStatus},
+        EventBox,
+    },
+    transaction::SignedTransaction,
+};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+/// Number of the most recently expired transactions which are remembered
+const EXPIRED_TRANSACTIONS_CAPACITY: usize = 4096;
+
+/// Hashes of the transactions which were recently dropped from the queue as expired
+#[derive(Debug, Default)]
+pub struct ExpiredTransactions(Mutex<ExpiredTransactionsInner>);
+
+#[derive(Debug, Default)]
+struct ExpiredTransactionsInner {
+    hashes: HashSet<HashOf<SignedTransaction>>,
+    /// Hashes in the order of expiration, used to forget the oldest ones
+    order: VecDeque<HashOf<SignedTransaction>>,
+}
+
+impl ExpiredTransactions {
+    /// Check if the transaction with the given `hash` expired recently
+    pub fn contains(&self, hash: &HashOf<SignedTransaction>) -> bool {
+        self.0
+            .lock()
+            .expect("Lock shouldn't be poisoned")
+            .hashes
+            .contains(hash)
+    }
+
+    fn insert(&self, hash: HashOf<SignedTransaction>) {
+        let mut inner = self.0.lock().expect("Lock shouldn't be poisoned");
+
+        if !inner.hashes.insert(hash) {
+            return;
+        }
+        inner.order.push_back(hash);
+        if inner.order.len() > EXPIRED_TRANSACTIONS_CAPACITY {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.hashes.remove(&oldest);
+            }
+        }
+    }
+
+    /// Remember expired transactions reported by `events` until the events channel is closed
+    pub async fn track(&self, mut events: Receiver<EventBox>) {
+        loop {
+            match events.recv().await {
+                Ok(EventBox::Pipeline(PipelineEventBox::Transaction(TransactionEvent {
+                    hash,
+                    status: TransactionStatus::Expired,
+                    ..
+                }))) => self.insert(hash),
+                Ok(_) => {}
+                Err(RecvError::Lagged(skipped)) => {
+                    iroha_logger::warn!(
+                        skipped,
+                        "Missed events while tracking expired transactions"
+                    );
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::Hash;
+
+    use super::*;
+
+    fn hash(n: usize) -> HashOf<SignedTransaction> {
+        HashOf::from_untyped_unchecked(Hash::new(n.to_le_bytes()))
+    }
+
+    #[test]
+    fn oldest_expired_transactions_are_forgotten() {
+        let expired = ExpiredTransactions::default();
+
+        for n in 0..=EXPIRED_TRANSACTIONS_CAPACITY {
+            expired.insert(hash(n));
+        }
+
+        assert!(!expired.contains(&hash(0)));
+        assert!(expired.contains(&hash(1)));
+        assert!(expired.contains(&hash(EXPIRED_TRANSACTIONS_CAPACITY)));
+    }
+}
@@ -1,8 +1,12 @@
 use std::convert::Infallible;
 
 use iroha_version::prelude::*;
+use serde::Serialize;
 use warp::{
-    http::{header::CONTENT_TYPE, HeaderValue},
+    http::{
+        header::{ACCEPT, CONTENT_TYPE},
+        HeaderValue,
+    },
     hyper::body::Bytes,
     reply::Response,
     Filter, Rejection, Reply,
@@ -37,6 +41,56 @@ impl<T: Encode + Send> Reply for Scale<T> {
     }
 }
 
+/// MIME used in Torii for JSON encoding
+pub const JSON_MIME_TYPE: &'_ str = "application/json";
+
+/// Encoding of request and response bodies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    /// SCALE encoding, used unless JSON is requested
+    #[default]
+    Scale,
+    /// JSON encoding
+    Json,
+}
+
+impl Encoding {
+    /// Encoding denoted by the value of the `Content-Type` or `Accept` header
+    fn from_header(header: Option<&str>) -> Self {
+        let is_json = header.is_some_and(|header| {
+            header
+                .split(',')
+                .filter_map(|mime| mime.split(';').next())
+                .any(|mime| mime.trim().eq_ignore_ascii_case(JSON_MIME_TYPE))
+        });
+
+        if is_json {
+            Self::Json
+        } else {
+            Self::Scale
+        }
+    }
+}
+
+/// Filter for warp which extracts the [`Encoding`] of the response requested with the `Accept` header
+pub fn accept() -> impl Filter<Extract = (Encoding,), Error = Rejection> + Copy {
+    warp::header::optional::<String>(ACCEPT.as_str())
+        .map(|accept: Option<String>| Encoding::from_header(accept.as_deref()))
+}
+
+/// Structure to reply using the [`Encoding`] requested by the client
+#[derive(Debug)]
+pub struct Encoded<T>(pub Encoding, pub T);
+
+impl<T: Encode + Serialize + Send> Reply for Encoded<T> {
+    fn into_response(self) -> Response {
+        match self.0 {
+            Encoding::Scale => Scale(self.1).into_response(),
+            Encoding::Json => warp::reply::json(&self.1).into_response(),
+        }
+    }
+}
+
 /// Adds state to filter
 macro_rules! add_state {
     ( $( $state : expr ),* $(,)? ) => {
@@ -49,18 +103,47 @@ macro_rules! add_state {
 
 pub mod body {
     use iroha_version::error::Error as VersionError;
+    use parity_scale_codec::DecodeAll;
 
     use super::*;
 
-    /// Decode body as versioned scale codec
-    pub fn versioned<T: DecodeVersioned>() -> impl Filter<Extract = (T,), Error = Rejection> + Copy
+    /// Decode body as versioned scale codec, or as versioned JSON if `Content-Type` is JSON
+    pub fn versioned<T>() -> impl Filter<Extract = (T,), Error = Rejection> + Copy
+    where
+        T: DecodeVersioned + for<'de> DeserializeVersioned<'de>,
+    {
+        warp::header::optional::<String>(CONTENT_TYPE.as_str())
+            .and(warp::body::bytes())
+            .and_then(|content_type: Option<String>, body: Bytes| async move {
+                match Encoding::from_header(content_type.as_deref()) {
+                    Encoding::Scale => T::decode_all_versioned(body.as_ref()),
+                    Encoding::Json => core::str::from_utf8(body.as_ref())
+                        .map_err(|_| VersionError::ExpectedJson)
+                        .and_then(T::from_versioned_json_str),
+                }
+                .map_err(warp::reject::custom)
+            })
+    }
+
+    /// Decode body as scale codec, or as JSON if `Content-Type` is JSON
+    pub fn unversioned<T>() -> impl Filter<Extract = (T,), Error = Rejection> + Copy
+    where
+        T: Decode + serde::de::DeserializeOwned,
     {
-        warp::body::bytes().and_then(|body: Bytes| async move {
-            T::decode_all_versioned(body.as_ref()).map_err(warp::reject::custom)
-        })
+        warp::header::optional::<String>(CONTENT_TYPE.as_str())
+            .and(warp::body::bytes())
+            .and_then(|content_type: Option<String>, body: Bytes| async move {
+                match Encoding::from_header(content_type.as_deref()) {
+                    Encoding::Scale => {
+                        T::decode_all(&mut body.as_ref()).map_err(VersionError::from)
+                    }
+                    Encoding::Json => serde_json::from_slice(&body).map_err(VersionError::from),
+                }
+                .map_err(warp::reject::custom)
+            })
     }
 
-    /// Recover from failure in `versioned`
+    /// Recover from failure in `versioned` and `unversioned`
     pub fn recover_versioned(rejection: Rejection) -> Result<impl Reply, Rejection> {
         rejection
             .find::<VersionError>()